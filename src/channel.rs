@@ -0,0 +1,359 @@
+use super::*;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Band {
+    /// 2.4 GHz, channels 1-14
+    TwoPointFourGHz,
+    /// 5 GHz, including the 4.9 GHz channels
+    FiveGHz,
+    /// 6 GHz, channels 1-233
+    SixGHz,
+    /// 60 GHz (DMG), channels 1-6
+    SixtyGHz,
+}
+
+impl Band {
+    /// Center frequency of channel `number`, in MHz
+    #[must_use]
+    pub fn channel_to_frequency(self, number: u8) -> Option<u32> {
+        let n = u32::from(number);
+        match self {
+            Self::TwoPointFourGHz => match number {
+                1..=13 => Some(2407 + n * 5),
+                14 => Some(2484),
+                _ => None,
+            },
+            Self::FiveGHz => match number {
+                // 4.9 GHz
+                182..=196 => Some(4000 + n * 5),
+                32..=177 => Some(5000 + n * 5),
+                _ => None,
+            },
+            Self::SixGHz => match number {
+                2 => Some(5935),
+                1..=233 => Some(5950 + n * 5),
+                _ => None,
+            },
+            Self::SixtyGHz => match number {
+                1..=6 => Some(56160 + n * 2160),
+                _ => None,
+            },
+        }
+    }
+
+    /// Whether `number` is a 20 MHz channel that can be a primary channel
+    #[must_use]
+    pub fn is_primary_channel(self, number: u8) -> bool {
+        match self {
+            Self::TwoPointFourGHz => (1..=14).contains(&number),
+            Self::FiveGHz => match number {
+                32..=144 => number % 4 == 0,
+                149..=177 => number % 4 == 1,
+                182..=196 => true,
+                _ => false,
+            },
+            Self::SixGHz => number == 2 || ((1..=233).contains(&number) && number % 4 == 1),
+            Self::SixtyGHz => (1..=6).contains(&number),
+        }
+    }
+
+    /// Band and channel number of a center frequency in MHz, e.g. from the
+    /// radiotap Channel field
+    #[must_use]
+    pub fn frequency_to_channel(frequency: u32) -> Option<(Self, u8)> {
+        let (band, number) = match frequency {
+            2484 => (Self::TwoPointFourGHz, 14),
+            2412..=2472 => (Self::TwoPointFourGHz, (frequency - 2407) / 5),
+            4910..=4980 => (Self::FiveGHz, (frequency - 4000) / 5),
+            5160..=5885 => (Self::FiveGHz, (frequency - 5000) / 5),
+            5935 => (Self::SixGHz, 2),
+            5955..=7115 => (Self::SixGHz, (frequency - 5950) / 5),
+            58320..=69120 => (Self::SixtyGHz, (frequency - 56160) / 2160),
+            _ => return None,
+        };
+
+        let number = number as u8;
+        if band.channel_to_frequency(number) != Some(frequency) {
+            return None;
+        }
+
+        Some((band, number))
+    }
+}
+
+/// Center channel numbers of the 6 GHz 320 MHz channels, of the 320-1 and
+/// the overlapping 320-2 channelization
+const SIX_GHZ_320_MHZ_CENTERS: [[u8; 3]; 2] = [[31, 95, 159], [63, 127, 191]];
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ChannelWidth {
+    MHz20,
+    MHz40,
+    MHz80,
+    MHz160,
+    /// two non-contiguous 80 MHz segments
+    MHz80Plus80,
+    MHz320,
+    /// 60 GHz channels
+    MHz2160,
+}
+
+impl ChannelWidth {
+    /// Width of one segment, in MHz
+    #[must_use]
+    pub fn mhz(self) -> u32 {
+        match self {
+            Self::MHz20 => 20,
+            Self::MHz40 => 40,
+            Self::MHz80 | Self::MHz80Plus80 => 80,
+            Self::MHz160 => 160,
+            Self::MHz320 => 320,
+            Self::MHz2160 => 2160,
+        }
+    }
+}
+
+/// An operating channel
+///
+/// `number` and `frequency` are those of the primary 20 MHz channel, the
+/// center frequencies are those of the whole channel.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Channel {
+    pub band: Band,
+    /// primary channel number
+    pub number: u8,
+    /// primary channel center frequency, in MHz
+    pub frequency: u32,
+    pub width: ChannelWidth,
+    /// in MHz
+    pub center_frequency: u32,
+    /// second segment of an 80+80 MHz channel, in MHz
+    pub center_frequency_2: Option<u32>,
+}
+
+impl Channel {
+    /// A 20 MHz channel
+    #[must_use]
+    pub fn new(band: Band, number: u8) -> Option<Self> {
+        let frequency = band.channel_to_frequency(number)?;
+        let width = if band == Band::SixtyGHz {
+            ChannelWidth::MHz2160
+        } else {
+            ChannelWidth::MHz20
+        };
+
+        Some(Self {
+            band,
+            number,
+            frequency,
+            width,
+            center_frequency: frequency,
+            center_frequency_2: None,
+        })
+    }
+
+    /// A 20 MHz channel from a center frequency in MHz
+    #[must_use]
+    pub fn from_frequency(frequency: u32) -> Option<Self> {
+        let (band, number) = Band::frequency_to_channel(frequency)?;
+        Self::new(band, number)
+    }
+
+    /// A channel of `width` with primary channel `number`, the center is the
+    /// block of `width` the primary channel falls in
+    ///
+    /// For 2.4 GHz 40 MHz channels use `with_center`, as the secondary channel
+    /// can be on either side.
+    #[must_use]
+    pub fn with_width(band: Band, number: u8, width: ChannelWidth) -> Option<Self> {
+        let channel = Self::new(band, number)?;
+        let center = match width {
+            ChannelWidth::MHz20 | ChannelWidth::MHz2160 => return Some(channel),
+            ChannelWidth::MHz80Plus80 => return None,
+            width => Self::block_center(band, number, width)?,
+        };
+
+        channel.with_center(width, center, None)
+    }
+
+    /// A channel of `width` from center channel numbers, as found in
+    /// operation elements
+    #[must_use]
+    pub fn with_center(
+        self,
+        width: ChannelWidth,
+        center: u8,
+        center_2: Option<u8>,
+    ) -> Option<Self> {
+        let center_frequency = self.band.channel_to_frequency(center)?;
+        let center_frequency_2 = match center_2 {
+            Some(center_2) => Some(self.band.channel_to_frequency(center_2)?),
+            None => None,
+        };
+
+        // the primary channel has to be inside the first segment
+        let half = width.mhz() / 2;
+        if self.frequency + 10 > center_frequency + half
+            || self.frequency < center_frequency + 10 - half
+        {
+            return None;
+        }
+
+        Some(Self {
+            width,
+            center_frequency,
+            center_frequency_2,
+            ..self
+        })
+    }
+
+    /// Center channel number of the channel
+    #[must_use]
+    pub fn center_number(&self) -> Option<u8> {
+        Band::frequency_to_channel(self.center_frequency).map(|(_, number)| number)
+    }
+
+    /// Center channel number of the `width` block channel `number` is in
+    ///
+    /// 6 GHz 320 MHz channels overlap, the 320-1 channelization is used where
+    /// it has a channel and 320-2 above it.
+    fn block_center(band: Band, number: u8, width: ChannelWidth) -> Option<u8> {
+        if (band, width) == (Band::SixGHz, ChannelWidth::MHz320) {
+            return SIX_GHZ_320_MHZ_CENTERS
+                .iter()
+                .flatten()
+                .copied()
+                .find(|center| center.abs_diff(number) <= 30);
+        }
+
+        // channels covered by a block, and the first channel of each group of
+        // blocks
+        let span = (width.mhz() / 5) as u8;
+        let base = match band {
+            Band::TwoPointFourGHz | Band::SixtyGHz => return None,
+            Band::FiveGHz => match (number, width) {
+                (36..=144, _) => 36,
+                (149..=177, ChannelWidth::MHz40 | ChannelWidth::MHz80 | ChannelWidth::MHz160) => {
+                    149
+                }
+                _ => return None,
+            },
+            Band::SixGHz => 1,
+        };
+        if number < base {
+            return None;
+        }
+
+        let center = number - ((number - base) % span) + (span / 2) - 2;
+
+        // 5 GHz wide channels stop at the edges of the subbands
+        let valid = match (band, width) {
+            (Band::FiveGHz, ChannelWidth::MHz80) => {
+                [42, 58, 106, 122, 138, 155, 171].contains(&center)
+            }
+            (Band::FiveGHz, ChannelWidth::MHz160) => [50, 114, 163].contains(&center),
+            (Band::FiveGHz, ChannelWidth::MHz320) => false,
+            _ => true,
+        };
+        if !valid {
+            return None;
+        }
+
+        Some(center)
+    }
+
+    /// Channel from a global operating class and a channel number
+    ///
+    /// See `OperatingClass::channel`.
+    #[must_use]
+    pub fn from_operating_class(operating_class: u8, number: u8) -> Option<Self> {
+        OperatingClass::global(operating_class)?.channel(number)
+    }
+
+    pub(crate) fn from_band_and_width(
+        band: Band,
+        number: u8,
+        width: ChannelWidth,
+        secondary_above: Option<bool>,
+    ) -> Option<Self> {
+        // 80+80 is described by the primary segment only
+        let segment_width = match width {
+            ChannelWidth::MHz80Plus80 => ChannelWidth::MHz80,
+            width => width,
+        };
+
+        // a center channel, the channel with the lowest primary channel
+        if !band.is_primary_channel(number) {
+            if segment_width.mhz() < 40 || band == Band::TwoPointFourGHz {
+                return None;
+            }
+
+            let primary = number.checked_sub((segment_width.mhz() / 10) as u8 - 2)?;
+            let is_center = match (band, segment_width) {
+                (Band::SixGHz, ChannelWidth::MHz320) => SIX_GHZ_320_MHZ_CENTERS
+                    .iter()
+                    .flatten()
+                    .any(|center| *center == number),
+                _ => Self::block_center(band, primary, segment_width) == Some(number),
+            };
+            if !is_center {
+                return None;
+            }
+
+            let channel = Self::new(band, primary)?.with_center(segment_width, number, None)?;
+            return Some(Self { width, ..channel });
+        }
+
+        let channel = match (band, secondary_above) {
+            // the secondary channel is 4 channels away and has to exist too
+            (Band::TwoPointFourGHz, Some(true)) => {
+                Self::new(band, number.checked_add(4)?)?;
+                Self::new(band, number)?.with_center(width, number + 2, None)?
+            }
+            (Band::TwoPointFourGHz, Some(false)) => {
+                Self::new(band, number.checked_sub(4)?)?;
+                Self::new(band, number)?.with_center(width, number - 2, None)?
+            }
+            _ => Self::with_width(band, number, segment_width)?,
+        };
+
+        // the secondary channel has to be on the side the class says
+        match secondary_above {
+            Some(true) if channel.center_frequency < channel.frequency => return None,
+            Some(false) if channel.center_frequency > channel.frequency => return None,
+            _ => {}
+        }
+
+        Some(Self { width, ..channel })
+    }
+}
+
+#[test]
+fn test_channel() {
+    assert_eq!(
+        Band::frequency_to_channel(2437),
+        Some((Band::TwoPointFourGHz, 6)),
+        "2437 MHz"
+    );
+    assert_eq!(
+        Band::frequency_to_channel(5180),
+        Some((Band::FiveGHz, 36)),
+        "5180 MHz"
+    );
+    assert_eq!(
+        Band::frequency_to_channel(5955),
+        Some((Band::SixGHz, 1)),
+        "5955 MHz"
+    );
+    assert_eq!(Band::frequency_to_channel(2440), None, "2440 MHz");
+
+    let channel = Channel::from_operating_class(128, 42).unwrap();
+    assert_eq!(channel.number, 36, "class 128 primary");
+    assert_eq!(channel.width, ChannelWidth::MHz80, "class 128 width");
+    assert_eq!(channel.center_frequency, 5210, "class 128 center");
+    assert_eq!(
+        Channel::from_operating_class(83, 11),
+        None,
+        "class 83 channel 11"
+    );
+}
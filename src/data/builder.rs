@@ -20,35 +20,38 @@ impl DataFrameBuilder {
     }
 
     #[must_use]
-    pub fn build(&self) -> DataFrame {
+    pub fn build(&self) -> DataFrame<'_> {
         DataFrame::new(self.bytes().to_vec())
     }
 
+    /// Sets the frame body, behind the security header of a protected frame
+    ///
+    /// The `encrypt_` methods encrypt the frame body set here, frames that are
+    /// already protected are refused.
     pub fn next_layer(&mut self, data: &[u8]) {
-        let mut index = Self::FRAGMENT_SEQUENCE_START + 2;
-
         let data_frame = self.build();
 
-        if data_frame.protected() {
-            index += 8; // skip TKIP/CCMP parameters
-        }
+        // invalid type for holding data
+        let mut index = match data_frame.header_length() {
+            Some(header_length) => header_length,
+            None => return,
+        };
 
-        match data_frame.subtype() {
-            FrameSubtype::Data(ref subtype) => match subtype {
-                DataSubtype::QoSData => {
-                    index += 2; // skip Qos Control
-                }
-                DataSubtype::Data => {}
-
-                // invalid type for holding data
-                _ => return,
-            },
-            _ => unreachable!(),
+        if data_frame.protected() {
+            // a missing security header is zeroed, the WEP IV
+            let key_id = self.bytes().get(index + 3).copied().unwrap_or(0);
+            index += security_header_length(key_id);
         }
 
         self.bytes.resize(index + data.len(), 0);
         self.bytes_mut()[index..].copy_from_slice(data);
     }
+
+    /// Everything from `start` on, for the ciphers to swap the frame body
+    pub(crate) fn replace_frame_body(&mut self, start: usize, body: &[u8]) {
+        self.bytes.truncate(start);
+        self.bytes.extend_from_slice(body);
+    }
 }
 impl FrameBuilderTrait for DataFrameBuilder {
     fn bytes(&self) -> &[u8] {
@@ -76,6 +79,13 @@ impl FrameBuilderTrait for DataFrameBuilder {
 
 impl FragmentSequenceBuilderTrait for DataFrameBuilder {}
 impl DataFrameBuilderTrait for DataFrameBuilder {
+    fn addr4(&mut self, mac_address: MacAddress) {
+        if self.bytes.len() < 30 {
+            self.bytes.resize(30, 0);
+        }
+        self.bytes_mut()[24..30].copy_from_slice(mac_address.as_bytes());
+    }
+
     fn transmitter_address(&mut self, mac_address: MacAddress) {
         self.addr2(mac_address)
     }
@@ -132,6 +142,7 @@ pub trait DataFrameBuilderTrait: FrameBuilderTrait {
 }
 
 #[test]
+#[allow(clippy::bool_assert_comparison, clippy::redundant_slicing)]
 fn test_data_frame_builder() {
     let mut data_frame_builder = DataFrameBuilder::new();
 
@@ -199,3 +210,386 @@ fn test_data_frame_builder() {
 
     assert_eq!(&data_frame.next_layer().unwrap()[..], b"hello!!");
 }
+
+#[test]
+fn test_data_frame_builder_llc_snap() {
+    let mut data_frame_builder = DataFrameBuilder::new();
+    data_frame_builder.subtype(FrameSubtype::Data(DataSubtype::Data));
+    data_frame_builder.ds_status(DSStatus::FromSTAToDS);
+
+    // VLAN 100, priority 5, carrying EAPOL
+    data_frame_builder.next_layer(&[
+        0xaa, 0xaa, 0x03, 0x00, 0x00, 0x00, 0x81, 0x00, 0xa0, 0x64, 0x88, 0x8e, 0x02, 0x01,
+    ]);
+
+    let data_frame = data_frame_builder.build();
+    let llc_snap = data_frame.llc_snap().unwrap();
+    assert_eq!(llc_snap.ether_type(), EtherType::VLAN, "ether_type");
+
+    match llc_snap.next_layer() {
+        LlcSnapLayer::VLAN(vlan_tag) => {
+            assert_eq!(vlan_tag.priority(), 5, "priority");
+            assert!(!vlan_tag.drop_eligible(), "drop_eligible");
+            assert_eq!(vlan_tag.vlan_id(), 100, "vlan_id");
+            assert_eq!(vlan_tag.ether_type(), EtherType::EAPOL, "vlan ether_type");
+            assert_eq!(vlan_tag.payload(), [0x02, 0x01], "payload");
+        }
+        _ => panic!("not a vlan tag"),
+    }
+
+    // bridge-tunnel AppleTalk ARP
+    let mut data_frame_builder = DataFrameBuilder::new();
+    data_frame_builder.subtype(FrameSubtype::Data(DataSubtype::Data));
+    data_frame_builder.next_layer(&[0xaa, 0xaa, 0x03, 0x00, 0x00, 0xf8, 0x80, 0xf3]);
+
+    let data_frame = data_frame_builder.build();
+    let llc_snap = data_frame.llc_snap().unwrap();
+    assert_eq!(llc_snap.oui(), BRIDGE_TUNNEL_OUI, "oui");
+    assert!(
+        matches!(
+            llc_snap.next_layer(),
+            LlcSnapLayer::Unknown(EtherType::Unknown(0x80f3), [])
+        ),
+        "unknown"
+    );
+
+    // not LLC/SNAP
+    data_frame_builder.next_layer(b"hello!!");
+    assert!(data_frame_builder.build().llc_snap().is_none(), "llc_snap");
+}
+
+#[test]
+fn test_data_frame_builder_ethernet() {
+    let destination: MacAddress = "22:22:33:44:55:66".parse().unwrap();
+    let source: MacAddress = "11:22:33:44:55:66".parse().unwrap();
+    let bssid: MacAddress = "33:22:33:44:55:66".parse().unwrap();
+
+    let mut ethernet = destination.as_bytes().to_vec();
+    ethernet.extend_from_slice(source.as_bytes());
+    ethernet.extend_from_slice(&[0x08, 0x06, 0x00, 0x01]);
+
+    for ds_status in [
+        DSStatus::NotLeavingDSOrADHOC,
+        DSStatus::FromDSToSTA,
+        DSStatus::FromSTAToDS,
+    ] {
+        let data_frame_builder =
+            DataFrameBuilder::from_ethernet(&ethernet, ds_status, bssid).unwrap();
+        let data_frame = data_frame_builder.build();
+
+        assert_eq!(data_frame.ds_status(), ds_status, "ds_status");
+        assert_eq!(
+            data_frame.bssid_address(),
+            Some(bssid),
+            "{:?} bssid_address",
+            ds_status
+        );
+        assert_eq!(
+            data_frame.to_ethernet().as_ref(),
+            Some(&ethernet),
+            "{:?} to_ethernet",
+            ds_status
+        );
+    }
+
+    let receiver: MacAddress = "44:22:33:44:55:66".parse().unwrap();
+    let data_frame_builder =
+        DataFrameBuilder::from_ethernet_wds(&ethernet, receiver, bssid).unwrap();
+    let data_frame = data_frame_builder.build();
+    assert_eq!(data_frame.addr4(), source, "addr4");
+    assert_eq!(data_frame.receiver_address(), receiver, "receiver_address");
+    assert_eq!(
+        data_frame.transmitter_address(),
+        Some(bssid),
+        "transmitter_address"
+    );
+    assert_eq!(
+        data_frame.to_ethernet().as_ref(),
+        Some(&ethernet),
+        "wds to_ethernet"
+    );
+
+    // IPX keeps the bridge-tunnel encapsulation
+    ethernet[12..14].copy_from_slice(&[0x81, 0x37]);
+    let data_frame_builder =
+        DataFrameBuilder::from_ethernet(&ethernet, DSStatus::FromSTAToDS, bssid).unwrap();
+    assert_eq!(
+        data_frame_builder.build().llc_snap().unwrap().oui(),
+        BRIDGE_TUNNEL_OUI,
+        "oui"
+    );
+
+    // 802.3 length
+    ethernet[12..14].copy_from_slice(&[0x00, 0x04]);
+    assert!(
+        DataFrameBuilder::from_ethernet(&ethernet, DSStatus::FromSTAToDS, bssid).is_none(),
+        "802.3"
+    );
+}
+
+/// Start of an ARP request from 02:00:00:00:00:02, carried by the frames of
+/// the encryption tests
+#[cfg(test)]
+const TEST_ETHERNET: [u8; 22] = [
+    0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x02, 0x00, 0x00, 0x00, 0x00, 0x02, 0x08, 0x06, 0x00, 0x01,
+    0x08, 0x00, 0x06, 0x04, 0x00, 0x01,
+];
+
+#[cfg(test)]
+const TEST_BSSID: MacAddress = MacAddress::new([0x02, 0x00, 0x00, 0x00, 0x00, 0x01]);
+
+#[test]
+fn test_data_frame_builder_wep() {
+    let mut data_frame_builder =
+        DataFrameBuilder::from_ethernet(&TEST_ETHERNET, DSStatus::FromSTAToDS, TEST_BSSID).unwrap();
+    let plaintext = data_frame_builder.bytes().to_vec();
+
+    let key = WEPKey::from_bytes(&[0x11; 13]).unwrap();
+    data_frame_builder
+        .encrypt_wep(&key, 2, [0x01, 0x02, 0x03])
+        .unwrap();
+
+    let data_frame = data_frame_builder.build();
+    assert!(data_frame.protected(), "protected");
+    assert_eq!(data_frame.wep_iv(), Some([0x01, 0x02, 0x03]), "wep_iv");
+    assert_eq!(data_frame.wep_key_index(), Some(2), "wep_key_index");
+    assert_eq!(
+        data_frame.bytes().len(),
+        plaintext.len() + WEP_IV_LENGTH + WEP_ICV_LENGTH,
+        "length"
+    );
+
+    let decrypted = data_frame.decrypt_wep(&key).unwrap();
+    assert_eq!(decrypted.bytes(), &plaintext[..], "decrypt_wep");
+
+    assert_eq!(
+        data_frame_builder.encrypt_wep(&key, 2, [0x01, 0x02, 0x03]),
+        Err(EncryptionError::AlreadyProtected),
+        "encrypted twice"
+    );
+
+    // a four address frame without its fourth address
+    let mut data_frame_builder = DataFrameBuilder::new();
+    data_frame_builder.subtype(FrameSubtype::Data(DataSubtype::Data));
+    data_frame_builder.ds_status(DSStatus::WDSOrMesh);
+    assert_eq!(
+        data_frame_builder.encrypt_wep(&key, 2, [0x01, 0x02, 0x03]),
+        Err(EncryptionError::TooShort),
+        "no addr4"
+    );
+
+    let mut data_frame_builder = DataFrameBuilder::new();
+    data_frame_builder.subtype(FrameSubtype::Data(DataSubtype::Null));
+    assert_eq!(
+        data_frame_builder.encrypt_wep(&key, 2, [0x01, 0x02, 0x03]),
+        Err(EncryptionError::NoFrameBody),
+        "null data"
+    );
+}
+
+#[test]
+fn test_data_frame_builder_protected_qos() {
+    let mut data_frame_builder = DataFrameBuilder::new();
+    data_frame_builder.subtype(FrameSubtype::Data(DataSubtype::QoSData));
+    data_frame_builder.ds_status(DSStatus::FromSTAToDS);
+    data_frame_builder.source_address("02:00:00:00:00:02".parse().unwrap());
+    data_frame_builder.bssid_address("02:00:00:00:00:01".parse().unwrap());
+    data_frame_builder.destination_address("02:00:00:00:00:03".parse().unwrap());
+    data_frame_builder.next_layer(b"hello!!");
+    let plaintext = data_frame_builder.bytes().to_vec();
+
+    let key = CCMPKey::from_bytes(CipherSuiteType::CCMP, &[0x11; 16]).unwrap();
+    data_frame_builder.encrypt_ccmp(&key, 0, 1).unwrap();
+    let ciphertext = data_frame_builder.build().next_layer().unwrap().to_vec();
+    assert_eq!(ciphertext.len(), 7 + 8, "ciphertext");
+
+    // the body goes behind the QoS Control and the CCMP header
+    data_frame_builder.next_layer(&ciphertext);
+    let data_frame = data_frame_builder.build();
+    assert_eq!(data_frame.bytes().len(), 26 + 8 + 7 + 8, "length");
+    assert_eq!(data_frame.next_layer(), Some(&ciphertext[..]), "next_layer");
+    assert_eq!(
+        data_frame.decrypt_ccmp(&key).unwrap().bytes(),
+        &plaintext[..],
+        "decrypt_ccmp"
+    );
+
+    // without a security header it is zeroed, and taken for a WEP IV
+    let mut data_frame_builder = DataFrameBuilder::new();
+    data_frame_builder.subtype(FrameSubtype::Data(DataSubtype::QoSData));
+    data_frame_builder.protected(true);
+    data_frame_builder.next_layer(b"hello!!");
+    let data_frame = data_frame_builder.build();
+    assert_eq!(data_frame.bytes().len(), 26 + 4 + 7, "wep length");
+    assert_eq!(
+        data_frame.next_layer(),
+        Some(&b"hello!!"[..]),
+        "wep next_layer"
+    );
+}
+
+#[test]
+fn test_data_frame_builder_ht_control() {
+    let ht_control_frame_builder = || {
+        let mut data_frame_builder = DataFrameBuilder::new();
+        data_frame_builder.subtype(FrameSubtype::Data(DataSubtype::QoSData));
+        data_frame_builder.ds_status(DSStatus::FromSTAToDS);
+        data_frame_builder.source_address("02:00:00:00:00:02".parse().unwrap());
+        data_frame_builder.bssid_address("02:00:00:00:00:01".parse().unwrap());
+        data_frame_builder.destination_address("02:00:00:00:00:03".parse().unwrap());
+        data_frame_builder.order(true);
+        data_frame_builder.next_layer(b"hello!!");
+        // TID 5, then the HT Control
+        data_frame_builder.bytes_mut()[24] = 5;
+        data_frame_builder.bytes_mut()[26..30].copy_from_slice(&[0x01, 0x02, 0x03, 0x04]);
+        data_frame_builder
+    };
+    let plaintext = ht_control_frame_builder().bytes().to_vec();
+
+    // the body goes behind the QoS Control and the HT Control
+    let data_frame = DataFrame::new(&plaintext[..]);
+    assert_eq!(data_frame.bytes().len(), 30 + 7, "length");
+    assert_eq!(data_frame.next_layer(), Some(&b"hello!!"[..]), "next_layer");
+
+    let key = CCMPKey::from_bytes(CipherSuiteType::CCMP, &[0x11; 16]).unwrap();
+    let mut data_frame_builder = ht_control_frame_builder();
+    data_frame_builder.encrypt_ccmp(&key, 0, 1).unwrap();
+    let mut ciphertext = data_frame_builder.bytes().to_vec();
+    assert_eq!(ciphertext.len(), 30 + 8 + 7 + 8, "ccmp length");
+    assert_eq!(
+        DataFrame::new(&ciphertext[..]).security_header(),
+        Some(SecurityHeader::CCMP(CCMPHeader {
+            packet_number: 1,
+            key_id: 0,
+        })),
+        "security_header"
+    );
+
+    // the HT Control is not covered by the MIC, the TID is
+    ciphertext[26] ^= 0xff;
+    assert_eq!(
+        DataFrame::new(&ciphertext[..])
+            .decrypt_ccmp(&key)
+            .map(|data_frame| data_frame.next_layer().map(<[u8]>::to_vec)),
+        Ok(Some(b"hello!!".to_vec())),
+        "decrypt_ccmp"
+    );
+    ciphertext[24] ^= 0x01;
+    assert_eq!(
+        DataFrame::new(&ciphertext[..]).decrypt_ccmp(&key).err(),
+        Some(DecryptionError::MICMismatch),
+        "tid"
+    );
+
+    let key = TKIPKey::from_bytes(&[0x11; 32]).unwrap();
+    let mut data_frame_builder = ht_control_frame_builder();
+    data_frame_builder.encrypt_tkip(&key, 0, 1).unwrap();
+    assert_eq!(
+        data_frame_builder
+            .build()
+            .decrypt_tkip(&key)
+            .unwrap()
+            .bytes(),
+        &plaintext[..],
+        "decrypt_tkip"
+    );
+}
+
+#[test]
+fn test_data_frame_builder_ccmp() {
+    for (cipher, tk_length, mic_length) in [
+        (CipherSuiteType::CCMP, 16, 8),
+        (CipherSuiteType::CCMP256, 32, 16),
+        (CipherSuiteType::GCMP, 16, 16),
+        (CipherSuiteType::GCMP256, 32, 16),
+    ] {
+        let mut data_frame_builder =
+            DataFrameBuilder::from_ethernet(&TEST_ETHERNET, DSStatus::FromSTAToDS, TEST_BSSID)
+                .unwrap();
+        let plaintext = data_frame_builder.bytes().to_vec();
+
+        let key = CCMPKey::from_bytes(cipher, &vec![0x11; tk_length]).unwrap();
+        data_frame_builder
+            .encrypt_ccmp(&key, 1, 0x0001_0000_0002)
+            .unwrap();
+
+        let data_frame = data_frame_builder.build();
+        assert!(data_frame.protected(), "protected");
+        assert_eq!(
+            data_frame.security_header(),
+            Some(SecurityHeader::CCMP(CCMPHeader {
+                packet_number: 0x0001_0000_0002,
+                key_id: 1,
+            })),
+            "security_header"
+        );
+        assert_eq!(
+            data_frame.bytes().len(),
+            plaintext.len() + CCMP_HEADER_LENGTH + mic_length,
+            "length"
+        );
+
+        let decrypted = data_frame.decrypt_ccmp(&key).unwrap();
+        assert_eq!(decrypted.bytes(), &plaintext[..], "decrypt_ccmp");
+
+        assert_eq!(
+            data_frame_builder.encrypt_ccmp(&key, 1, 0x0001_0000_0003),
+            Err(EncryptionError::AlreadyProtected),
+            "encrypted twice"
+        );
+
+        // a QoS frame without its QoS Control
+        let mut data_frame_builder = DataFrameBuilder::new();
+        data_frame_builder.subtype(FrameSubtype::Data(DataSubtype::QoSData));
+        assert_eq!(
+            data_frame_builder.encrypt_ccmp(&key, 1, 0x0001_0000_0002),
+            Err(EncryptionError::TooShort),
+            "no qos control"
+        );
+    }
+}
+
+#[test]
+fn test_data_frame_builder_tkip() {
+    let mut data_frame_builder =
+        DataFrameBuilder::from_ethernet(&TEST_ETHERNET, DSStatus::FromSTAToDS, TEST_BSSID).unwrap();
+    let plaintext = data_frame_builder.bytes().to_vec();
+
+    let key = TKIPKey::from_bytes(&[0x11; 32]).unwrap();
+    data_frame_builder
+        .encrypt_tkip(&key, 0, 0x0001_0000_ffff)
+        .unwrap();
+
+    let data_frame = data_frame_builder.build();
+    assert!(data_frame.protected(), "protected");
+    assert_eq!(
+        data_frame
+            .security_header()
+            .and_then(|security_header| security_header.packet_number()),
+        Some(0x0001_0000_ffff),
+        "tsc"
+    );
+    assert_eq!(
+        data_frame.bytes().len(),
+        plaintext.len() + TKIP_HEADER_LENGTH + MICHAEL_MIC_LENGTH + WEP_ICV_LENGTH,
+        "length"
+    );
+
+    let decrypted = data_frame.decrypt_tkip(&key).unwrap();
+    assert_eq!(decrypted.bytes(), &plaintext[..], "decrypt_tkip");
+
+    assert_eq!(
+        data_frame_builder.encrypt_tkip(&key, 0, 0x0001_0001_0000),
+        Err(EncryptionError::AlreadyProtected),
+        "encrypted twice"
+    );
+
+    // a QoS frame without its QoS Control
+    let mut data_frame_builder = DataFrameBuilder::new();
+    data_frame_builder.subtype(FrameSubtype::Data(DataSubtype::QoSData));
+    assert_eq!(
+        data_frame_builder.encrypt_tkip(&key, 0, 0x0001_0000_ffff),
+        Err(EncryptionError::TooShort),
+        "no qos control"
+    );
+}
@@ -0,0 +1,250 @@
+use super::*;
+use aes::{Aes128, Aes256};
+use aes_gcm::{
+    aead::{
+        consts::{U13, U16, U8},
+        generic_array::GenericArray,
+        AeadInPlace, KeyInit,
+    },
+    Aes128Gcm, Aes256Gcm,
+};
+use ccm::Ccm;
+
+/// PN, ExtIV and key ID in front of the CCMP or GCMP encrypted frame body
+pub const CCMP_HEADER_LENGTH: usize = 8;
+
+type Ccmp128 = Ccm<Aes128, U8, U13>;
+type Ccmp256 = Ccm<Aes256, U16, U13>;
+
+/// Temporal key of a CCMP or GCMP pairwise or group key
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum CCMPKey {
+    /// CCMP-128, 8 byte MIC
+    CCMP128([u8; 16]),
+    /// CCMP-256, 16 byte MIC
+    CCMP256([u8; 32]),
+    /// GCMP-128, 16 byte MIC
+    GCMP128([u8; 16]),
+    /// GCMP-256, 16 byte MIC
+    GCMP256([u8; 32]),
+}
+
+impl CCMPKey {
+    /// None unless `cipher` is CCMP or GCMP and `bytes` is as long as its TK
+    #[must_use]
+    pub fn from_bytes(cipher: CipherSuiteType, bytes: &[u8]) -> Option<Self> {
+        match (cipher, bytes.len()) {
+            (CipherSuiteType::CCMP, 16) => Some(Self::CCMP128(bytes.try_into().ok()?)),
+            (CipherSuiteType::CCMP256, 32) => Some(Self::CCMP256(bytes.try_into().ok()?)),
+            (CipherSuiteType::GCMP, 16) => Some(Self::GCMP128(bytes.try_into().ok()?)),
+            (CipherSuiteType::GCMP256, 32) => Some(Self::GCMP256(bytes.try_into().ok()?)),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub fn as_bytes(&self) -> &[u8] {
+        match self {
+            Self::CCMP128(key) | Self::GCMP128(key) => key,
+            Self::CCMP256(key) | Self::GCMP256(key) => key,
+        }
+    }
+
+    #[must_use]
+    pub fn cipher_suite_type(&self) -> CipherSuiteType {
+        match self {
+            Self::CCMP128(_) => CipherSuiteType::CCMP,
+            Self::CCMP256(_) => CipherSuiteType::CCMP256,
+            Self::GCMP128(_) => CipherSuiteType::GCMP,
+            Self::GCMP256(_) => CipherSuiteType::GCMP256,
+        }
+    }
+
+    #[must_use]
+    pub fn mic_length(&self) -> usize {
+        match self {
+            Self::CCMP128(_) => 8,
+            _ => 16,
+        }
+    }
+
+    /// MIC of the encrypted `buffer`, GCMP leaves out the flags byte of the
+    /// CCMP nonce
+    fn encrypt(&self, nonce: &[u8; 13], aad: &[u8], buffer: &mut [u8]) -> Option<Vec<u8>> {
+        let gcmp_nonce = GenericArray::from_slice(&nonce[1..]);
+        let nonce = GenericArray::from_slice(nonce);
+
+        let mic = match self {
+            Self::CCMP128(key) => Ccmp128::new(key.into())
+                .encrypt_in_place_detached(nonce, aad, buffer)
+                .ok()?
+                .to_vec(),
+            Self::CCMP256(key) => Ccmp256::new(key.into())
+                .encrypt_in_place_detached(nonce, aad, buffer)
+                .ok()?
+                .to_vec(),
+            Self::GCMP128(key) => Aes128Gcm::new(key.into())
+                .encrypt_in_place_detached(gcmp_nonce, aad, buffer)
+                .ok()?
+                .to_vec(),
+            Self::GCMP256(key) => Aes256Gcm::new(key.into())
+                .encrypt_in_place_detached(gcmp_nonce, aad, buffer)
+                .ok()?
+                .to_vec(),
+        };
+
+        Some(mic)
+    }
+
+    /// False when the MIC does not match
+    fn decrypt(&self, nonce: &[u8; 13], aad: &[u8], buffer: &mut [u8], mic: &[u8]) -> bool {
+        let gcmp_nonce = GenericArray::from_slice(&nonce[1..]);
+        let nonce = GenericArray::from_slice(nonce);
+
+        match self {
+            Self::CCMP128(key) => Ccmp128::new(key.into())
+                .decrypt_in_place_detached(nonce, aad, buffer, GenericArray::from_slice(mic))
+                .is_ok(),
+            Self::CCMP256(key) => Ccmp256::new(key.into())
+                .decrypt_in_place_detached(nonce, aad, buffer, GenericArray::from_slice(mic))
+                .is_ok(),
+            Self::GCMP128(key) => Aes128Gcm::new(key.into())
+                .decrypt_in_place_detached(gcmp_nonce, aad, buffer, GenericArray::from_slice(mic))
+                .is_ok(),
+            Self::GCMP256(key) => Aes256Gcm::new(key.into())
+                .decrypt_in_place_detached(gcmp_nonce, aad, buffer, GenericArray::from_slice(mic))
+                .is_ok(),
+        }
+    }
+}
+
+impl DataFrame<'_> {
+    /// Plaintext frame, without the CCMP header and MIC, checked against the
+    /// MIC
+    pub fn decrypt_ccmp(&self, key: &CCMPKey) -> Result<DataFrame<'static>, DecryptionError> {
+        let mut bytes = self.bytes().to_vec();
+        DataFrame::decrypt_ccmp_in_place(&mut bytes, key)?;
+
+        Ok(DataFrame::new(bytes))
+    }
+
+    /// Decrypts the frame in `bytes` without copying the frame body, on
+    /// success `bytes` holds the plaintext frame with the protected bit
+    /// cleared, after a MIC mismatch the frame body may be overwritten
+    pub fn decrypt_ccmp_in_place(
+        bytes: &mut Vec<u8>,
+        key: &CCMPKey,
+    ) -> Result<(), DecryptionError> {
+        let frame = DataFrame::new(&bytes[..]);
+        let header_length = frame
+            .header_length()
+            .filter(|_| frame.protected())
+            .ok_or(DecryptionError::NotProtected)?;
+
+        let body = &frame.bytes()[header_length.min(bytes.len())..];
+        if body.len() < CCMP_HEADER_LENGTH + key.mic_length() {
+            return Err(DecryptionError::TooShort);
+        }
+        let packet_number = SecurityHeader::from_bytes_with_cipher(body, key.cipher_suite_type())
+            .and_then(|security_header| security_header.packet_number())
+            .ok_or(DecryptionError::NotProtected)?;
+
+        let aad = frame.ccmp_aad(header_length);
+        let nonce = frame.ccmp_nonce(packet_number);
+
+        let payload_start = header_length + CCMP_HEADER_LENGTH;
+        let mic_start = bytes.len() - key.mic_length();
+        let mic = bytes[mic_start..].to_vec();
+
+        if !key.decrypt(&nonce, &aad, &mut bytes[payload_start..mic_start], &mic) {
+            return Err(DecryptionError::MICMismatch);
+        }
+
+        bytes.truncate(mic_start);
+        bytes.drain(header_length..payload_start);
+        bytes[1] &= !0b0100_0000;
+
+        Ok(())
+    }
+
+    /// MAC header with the fields that may change on retransmission masked
+    fn ccmp_aad(&self, header_length: usize) -> Vec<u8> {
+        let bytes = self.bytes();
+        let qos = self.subtype() == FrameSubtype::Data(DataSubtype::QoSData);
+
+        let mut aad = Vec::with_capacity(header_length);
+
+        // subtype bits 4 to 6, retry, power management and more data masked,
+        // protected set, the HT Control is left out
+        aad.push(bytes[0] & 0b1000_1111);
+        let mut flags = (bytes[1] & 0b1100_0111) | 0b0100_0000;
+        if qos {
+            flags &= 0b0111_1111; // order
+        }
+        aad.push(flags);
+
+        // addr1 to addr3
+        aad.extend_from_slice(&bytes[4..22]);
+
+        // sequence number masked, fragment number kept
+        aad.push(bytes[Self::FRAGMENT_SEQUENCE_START] & 0b0000_1111);
+        aad.push(0);
+
+        if self.ds_status() == DSStatus::WDSOrMesh {
+            aad.extend_from_slice(&bytes[24..30]); // addr4
+        }
+
+        if qos {
+            // only the TID of the QoS Control
+            aad.push(self.tid());
+            aad.push(0);
+        }
+
+        aad
+    }
+
+    /// Priority, addr2 and PN5 to PN0, GCMP skips the priority
+    fn ccmp_nonce(&self, packet_number: u64) -> [u8; 13] {
+        let bytes = self.bytes();
+        let mut nonce = [0; 13];
+
+        nonce[0] = self.tid();
+        nonce[1..7].copy_from_slice(&bytes[10..16]);
+        nonce[7..13].copy_from_slice(&packet_number.to_be_bytes()[2..8]);
+
+        nonce
+    }
+}
+
+impl DataFrameBuilder {
+    /// Encrypts the frame body with CCMP or GCMP, puts the CCMP header in
+    /// front and the MIC behind it and sets the protected bit
+    pub fn encrypt_ccmp(
+        &mut self,
+        key: &CCMPKey,
+        key_id: u8,
+        packet_number: u64,
+    ) -> Result<(), EncryptionError> {
+        let frame = self.build();
+        let header_length = frame.encryption_header_length()?;
+
+        // the AAD always has the protected bit set
+        let aad = frame.ccmp_aad(header_length);
+        let nonce = frame.ccmp_nonce(packet_number);
+
+        let mut body = self.bytes()[header_length..].to_vec();
+        let mic = key
+            .encrypt(&nonce, &aad, &mut body)
+            .ok_or(EncryptionError::TooLong)?;
+
+        let pn = packet_number.to_le_bytes();
+        let mut protected_body = vec![pn[0], pn[1], 0, 0b0010_0000 | ((key_id & 0b11) << 6)];
+        protected_body.extend_from_slice(&pn[2..6]);
+        protected_body.extend_from_slice(&body);
+        protected_body.extend_from_slice(&mic);
+        self.replace_frame_body(header_length, &protected_body);
+
+        self.protected(true);
+        Ok(())
+    }
+}
@@ -0,0 +1,98 @@
+use super::*;
+use byteorder::{BigEndian, ByteOrder};
+
+/// Destination address, source address and EtherType
+pub const ETHERNET_HEADER_LENGTH: usize = 14;
+
+impl DataFrame<'_> {
+    /// Ethernet II frame with the destination and source addresses and the
+    /// LLC/SNAP payload, None for protected frames and other payloads
+    ///
+    /// The frame is taken without its FCS, a trailing FCS is taken for the end
+    /// of the payload.
+    pub fn to_ethernet(&self) -> Option<Vec<u8>> {
+        let llc_snap = self.llc_snap()?;
+
+        let mut ethernet = Vec::with_capacity(ETHERNET_HEADER_LENGTH + llc_snap.payload().len());
+        ethernet.extend_from_slice(self.destination_address()?.as_bytes());
+        ethernet.extend_from_slice(self.source_address()?.as_bytes());
+        ethernet.extend_from_slice(&llc_snap.ether_type().into_u16().to_be_bytes());
+        ethernet.extend_from_slice(llc_snap.payload());
+
+        Some(ethernet)
+    }
+}
+
+impl DataFrameBuilder {
+    /// Data frame carrying an Ethernet II frame through the BSS `bssid`
+    ///
+    /// `ds_status` gives the direction, use `from_ethernet_wds` for WDS.
+    #[must_use]
+    pub fn from_ethernet(ethernet: &[u8], ds_status: DSStatus, bssid: MacAddress) -> Option<Self> {
+        if ds_status == DSStatus::WDSOrMesh {
+            return None;
+        }
+        let (destination, source, llc_snap) = read_ethernet(ethernet)?;
+
+        let mut builder = Self::new();
+        builder.subtype(FrameSubtype::Data(DataSubtype::Data));
+        builder.ds_status(ds_status);
+        builder.destination_address(destination);
+        builder.source_address(source);
+        builder.bssid_address(bssid);
+        builder.next_layer(&llc_snap);
+
+        Some(builder)
+    }
+
+    /// Four address data frame carrying an Ethernet II frame from
+    /// `transmitter` to `receiver`
+    #[must_use]
+    pub fn from_ethernet_wds(
+        ethernet: &[u8],
+        receiver: MacAddress,
+        transmitter: MacAddress,
+    ) -> Option<Self> {
+        let (destination, source, llc_snap) = read_ethernet(ethernet)?;
+
+        let mut builder = Self::new();
+        builder.subtype(FrameSubtype::Data(DataSubtype::Data));
+        builder.ds_status(DSStatus::WDSOrMesh);
+        builder.receiver_address(receiver);
+        builder.transmitter_address(transmitter);
+        builder.destination_address(destination);
+        builder.source_address(source);
+        builder.next_layer(&llc_snap);
+
+        Some(builder)
+    }
+}
+
+/// Destination, source and the payload behind an LLC/SNAP header
+fn read_ethernet(ethernet: &[u8]) -> Option<(MacAddress, MacAddress, Vec<u8>)> {
+    if ethernet.len() < ETHERNET_HEADER_LENGTH {
+        return None;
+    }
+
+    let ether_type = BigEndian::read_u16(&ethernet[12..14]);
+    // an 802.3 length field
+    if ether_type < 0x0600 {
+        return None;
+    }
+
+    // AppleTalk ARP and IPX keep the bridge-tunnel encapsulation
+    let oui = match ether_type {
+        0x80f3 | 0x8137 => BRIDGE_TUNNEL_OUI,
+        _ => RFC1042_OUI,
+    };
+
+    let mut llc_snap = vec![0xaa, 0xaa, 0x03];
+    llc_snap.extend_from_slice(&oui);
+    llc_snap.extend_from_slice(&ethernet[12..]);
+
+    Some((
+        MacAddress::from_bytes(&ethernet[0..6]).unwrap(),
+        MacAddress::from_bytes(&ethernet[6..12]).unwrap(),
+        llc_snap,
+    ))
+}
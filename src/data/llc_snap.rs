@@ -0,0 +1,164 @@
+use byteorder::{BigEndian, ByteOrder};
+
+/// RFC 1042 encapsulation
+pub const RFC1042_OUI: [u8; 3] = [0x00, 0x00, 0x00];
+/// 802.1H bridge-tunnel encapsulation, used for AppleTalk ARP and IPX
+pub const BRIDGE_TUNNEL_OUI: [u8; 3] = [0x00, 0x00, 0xf8];
+
+/// 802.2 LLC header with a SNAP extension
+///
+/// DSAP, SSAP, control, OUI and EtherType, followed by the payload.
+pub struct LlcSnap<'a> {
+    bytes: &'a [u8],
+}
+
+pub enum LlcSnapLayer<'a> {
+    IPv4(&'a [u8]),
+    IPv6(&'a [u8]),
+    ARP(&'a [u8]),
+    EAPOL(&'a [u8]),
+    VLAN(VlanTag<'a>),
+    /// 802.11 encapsulated TDLS action frame, from the payload type on
+    TDLS(&'a [u8]),
+    WAI(&'a [u8]),
+    Unknown(EtherType, &'a [u8]),
+}
+
+impl<'a> LlcSnap<'a> {
+    pub const LENGTH: usize = 8;
+
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+
+    /// Whether `bytes` starts with an LLC/SNAP header
+    #[must_use]
+    pub fn is_llc_snap(bytes: &[u8]) -> bool {
+        bytes.len() >= Self::LENGTH && bytes[0..3] == [0xaa, 0xaa, 0x03]
+    }
+
+    pub fn dsap(&self) -> u8 {
+        self.bytes[0]
+    }
+
+    pub fn ssap(&self) -> u8 {
+        self.bytes[1]
+    }
+
+    pub fn control(&self) -> u8 {
+        self.bytes[2]
+    }
+
+    pub fn oui(&self) -> [u8; 3] {
+        [self.bytes[3], self.bytes[4], self.bytes[5]]
+    }
+
+    pub fn ether_type(&self) -> EtherType {
+        EtherType::from_u16(BigEndian::read_u16(&self.bytes[6..8]))
+    }
+
+    pub fn payload(&self) -> &'a [u8] {
+        &self.bytes[Self::LENGTH..]
+    }
+
+    pub fn next_layer(&self) -> LlcSnapLayer<'a> {
+        let payload = self.payload();
+
+        match self.ether_type() {
+            EtherType::IPv4 => LlcSnapLayer::IPv4(payload),
+            EtherType::IPv6 => LlcSnapLayer::IPv6(payload),
+            EtherType::ARP => LlcSnapLayer::ARP(payload),
+            EtherType::EAPOL => LlcSnapLayer::EAPOL(payload),
+            EtherType::VLAN if payload.len() >= 4 => LlcSnapLayer::VLAN(VlanTag::new(payload)),
+            EtherType::TDLS => LlcSnapLayer::TDLS(payload),
+            EtherType::WAI => LlcSnapLayer::WAI(payload),
+            other => LlcSnapLayer::Unknown(other, payload),
+        }
+    }
+}
+
+/// 802.1Q tag, followed by the encapsulated EtherType and payload
+pub struct VlanTag<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> VlanTag<'a> {
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+
+    /// Priority Code Point
+    pub fn priority(&self) -> u8 {
+        self.bytes[0] >> 5
+    }
+
+    /// Drop Eligible Indicator
+    pub fn drop_eligible(&self) -> bool {
+        (self.bytes[0] & 0b0001_0000) != 0
+    }
+
+    pub fn vlan_id(&self) -> u16 {
+        BigEndian::read_u16(&self.bytes[0..2]) & 0x0fff
+    }
+
+    pub fn ether_type(&self) -> EtherType {
+        EtherType::from_u16(BigEndian::read_u16(&self.bytes[2..4]))
+    }
+
+    pub fn payload(&self) -> &'a [u8] {
+        &self.bytes[4..]
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum EtherType {
+    /// Internet Protocol version 4
+    IPv4, // 0x0800
+    /// Address Resolution Protocol
+    ARP, // 0x0806
+    /// 802.1Q VLAN tag
+    VLAN, // 0x8100
+    /// Internet Protocol version 6
+    IPv6, // 0x86dd
+    /// 802.1X authentication
+    EAPOL, // 0x888e
+    /// WAPI Authentication Infrastructure
+    WAI, // 0x88b4
+    /// RSN pre-authentication
+    PreAuthentication, // 0x88c7
+    /// 802.11 encapsulation, used by TDLS
+    TDLS, // 0x890d
+    Unknown(u16),
+}
+
+impl EtherType {
+    #[must_use]
+    pub fn from_u16(n: u16) -> Self {
+        match n {
+            0x0800 => Self::IPv4,
+            0x0806 => Self::ARP,
+            0x8100 => Self::VLAN,
+            0x86dd => Self::IPv6,
+            0x888e => Self::EAPOL,
+            0x88b4 => Self::WAI,
+            0x88c7 => Self::PreAuthentication,
+            0x890d => Self::TDLS,
+            other => Self::Unknown(other),
+        }
+    }
+
+    #[must_use]
+    pub fn into_u16(self) -> u16 {
+        match self {
+            Self::IPv4 => 0x0800,
+            Self::ARP => 0x0806,
+            Self::VLAN => 0x8100,
+            Self::IPv6 => 0x86dd,
+            Self::EAPOL => 0x888e,
+            Self::WAI => 0x88b4,
+            Self::PreAuthentication => 0x88c7,
+            Self::TDLS => 0x890d,
+            Self::Unknown(other) => other,
+        }
+    }
+}
@@ -1,6 +1,16 @@
 mod builder;
-
-pub use self::builder::*;
+mod ccmp;
+mod ethernet;
+mod llc_snap;
+mod protected;
+mod security_header;
+mod tkip;
+mod wep;
+
+pub use self::{
+    builder::*, ccmp::*, ethernet::*, llc_snap::*, protected::*, security_header::*, tkip::*,
+    wep::*,
+};
 use super::*;
 use std::borrow::Cow;
 
@@ -16,16 +26,31 @@ impl<'a> DataFrame<'a> {
     }
 
     pub fn next_layer(&self) -> Option<&[u8]> {
-        let mut index = Self::FRAGMENT_SEQUENCE_START + 2;
+        let mut index = self.header_length()?;
 
         if self.protected() {
-            index += 8; // skip TKIP/CCMP parameters
+            let key_id = *self.bytes().get(index + 3)?;
+            index += security_header_length(key_id);
+        }
+
+        self.bytes().get(index..)
+    }
+
+    /// Length of the MAC header, None for subtypes without a frame body
+    pub(crate) fn header_length(&self) -> Option<usize> {
+        let mut index = Self::FRAGMENT_SEQUENCE_START + 2;
+
+        if self.ds_status() == DSStatus::WDSOrMesh {
+            index += 6; // skip addr4
         }
 
         match self.subtype() {
             FrameSubtype::Data(ref subtype) => match subtype {
                 DataSubtype::QoSData => {
                     index += 2; // skip Qos Control
+                    if self.order() {
+                        index += 4; // skip HT Control
+                    }
                 }
                 DataSubtype::Data => {}
                 _ => return None,
@@ -33,7 +58,35 @@ impl<'a> DataFrame<'a> {
             _ => unreachable!(),
         }
 
-        Some(&self.bytes()[index..])
+        Some(index)
+    }
+
+    /// TID of the QoS Control field, 0 without one
+    pub(crate) fn tid(&self) -> u8 {
+        if self.subtype() != FrameSubtype::Data(DataSubtype::QoSData) {
+            return 0;
+        }
+
+        let mut index = Self::FRAGMENT_SEQUENCE_START + 2;
+        if self.ds_status() == DSStatus::WDSOrMesh {
+            index += 6; // skip addr4
+        }
+
+        self.bytes().get(index).map_or(0, |b| b & 0b0000_1111)
+    }
+
+    /// LLC/SNAP header of an unprotected frame's payload
+    pub fn llc_snap(&self) -> Option<LlcSnap<'_>> {
+        if self.protected() {
+            return None;
+        }
+
+        let payload = self.next_layer()?;
+        if !LlcSnap::is_llc_snap(payload) {
+            return None;
+        }
+
+        Some(LlcSnap::new(payload))
     }
 }
 
@@ -0,0 +1,128 @@
+use super::*;
+use std::{error::Error, fmt};
+
+/// Why a protected frame could not be decrypted
+///
+/// Frames are decrypted without their FCS, a trailing FCS is taken for the
+/// end of the encrypted frame body.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum DecryptionError {
+    /// The frame is not protected, or not with this cipher
+    NotProtected,
+    /// Too short for the security header and trailer of the cipher
+    TooShort,
+    /// The key does not fit the cipher
+    InvalidKey,
+    /// The ICV of the decrypted frame body does not match
+    ICVMismatch,
+    /// The MIC of the frame does not match, the key is wrong or the frame
+    /// was modified
+    MICMismatch,
+}
+
+impl fmt::Display for DecryptionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let reason = match self {
+            Self::NotProtected => "frame is not protected with this cipher",
+            Self::TooShort => "frame is too short",
+            Self::InvalidKey => "key does not fit the cipher",
+            Self::ICVMismatch => "ICV mismatch",
+            Self::MICMismatch => "MIC mismatch",
+        };
+
+        write!(f, "DecryptionError: {}", reason)
+    }
+}
+
+impl Error for DecryptionError {}
+
+/// Why a frame could not be encrypted
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum EncryptionError {
+    /// The protected bit is set already
+    AlreadyProtected,
+    /// The subtype has no frame body to encrypt
+    NoFrameBody,
+    /// Shorter than the MAC header of its subtype and DS status
+    TooShort,
+    /// The frame body is longer than the cipher can encrypt
+    TooLong,
+}
+
+impl fmt::Display for EncryptionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let reason = match self {
+            Self::AlreadyProtected => "frame is protected already",
+            Self::NoFrameBody => "subtype has no frame body",
+            Self::TooShort => "frame is shorter than its header",
+            Self::TooLong => "frame body is too long for the cipher",
+        };
+
+        write!(f, "EncryptionError: {}", reason)
+    }
+}
+
+impl Error for EncryptionError {}
+
+impl DataFrame<'_> {
+    /// Plaintext copy of a protected frame, with the protected bit cleared
+    /// and `plaintext` as the frame body
+    pub(crate) fn with_plaintext(
+        &self,
+        header_length: usize,
+        plaintext: &[u8],
+    ) -> DataFrame<'static> {
+        let mut bytes = Vec::with_capacity(header_length + plaintext.len());
+        bytes.extend_from_slice(&self.bytes()[..header_length]);
+        bytes[1] &= !0b0100_0000;
+        bytes.extend_from_slice(plaintext);
+
+        DataFrame::new(bytes)
+    }
+
+    /// Length of the MAC header of a frame about to be encrypted, checked
+    /// against the frame
+    pub(crate) fn encryption_header_length(&self) -> Result<usize, EncryptionError> {
+        if self.protected() {
+            return Err(EncryptionError::AlreadyProtected);
+        }
+        let header_length = self.header_length().ok_or(EncryptionError::NoFrameBody)?;
+        if self.bytes().len() < header_length {
+            return Err(EncryptionError::TooShort);
+        }
+
+        Ok(header_length)
+    }
+}
+
+/// Length of the security header with key ID byte `key_id`, the WEP IV or
+/// the 8 bytes of TKIP, CCMP and GCMP which set ExtIV
+pub(crate) fn security_header_length(key_id: u8) -> usize {
+    if (key_id & 0b0010_0000) != 0 {
+        8
+    } else {
+        4
+    }
+}
+
+/// RC4 keystream XORed into `data`, as used by WEP and TKIP
+pub(crate) fn rc4(key: &[u8], data: &mut [u8]) {
+    let mut s = [0u8; 256];
+    for (i, b) in s.iter_mut().enumerate() {
+        *b = i as u8;
+    }
+
+    let mut j = 0u8;
+    for i in 0..256 {
+        j = j.wrapping_add(s[i]).wrapping_add(key[i % key.len()]);
+        s.swap(i, j as usize);
+    }
+
+    let (mut i, mut j) = (0u8, 0u8);
+    for b in data {
+        i = i.wrapping_add(1);
+        j = j.wrapping_add(s[i as usize]);
+        s.swap(i as usize, j as usize);
+        *b ^= s[s[i as usize].wrapping_add(s[j as usize]) as usize];
+    }
+}
@@ -0,0 +1,179 @@
+use super::*;
+use byteorder::{ByteOrder, LittleEndian};
+
+/// Parameters between the MAC header and the encrypted frame body of a
+/// protected frame
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum SecurityHeader {
+    /// IV and key ID
+    WEP(WEPHeader),
+    /// TKIP IV and extended IV
+    TKIP(TKIPHeader),
+    /// CCMP header, GCMP uses the same layout
+    CCMP(CCMPHeader),
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct WEPHeader {
+    pub iv: [u8; 3],
+    pub key_id: u8,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct TKIPHeader {
+    /// 48 bit TKIP sequence counter, TSC0 is the least significant byte
+    pub tsc: u64,
+    /// (TSC1 | 0x20) & 0x7f, avoids weak RC4 keys
+    pub wep_seed: u8,
+    pub key_id: u8,
+    /// Always set, the extended IV holds TSC2 to TSC5
+    pub ext_iv: bool,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct CCMPHeader {
+    /// 48 bit packet number, PN0 is the least significant byte
+    pub packet_number: u64,
+    pub key_id: u8,
+}
+
+impl SecurityHeader {
+    /// Guesses the cipher, TKIP when the second byte is the WEP seed of the
+    /// first, CCMP when ExtIV is set otherwise, WEP without ExtIV
+    #[must_use]
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() < 4 {
+            return None;
+        }
+
+        if (bytes[3] & 0b0010_0000) == 0 {
+            return Self::from_bytes_with_cipher(bytes, CipherSuiteType::WEP40);
+        }
+
+        if bytes[1] == ((bytes[0] | 0x20) & 0x7f) {
+            Self::from_bytes_with_cipher(bytes, CipherSuiteType::TKIP)
+        } else {
+            Self::from_bytes_with_cipher(bytes, CipherSuiteType::CCMP)
+        }
+    }
+
+    /// Security header of a frame protected with `cipher`, None when the
+    /// ExtIV bit does not fit the cipher
+    #[must_use]
+    pub fn from_bytes_with_cipher(bytes: &[u8], cipher: CipherSuiteType) -> Option<Self> {
+        let ext_iv = (*bytes.get(3)? & 0b0010_0000) != 0;
+        let key_id = bytes[3] >> 6;
+
+        match cipher {
+            CipherSuiteType::WEP40 | CipherSuiteType::WEP104 if !ext_iv => {
+                Some(Self::WEP(WEPHeader {
+                    iv: [bytes[0], bytes[1], bytes[2]],
+                    key_id,
+                }))
+            }
+            CipherSuiteType::TKIP if ext_iv && bytes.len() >= 8 => Some(Self::TKIP(TKIPHeader {
+                tsc: u64::from(bytes[2])
+                    | (u64::from(bytes[0]) << 8)
+                    | (u64::from(LittleEndian::read_u32(&bytes[4..8])) << 16),
+                wep_seed: bytes[1],
+                key_id,
+                ext_iv,
+            })),
+            CipherSuiteType::CCMP
+            | CipherSuiteType::CCMP256
+            | CipherSuiteType::GCMP
+            | CipherSuiteType::GCMP256
+                if ext_iv && bytes.len() >= 8 =>
+            {
+                Some(Self::CCMP(CCMPHeader {
+                    packet_number: u64::from(bytes[0])
+                        | (u64::from(bytes[1]) << 8)
+                        | (u64::from(LittleEndian::read_u32(&bytes[4..8])) << 16),
+                    key_id,
+                }))
+            }
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub fn length(&self) -> usize {
+        match self {
+            Self::WEP(_) => 4,
+            Self::TKIP(_) | Self::CCMP(_) => 8,
+        }
+    }
+
+    #[must_use]
+    pub fn key_id(&self) -> u8 {
+        match self {
+            Self::WEP(header) => header.key_id,
+            Self::TKIP(header) => header.key_id,
+            Self::CCMP(header) => header.key_id,
+        }
+    }
+
+    /// TSC or PN, for replay detection, None for WEP
+    #[must_use]
+    pub fn packet_number(&self) -> Option<u64> {
+        match self {
+            Self::WEP(_) => None,
+            Self::TKIP(header) => Some(header.tsc),
+            Self::CCMP(header) => Some(header.packet_number),
+        }
+    }
+}
+
+/// Frame body of a protected frame, split at the security header and MIC
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ProtectedPayload<'a> {
+    pub security_header: SecurityHeader,
+    /// Encrypted data, for TKIP with the Michael MIC of the last fragment
+    pub payload: &'a [u8],
+    /// CCMP and GCMP MIC, or the encrypted ICV of WEP and TKIP
+    pub mic: &'a [u8],
+}
+
+impl DataFrame<'_> {
+    /// Security header of a protected frame, the cipher is guessed as in
+    /// `SecurityHeader::from_bytes`
+    pub fn security_header(&self) -> Option<SecurityHeader> {
+        if !self.protected() {
+            return None;
+        }
+
+        SecurityHeader::from_bytes(self.bytes().get(self.header_length()?..)?)
+    }
+
+    /// Encrypted payload and MIC of a frame protected with `cipher`, the
+    /// frame without its FCS
+    pub fn protected_payload(&self, cipher: CipherSuiteType) -> Option<ProtectedPayload<'_>> {
+        if !self.protected() {
+            return None;
+        }
+
+        let mic_length = match cipher {
+            CipherSuiteType::WEP40 | CipherSuiteType::WEP104 | CipherSuiteType::TKIP => {
+                WEP_ICV_LENGTH
+            }
+            CipherSuiteType::CCMP => 8,
+            CipherSuiteType::CCMP256 | CipherSuiteType::GCMP | CipherSuiteType::GCMP256 => 16,
+            _ => return None,
+        };
+
+        let body = self.bytes().get(self.header_length()?..)?;
+        let security_header = SecurityHeader::from_bytes_with_cipher(body, cipher)?;
+
+        let body = &body[security_header.length()..];
+        if body.len() < mic_length {
+            return None;
+        }
+        let (payload, mic) = body.split_at(body.len() - mic_length);
+
+        Some(ProtectedPayload {
+            security_header,
+            payload,
+            mic,
+        })
+    }
+}
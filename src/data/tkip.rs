@@ -0,0 +1,351 @@
+use super::*;
+use byteorder::{ByteOrder, LittleEndian};
+use std::sync::OnceLock;
+
+/// IV and extended IV in front of the TKIP encrypted frame body
+pub const TKIP_HEADER_LENGTH: usize = 8;
+/// Michael MIC at the end of the MSDU, in the last fragment
+pub const MICHAEL_MIC_LENGTH: usize = 8;
+
+/// Temporal key of a TKIP pairwise or group key, as it is laid out in the
+/// PTK and GTK
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TKIPKey {
+    pub tk: [u8; 16],
+    /// Michael key of frames from the authenticator
+    pub tx_mic_key: [u8; 8],
+    /// Michael key of frames to the authenticator
+    pub rx_mic_key: [u8; 8],
+}
+
+impl TKIPKey {
+    /// None unless `bytes` is 32 bytes long
+    #[must_use]
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != 32 {
+            return None;
+        }
+
+        Some(Self {
+            tk: bytes[..16].try_into().ok()?,
+            tx_mic_key: bytes[16..24].try_into().ok()?,
+            rx_mic_key: bytes[24..32].try_into().ok()?,
+        })
+    }
+
+    /// RC4 key of a frame, from the phase 1 and phase 2 key mixing of the
+    /// TK with the transmitter address and TSC
+    fn per_frame_key(&self, transmitter_address: &[u8], tsc: u64) -> [u8; 16] {
+        let tk = |i: usize| LittleEndian::read_u16(&self.tk[i..(i + 2)]);
+        let iv16 = tsc as u16;
+        let iv32 = (tsc >> 16) as u32;
+
+        // phase 1, only changes every 65536 frames
+        let mut ttak = [
+            iv32 as u16,
+            (iv32 >> 16) as u16,
+            LittleEndian::read_u16(&transmitter_address[0..2]),
+            LittleEndian::read_u16(&transmitter_address[2..4]),
+            LittleEndian::read_u16(&transmitter_address[4..6]),
+        ];
+        for i in 0..8 {
+            let j = 2 * (i & 1);
+            ttak[0] = ttak[0].wrapping_add(tkip_s(ttak[4] ^ tk(j)));
+            ttak[1] = ttak[1].wrapping_add(tkip_s(ttak[0] ^ tk(4 + j)));
+            ttak[2] = ttak[2].wrapping_add(tkip_s(ttak[1] ^ tk(8 + j)));
+            ttak[3] = ttak[3].wrapping_add(tkip_s(ttak[2] ^ tk(12 + j)));
+            ttak[4] = ttak[4]
+                .wrapping_add(tkip_s(ttak[3] ^ tk(j)))
+                .wrapping_add(i as u16);
+        }
+
+        // phase 2
+        let mut ppk = [
+            ttak[0],
+            ttak[1],
+            ttak[2],
+            ttak[3],
+            ttak[4],
+            ttak[4].wrapping_add(iv16),
+        ];
+        for i in 0..6 {
+            ppk[i] = ppk[i].wrapping_add(tkip_s(ppk[(i + 5) % 6] ^ tk(2 * i)));
+        }
+        ppk[0] = ppk[0].wrapping_add((ppk[5] ^ tk(12)).rotate_right(1));
+        ppk[1] = ppk[1].wrapping_add((ppk[0] ^ tk(14)).rotate_right(1));
+        for i in 2..6 {
+            ppk[i] = ppk[i].wrapping_add(ppk[i - 1].rotate_right(1));
+        }
+
+        // the first 3 bytes are the WEP IV
+        let mut key = [0; 16];
+        key[0] = (iv16 >> 8) as u8;
+        key[1] = (key[0] | 0x20) & 0x7f;
+        key[2] = iv16 as u8;
+        key[3] = ((ppk[5] ^ tk(0)) >> 1) as u8;
+        for (i, p) in ppk.iter().enumerate() {
+            LittleEndian::write_u16(&mut key[(4 + 2 * i)..(6 + 2 * i)], *p);
+        }
+
+        key
+    }
+}
+
+/// 16 bit S-box of the key mixing, the AES S-box multiplied by 2 and 3
+fn tkip_s(v: u16) -> u16 {
+    static S_BOX: OnceLock<[u16; 256]> = OnceLock::new();
+
+    let s_box = S_BOX.get_or_init(|| {
+        let mut s_box = [0; 256];
+        for (i, s) in s_box.iter_mut().enumerate() {
+            let a = aes_s_box(i as u8);
+            let a2 = (a << 1) ^ (if (a & 0x80) != 0 { 0x1b } else { 0 });
+            *s = (u16::from(a2) << 8) | u16::from(a2 ^ a);
+        }
+        s_box
+    });
+
+    s_box[(v & 0xff) as usize] ^ s_box[(v >> 8) as usize].swap_bytes()
+}
+
+/// Multiplicative inverse in GF(2^8) followed by the affine transformation
+fn aes_s_box(x: u8) -> u8 {
+    let gf_mul = |mut a: u8, mut b: u8| {
+        let mut p = 0;
+        while b != 0 {
+            if (b & 1) != 0 {
+                p ^= a;
+            }
+            a = (a << 1) ^ (if (a & 0x80) != 0 { 0x1b } else { 0 });
+            b >>= 1;
+        }
+        p
+    };
+
+    // x^254, 0 stays 0
+    let mut inverse = 1;
+    for _ in 0..254 {
+        inverse = gf_mul(inverse, x);
+    }
+
+    inverse
+        ^ inverse.rotate_left(1)
+        ^ inverse.rotate_left(2)
+        ^ inverse.rotate_left(3)
+        ^ inverse.rotate_left(4)
+        ^ 0x63
+}
+
+/// Michael MIC of `message`
+fn michael(key: &[u8; 8], message: &[u8]) -> [u8; 8] {
+    // 0x5a and 4 to 7 zeros
+    let mut data = Vec::with_capacity(message.len() + 8);
+    data.extend_from_slice(message);
+    data.push(0x5a);
+    data.resize((data.len() + 4).next_multiple_of(4), 0);
+
+    let mut l = LittleEndian::read_u32(&key[0..4]);
+    let mut r = LittleEndian::read_u32(&key[4..8]);
+    for block in data.chunks_exact(4) {
+        l ^= LittleEndian::read_u32(block);
+
+        r ^= l.rotate_left(17);
+        l = l.wrapping_add(r);
+        r ^= ((l & 0xff00_ff00) >> 8) | ((l & 0x00ff_00ff) << 8);
+        l = l.wrapping_add(r);
+        r ^= l.rotate_left(3);
+        l = l.wrapping_add(r);
+        r ^= l.rotate_right(2);
+        l = l.wrapping_add(r);
+    }
+
+    let mut mic = [0; 8];
+    LittleEndian::write_u32(&mut mic[0..4], l);
+    LittleEndian::write_u32(&mut mic[4..8], r);
+    mic
+}
+
+impl DataFrame<'_> {
+    /// Plaintext frame, without the IV, extended IV and ICV, checked against
+    /// the ICV
+    ///
+    /// Unfragmented frames are also checked against the Michael MIC, which
+    /// is removed, fragments keep it for `verify_michael_mic` after
+    /// reassembly.
+    pub fn decrypt_tkip(&self, key: &TKIPKey) -> Result<DataFrame<'static>, DecryptionError> {
+        let header_length = self
+            .header_length()
+            .filter(|_| self.protected())
+            .ok_or(DecryptionError::NotProtected)?;
+
+        let body = &self.bytes()[header_length.min(self.bytes().len())..];
+        if body.len() < TKIP_HEADER_LENGTH + WEP_ICV_LENGTH {
+            return Err(DecryptionError::TooShort);
+        }
+        let tsc = SecurityHeader::from_bytes_with_cipher(body, CipherSuiteType::TKIP)
+            .and_then(|security_header| security_header.packet_number())
+            .ok_or(DecryptionError::NotProtected)?;
+
+        let mut plaintext = body[TKIP_HEADER_LENGTH..].to_vec();
+        rc4(
+            &key.per_frame_key(self.addr2().as_bytes(), tsc),
+            &mut plaintext,
+        );
+
+        let icv = plaintext.split_off(plaintext.len() - WEP_ICV_LENGTH);
+        if LittleEndian::read_u32(&icv) != crc32(&plaintext) {
+            return Err(DecryptionError::ICVMismatch);
+        }
+
+        if !self.more_fragments() && self.fragment_number() == 0 {
+            let msdu_length = self.verify_michael_mic(key, &plaintext)?.len();
+            plaintext.truncate(msdu_length);
+        }
+
+        Ok(self.with_plaintext(header_length, &plaintext))
+    }
+
+    /// MSDU without the Michael MIC at its end, `msdu` being the decrypted
+    /// frame bodies of all fragments of this frame's MSDU concatenated
+    pub fn verify_michael_mic<'b>(
+        &self,
+        key: &TKIPKey,
+        msdu: &'b [u8],
+    ) -> Result<&'b [u8], DecryptionError> {
+        if msdu.len() < MICHAEL_MIC_LENGTH {
+            return Err(DecryptionError::TooShort);
+        }
+
+        let (msdu, mic) = msdu.split_at(msdu.len() - MICHAEL_MIC_LENGTH);
+        if mic != self.michael_mic(key, msdu) {
+            return Err(DecryptionError::MICMismatch);
+        }
+
+        Ok(msdu)
+    }
+
+    fn michael_mic(&self, key: &TKIPKey, msdu: &[u8]) -> [u8; 8] {
+        let michael_key = if self.ds_status() == DSStatus::FromSTAToDS {
+            &key.rx_mic_key
+        } else {
+            &key.tx_mic_key
+        };
+
+        let priority = self.tid();
+
+        // DA, SA and priority in front of the MSDU
+        let mut message = Vec::with_capacity(16 + msdu.len());
+        message.extend_from_slice(self.destination_address().unwrap().as_bytes());
+        message.extend_from_slice(self.source_address().unwrap().as_bytes());
+        message.extend_from_slice(&[priority, 0, 0, 0]);
+        message.extend_from_slice(msdu);
+
+        michael(michael_key, &message)
+    }
+}
+
+impl DataFrameBuilder {
+    /// Appends the Michael MIC and ICV to the frame body, encrypts them with
+    /// the key mixed for `tsc`, puts the IV and extended IV in front and sets
+    /// the protected bit
+    pub fn encrypt_tkip(
+        &mut self,
+        key: &TKIPKey,
+        key_id: u8,
+        tsc: u64,
+    ) -> Result<(), EncryptionError> {
+        let frame = self.build();
+        let header_length = frame.encryption_header_length()?;
+
+        let mut body = self.bytes()[header_length..].to_vec();
+        let mic = frame.michael_mic(key, &body);
+        body.extend_from_slice(&mic);
+        body.extend_from_slice(&crc32(&body).to_le_bytes());
+        rc4(&key.per_frame_key(frame.addr2().as_bytes(), tsc), &mut body);
+
+        let tsc = tsc.to_le_bytes();
+        let mut protected_body = vec![
+            tsc[1],
+            (tsc[1] | 0x20) & 0x7f,
+            tsc[0],
+            0b0010_0000 | ((key_id & 0b11) << 6),
+        ];
+        protected_body.extend_from_slice(&tsc[2..6]);
+        protected_body.extend_from_slice(&body);
+        self.replace_frame_body(header_length, &protected_body);
+
+        self.protected(true);
+        Ok(())
+    }
+}
+
+#[test]
+fn test_tkip_key_mixing() {
+    // test vectors of IEEE 802.11, TKIP mixing function
+    let tk: Vec<u8> = (0..16).collect();
+    let ta = [0x10, 0x22, 0x33, 0x44, 0x55, 0x66];
+    let other_tk = [
+        0x63, 0x89, 0x3b, 0x25, 0x08, 0x40, 0xb8, 0xae, 0x0b, 0xd0, 0xfa, 0x7e, 0x61, 0xd2, 0x78,
+        0x3e,
+    ];
+    let other_ta = [0x64, 0xf2, 0xea, 0xed, 0xdc, 0x25];
+
+    for (tk, ta, tsc, rc4_key) in [
+        (
+            &tk[..],
+            ta,
+            0x0000_0000_0000,
+            [
+                0x00, 0x20, 0x00, 0x33, 0xea, 0x8d, 0x2f, 0x60, 0xca, 0x6d, 0x13, 0x74, 0x23, 0x4a,
+                0x66, 0x0b,
+            ],
+        ),
+        (
+            &tk[..],
+            ta,
+            0x0000_0000_0001,
+            [
+                0x00, 0x20, 0x01, 0x90, 0xff, 0xdc, 0x31, 0x43, 0x89, 0xa9, 0xd9, 0xd0, 0x74, 0xfd,
+                0x20, 0xaa,
+            ],
+        ),
+        (
+            &other_tk[..],
+            other_ta,
+            0x20dc_fd43_ffff,
+            [
+                0xff, 0x7f, 0xff, 0x93, 0x81, 0x0f, 0xc6, 0xe5, 0x8f, 0x5d, 0xd3, 0x26, 0x25, 0x15,
+                0x44, 0xce,
+            ],
+        ),
+        (
+            &other_tk[..],
+            other_ta,
+            0x20dc_fd44_0000,
+            [
+                0x00, 0x20, 0x00, 0x49, 0x8c, 0xa4, 0x71, 0xfc, 0xfb, 0xfa, 0xa1, 0x6e, 0x36, 0x10,
+                0xf0, 0x05,
+            ],
+        ),
+    ] {
+        let key = TKIPKey::from_bytes(&[tk, &[0; 16]].concat()).unwrap();
+        assert_eq!(key.per_frame_key(&ta, tsc), rc4_key, "tsc {:012x}", tsc);
+    }
+}
+
+#[test]
+fn test_tkip_michael() {
+    // test vectors of IEEE 802.11, each MIC is the key of the next message
+    let mut key = [0; 8];
+    for (message, mic) in [
+        (&b""[..], 0x8292_5c1c_a1d1_30b8_u64),
+        (b"M", 0x4347_21ca_4063_9b3f),
+        (b"Mi", 0xe8f9_beca_e97e_5d29),
+        (b"Mic", 0x9003_8fc6_cf13_c1db),
+        (b"Mich", 0xd55e_1005_1012_8986),
+        (b"Michael", 0x0a94_2b12_4eca_a546),
+    ] {
+        key = michael(&key, message);
+        assert_eq!(key, mic.to_be_bytes(), "{:?}", message);
+    }
+}
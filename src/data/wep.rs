@@ -0,0 +1,128 @@
+use super::*;
+use byteorder::{ByteOrder, LittleEndian};
+
+/// IV and key ID in front of the WEP encrypted frame body
+pub const WEP_IV_LENGTH: usize = 4;
+/// ICV at the end of the WEP encrypted frame body
+pub const WEP_ICV_LENGTH: usize = 4;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum WEPKey {
+    /// 40 bit key, WEP-64 with the IV
+    WEP40([u8; 5]),
+    /// 104 bit key, WEP-128 with the IV
+    WEP104([u8; 13]),
+}
+
+impl WEPKey {
+    /// None unless `bytes` is 5 or 13 bytes long
+    #[must_use]
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        match bytes.len() {
+            5 => {
+                let mut key = [0; 5];
+                key.copy_from_slice(bytes);
+                Some(Self::WEP40(key))
+            }
+            13 => {
+                let mut key = [0; 13];
+                key.copy_from_slice(bytes);
+                Some(Self::WEP104(key))
+            }
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub fn as_bytes(&self) -> &[u8] {
+        match self {
+            Self::WEP40(key) => key,
+            Self::WEP104(key) => key,
+        }
+    }
+
+    /// RC4 key of a frame, the IV followed by the key
+    fn per_frame_key(&self, iv: [u8; 3]) -> Vec<u8> {
+        let mut key = iv.to_vec();
+        key.extend_from_slice(self.as_bytes());
+        key
+    }
+}
+
+impl DataFrame<'_> {
+    /// Protected without the ExtIV bit of TKIP, CCMP and GCMP
+    pub fn is_wep(&self) -> bool {
+        self.protected()
+            && self
+                .header_length()
+                .and_then(|header_length| self.bytes().get(header_length + 3))
+                .is_some_and(|key_id| (key_id & 0b0010_0000) == 0)
+    }
+
+    /// IV of a WEP protected frame
+    pub fn wep_iv(&self) -> Option<[u8; 3]> {
+        if !self.is_wep() {
+            return None;
+        }
+
+        let start = self.header_length()?;
+        let mut iv = [0; 3];
+        iv.copy_from_slice(&self.bytes()[start..(start + 3)]);
+        Some(iv)
+    }
+
+    /// Which of the 4 WEP keys a WEP protected frame uses
+    pub fn wep_key_index(&self) -> Option<u8> {
+        if !self.is_wep() {
+            return None;
+        }
+
+        Some(self.bytes()[self.header_length()? + 3] >> 6)
+    }
+
+    /// Plaintext frame, without the IV and ICV, checked against the ICV
+    pub fn decrypt_wep(&self, key: &WEPKey) -> Result<DataFrame<'static>, DecryptionError> {
+        let iv = self.wep_iv().ok_or(DecryptionError::NotProtected)?;
+        let header_length = self.header_length().ok_or(DecryptionError::NotProtected)?;
+
+        let body = &self.bytes()[(header_length + WEP_IV_LENGTH)..];
+        if body.len() < WEP_ICV_LENGTH {
+            return Err(DecryptionError::TooShort);
+        }
+
+        let mut plaintext = body.to_vec();
+        rc4(&key.per_frame_key(iv), &mut plaintext);
+
+        let icv = plaintext.split_off(plaintext.len() - WEP_ICV_LENGTH);
+        if LittleEndian::read_u32(&icv) != crc32(&plaintext) {
+            return Err(DecryptionError::ICVMismatch);
+        }
+
+        Ok(self.with_plaintext(header_length, &plaintext))
+    }
+}
+
+impl DataFrameBuilder {
+    /// Appends the ICV to the frame body, encrypts both with WEP, puts the IV
+    /// and key ID in front and sets the protected bit
+    pub fn encrypt_wep(
+        &mut self,
+        key: &WEPKey,
+        key_index: u8,
+        iv: [u8; 3],
+    ) -> Result<(), EncryptionError> {
+        let header_length = self.build().encryption_header_length()?;
+
+        let mut body = self.bytes()[header_length..].to_vec();
+        body.extend_from_slice(&crc32(&body).to_le_bytes());
+        rc4(&key.per_frame_key(iv), &mut body);
+
+        let mut protected_body = iv.to_vec();
+        protected_body.push((key_index & 0b11) << 6);
+        protected_body.extend_from_slice(&body);
+        self.replace_frame_body(header_length, &protected_body);
+
+        self.protected(true);
+        Ok(())
+    }
+}
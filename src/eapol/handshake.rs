@@ -0,0 +1,427 @@
+use super::*;
+use std::collections::HashMap;
+
+/// Which two messages of the 4-way handshake a record was assembled from
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum HandshakeMessagePair {
+    /// ANonce from message 1, SNonce and MIC from message 2, the station may
+    /// not know the passphrase
+    M1M2,
+    /// ANonce from message 1, SNonce and MIC from message 4
+    M1M4,
+    /// SNonce and MIC from message 2, ANonce from message 3
+    M2M3,
+    /// ANonce from message 3, SNonce and MIC from message 4
+    M3M4,
+}
+
+impl HandshakeMessagePair {
+    /// The authenticator answered the station, so the MIC was valid
+    #[must_use]
+    pub fn authorized(self) -> bool {
+        self != Self::M1M2
+    }
+
+    /// Message whose EAPOL frame and MIC the record holds
+    #[must_use]
+    pub fn eapol_message(self) -> EAPOLKeyMessage {
+        match self {
+            Self::M1M2 | Self::M2M3 => EAPOLKeyMessage::Message2,
+            Self::M1M4 | Self::M3M4 => EAPOLKeyMessage::Message4,
+        }
+    }
+}
+
+/// One EAPOL-Key frame of a handshake attempt
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct HandshakeMessage {
+    pub message: EAPOLKeyMessage,
+    pub replay_counter: u64,
+    pub nonce: [u8; 32],
+    pub mic: Vec<u8>,
+    /// EAPOL header and body, without padding
+    pub eapol: Vec<u8>,
+}
+
+impl HandshakeMessage {
+    fn new(message: EAPOLKeyMessage, eapol: &EAPOL, key: &EAPOLKey) -> Self {
+        let mut nonce = [0; 32];
+        nonce.copy_from_slice(key.nonce());
+
+        Self {
+            message,
+            replay_counter: key.replay_counter(),
+            nonce,
+            mic: key.mic().unwrap_or_default().to_vec(),
+            eapol: eapol.frame().to_vec(),
+        }
+    }
+
+    fn has_nonce(&self) -> bool {
+        self.nonce.iter().any(|&b| b != 0)
+    }
+}
+
+/// A crackable pair of 4-way handshake messages
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Handshake {
+    pub bssid: MacAddress,
+    pub station: MacAddress,
+    /// SSID of the BSSID's beacons and probe responses seen so far
+    pub ssid: Option<Vec<u8>>,
+    pub message_pair: HandshakeMessagePair,
+    pub anonce: [u8; 32],
+    pub snonce: [u8; 32],
+    /// Replay counter of the message holding the MIC
+    pub replay_counter: u64,
+    /// false when the messages were only paired because nothing else was
+    /// available, the ANonce may then need nonce error correction
+    pub replay_counter_matched: bool,
+    /// The BSSID's ANonces were seen counting up, in this byte order
+    pub nonce_counter: Option<NonceCounterEndianness>,
+    pub mic: Vec<u8>,
+    /// EAPOL frame the MIC was computed over, with the MIC in it
+    pub eapol: Vec<u8>,
+    /// Every EAPOL-Key frame of the attempt so far, in capture order
+    pub messages: Vec<HandshakeMessage>,
+}
+
+/// The EAPOL-Key frames seen between a station and an authenticator since the
+/// last message 1 with a new ANonce
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct HandshakeAttempt {
+    pub bssid: MacAddress,
+    pub station: MacAddress,
+    pub messages: Vec<HandshakeMessage>,
+    pub retransmissions: usize,
+    pairs: Vec<HandshakeMessagePair>,
+}
+
+impl HandshakeAttempt {
+    fn new(bssid: MacAddress, station: MacAddress) -> Self {
+        Self {
+            bssid,
+            station,
+            messages: Vec::new(),
+            retransmissions: 0,
+            pairs: Vec::new(),
+        }
+    }
+
+    /// ANonce of the latest message 1 or 3
+    #[must_use]
+    pub fn anonce(&self) -> Option<[u8; 32]> {
+        self.messages
+            .iter()
+            .rev()
+            .find(|message| {
+                matches!(
+                    message.message,
+                    EAPOLKeyMessage::Message1 | EAPOLKeyMessage::Message3
+                )
+            })
+            .map(|message| message.nonce)
+    }
+
+    /// Message 4 was seen
+    #[must_use]
+    pub fn is_complete(&self) -> bool {
+        self.has(EAPOLKeyMessage::Message4)
+    }
+
+    /// Crackable pairs found in this attempt
+    #[must_use]
+    pub fn message_pairs(&self) -> &[HandshakeMessagePair] {
+        &self.pairs
+    }
+
+    fn has(&self, message: EAPOLKeyMessage) -> bool {
+        self.messages.iter().any(|m| m.message == message)
+    }
+
+    fn is_retransmission(&self, new: &HandshakeMessage) -> bool {
+        self.messages.iter().any(|m| {
+            m.message == new.message
+                && m.nonce == new.nonce
+                && (new.has_nonce() || m.replay_counter == new.replay_counter)
+        })
+    }
+
+    /// Latest message of a kind, preferring the one with `replay_counter`
+    fn find(
+        &self,
+        message: EAPOLKeyMessage,
+        replay_counter: u64,
+    ) -> Option<(&HandshakeMessage, bool)> {
+        let mut candidates = self.messages.iter().rev().filter(|m| m.message == message);
+
+        candidates
+            .clone()
+            .find(|m| m.replay_counter == replay_counter)
+            .map(|m| (m, true))
+            .or_else(|| candidates.next().map(|m| (m, false)))
+    }
+
+    /// Every pair made possible by the latest message
+    fn new_pairs(
+        &self,
+    ) -> Vec<(
+        HandshakeMessagePair,
+        &HandshakeMessage,
+        &HandshakeMessage,
+        bool,
+    )> {
+        let latest = match self.messages.last() {
+            Some(latest) => latest,
+            None => return Vec::new(),
+        };
+        let rc = latest.replay_counter;
+
+        let pairs = match latest.message {
+            EAPOLKeyMessage::Message2 => vec![
+                (HandshakeMessagePair::M1M2, EAPOLKeyMessage::Message1, rc),
+                (
+                    HandshakeMessagePair::M2M3,
+                    EAPOLKeyMessage::Message3,
+                    rc.wrapping_add(1),
+                ),
+            ],
+            EAPOLKeyMessage::Message3 => vec![(
+                HandshakeMessagePair::M2M3,
+                EAPOLKeyMessage::Message2,
+                rc.wrapping_sub(1),
+            )],
+            // the SNonce is only repeated in message 4 by some stations
+            EAPOLKeyMessage::Message4 if latest.has_nonce() => vec![
+                (HandshakeMessagePair::M3M4, EAPOLKeyMessage::Message3, rc),
+                (
+                    HandshakeMessagePair::M1M4,
+                    EAPOLKeyMessage::Message1,
+                    rc.wrapping_sub(1),
+                ),
+            ],
+            _ => Vec::new(),
+        };
+
+        pairs
+            .into_iter()
+            .filter(|(pair, _, _)| !self.pairs.contains(pair))
+            .filter_map(|(pair, other, replay_counter)| {
+                let (other, matched) = self.find(other, replay_counter)?;
+                Some((pair, latest, other, matched))
+            })
+            .collect()
+    }
+}
+
+/// What a frame changed in the tracked handshakes
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum HandshakeEvent {
+    /// A new crackable pair of messages
+    Complete(Handshake),
+    /// A message already seen in the attempt, with the same nonce
+    Retransmission {
+        bssid: MacAddress,
+        station: MacAddress,
+        message: EAPOLKeyMessage,
+    },
+    /// An attempt replaced by a new message 1 before it got to message 2
+    Incomplete(HandshakeAttempt),
+    /// An attempt that got message 2 but was deauthenticated, disassociated
+    /// or restarted before message 4, usually a wrong passphrase
+    Failed(HandshakeAttempt),
+}
+
+/// Assembles 4-way handshakes from captured frames
+///
+/// Attempts are keyed by (BSSID, station), SSIDs are learned from beacons,
+/// probe responses and (re)association requests.
+#[derive(Debug, Default)]
+pub struct HandshakeTracker {
+    ssids: HashMap<MacAddress, Vec<u8>>,
+    anonces: HashMap<MacAddress, [u8; 32]>,
+    nonce_counters: HashMap<MacAddress, NonceCounterEndianness>,
+    attempts: HashMap<(MacAddress, MacAddress), HandshakeAttempt>,
+}
+
+impl HandshakeTracker {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// SSID learned for a BSSID
+    #[must_use]
+    pub fn ssid(&self, bssid: MacAddress) -> Option<&[u8]> {
+        self.ssids.get(&bssid).map(AsRef::as_ref)
+    }
+
+    /// Attempt in progress between a BSSID and a station
+    #[must_use]
+    pub fn attempt(&self, bssid: MacAddress, station: MacAddress) -> Option<&HandshakeAttempt> {
+        self.attempts.get(&(bssid, station))
+    }
+
+    /// Every attempt in progress
+    pub fn attempts(&self) -> impl Iterator<Item = &HandshakeAttempt> + '_ {
+        self.attempts.values()
+    }
+
+    /// Everything the frame changed, in order: an attempt it ended, then a
+    /// retransmission, then every pair it completed
+    pub fn add_frame(&mut self, frame: &Frame) -> Vec<HandshakeEvent> {
+        let events = match frame.next_layer() {
+            Some(FrameLayer::Management(management_frame)) => {
+                self.add_management_frame(&management_frame)
+            }
+            Some(FrameLayer::Data(data_frame)) => self.add_data_frame(&data_frame),
+            _ => None,
+        };
+
+        events.unwrap_or_default()
+    }
+
+    fn add_management_frame(
+        &mut self,
+        management_frame: &ManagementFrame,
+    ) -> Option<Vec<HandshakeEvent>> {
+        let bssid = management_frame.bssid_address()?;
+
+        let ssid = match management_frame.next_layer()? {
+            ManagementFrameLayer::Beacon(beacon_frame) => beacon_frame.ssid(),
+            ManagementFrameLayer::ProbeResponse(probe_response_frame) => {
+                probe_response_frame.ssid()
+            }
+            ManagementFrameLayer::AssociationRequest(association_request_frame) => {
+                association_request_frame.ssid()
+            }
+            ManagementFrameLayer::ReassociationRequest(reassociation_request_frame) => {
+                reassociation_request_frame.ssid()
+            }
+            ManagementFrameLayer::Deauthentication(_) | ManagementFrameLayer::Disassociate(_) => {
+                let station = if management_frame.receiver_address() == bssid {
+                    management_frame.transmitter_address()?
+                } else {
+                    management_frame.receiver_address()
+                };
+                return Some(self.end_attempt(bssid, station));
+            }
+            _ => None,
+        };
+
+        // hidden SSIDs are empty or zeroed
+        if let Some(ssid) = ssid.filter(|ssid| ssid.iter().any(|&b| b != 0)) {
+            self.ssids.insert(bssid, ssid);
+        }
+
+        None
+    }
+
+    fn end_attempt(&mut self, bssid: MacAddress, station: MacAddress) -> Vec<HandshakeEvent> {
+        // every station of the BSS
+        let stations: Vec<_> = if station.is_broadcast() {
+            self.attempts
+                .keys()
+                .filter(|(b, _)| *b == bssid)
+                .map(|(_, station)| *station)
+                .collect()
+        } else {
+            vec![station]
+        };
+
+        stations
+            .into_iter()
+            .filter_map(|station| self.attempts.remove(&(bssid, station)))
+            .filter(|attempt| attempt.has(EAPOLKeyMessage::Message2) && !attempt.is_complete())
+            .map(HandshakeEvent::Failed)
+            .collect()
+    }
+
+    fn add_data_frame(&mut self, data_frame: &DataFrame) -> Option<Vec<HandshakeEvent>> {
+        let bssid = data_frame.bssid_address()?;
+        let station = data_frame.station_address()?;
+
+        let eapol = data_frame.eapol()?;
+        let EAPOLLayer::Key(key) = eapol.next_layer()?;
+        let message = key.message().filter(|message| message.is_four_way())?;
+        let message = HandshakeMessage::new(message, &eapol, &key);
+
+        // APs with a weak random number generator count their ANonces up
+        if message.message.from_authenticator() {
+            let previous = self.anonces.insert(bssid, message.nonce);
+            if let Some(endianness) = previous
+                .and_then(|previous| NonceCounterEndianness::detect(&previous, &message.nonce))
+            {
+                self.nonce_counters.insert(bssid, endianness);
+            }
+        }
+
+        let mut events = Vec::new();
+        let attempt = self
+            .attempts
+            .entry((bssid, station))
+            .or_insert_with(|| HandshakeAttempt::new(bssid, station));
+
+        // a new ANonce starts a new attempt
+        let restarted = matches!(
+            message.message,
+            EAPOLKeyMessage::Message1 | EAPOLKeyMessage::Message3
+        ) && attempt
+            .anonce()
+            .is_some_and(|anonce| anonce != message.nonce);
+        if restarted {
+            let old = std::mem::replace(attempt, HandshakeAttempt::new(bssid, station));
+            if !old.is_complete() {
+                events.push(if old.has(EAPOLKeyMessage::Message2) {
+                    HandshakeEvent::Failed(old)
+                } else {
+                    HandshakeEvent::Incomplete(old)
+                });
+            }
+        }
+
+        if attempt.is_retransmission(&message) {
+            attempt.retransmissions += 1;
+            events.push(HandshakeEvent::Retransmission {
+                bssid,
+                station,
+                message: message.message,
+            });
+        }
+        attempt.messages.push(message);
+
+        let mut pairs = Vec::new();
+        for (pair, latest, other, matched) in attempt.new_pairs() {
+            let (anonce, snonce) = if latest.message.from_authenticator() {
+                (latest.nonce, other.nonce)
+            } else {
+                (other.nonce, latest.nonce)
+            };
+            let with_mic = if latest.message == pair.eapol_message() {
+                latest
+            } else {
+                other
+            };
+
+            let handshake = Handshake {
+                bssid,
+                station,
+                ssid: self.ssids.get(&bssid).cloned(),
+                message_pair: pair,
+                anonce,
+                snonce,
+                replay_counter: with_mic.replay_counter,
+                replay_counter_matched: matched,
+                nonce_counter: self.nonce_counters.get(&bssid).copied(),
+                mic: with_mic.mic.clone(),
+                eapol: with_mic.eapol.clone(),
+                messages: attempt.messages.clone(),
+            };
+            pairs.push(pair);
+            events.push(HandshakeEvent::Complete(handshake));
+        }
+        attempt.pairs.extend(pairs);
+
+        Some(events)
+    }
+}
@@ -0,0 +1,178 @@
+use super::*;
+use byteorder::{BigEndian, ByteOrder, LittleEndian};
+use std::collections::HashSet;
+use std::io;
+
+/// Byte order of the counter in the last 4 bytes of an AP's ANonces
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum NonceCounterEndianness {
+    Little,
+    Big,
+}
+
+impl NonceCounterEndianness {
+    /// Most ANonces an AP is expected to use between two captured ones
+    const MAX_DISTANCE: u32 = 1024;
+
+    /// Byte order in which `anonce` counts up from `previous`
+    #[must_use]
+    pub fn detect(previous: &[u8; 32], anonce: &[u8; 32]) -> Option<Self> {
+        if previous[..28] != anonce[..28] {
+            return None;
+        }
+
+        let counts_up = |previous: u32, anonce: u32| {
+            (1..=Self::MAX_DISTANCE).contains(&anonce.wrapping_sub(previous))
+        };
+
+        if counts_up(
+            LittleEndian::read_u32(&previous[28..]),
+            LittleEndian::read_u32(&anonce[28..]),
+        ) {
+            Some(Self::Little)
+        } else if counts_up(
+            BigEndian::read_u32(&previous[28..]),
+            BigEndian::read_u32(&anonce[28..]),
+        ) {
+            Some(Self::Big)
+        } else {
+            None
+        }
+    }
+}
+
+impl Handshake {
+    /// EAPOL frame with a zeroed MIC, as it was when the MIC was computed
+    #[must_use]
+    pub fn eapol_without_mic(&self) -> Vec<u8> {
+        let mut eapol = self.eapol.clone();
+
+        let start = EAPOL::HEADER_LENGTH + EAPOLKey::MIC_START;
+        if let Some(mic) = eapol.get_mut(start..(start + self.mic.len())) {
+            mic.fill(0);
+        }
+
+        eapol
+    }
+
+    /// Message pair field of the hashcat 22000 format
+    #[must_use]
+    pub fn hashcat_message_pair(&self) -> u8 {
+        let mut message_pair = match self.message_pair {
+            HandshakeMessagePair::M1M2 => 0,
+            HandshakeMessagePair::M1M4 => 1,
+            HandshakeMessagePair::M2M3 => 2,
+            HandshakeMessagePair::M3M4 => 5,
+        };
+
+        // only correct the ANonce in the byte order the AP counts in
+        match self.nonce_counter {
+            Some(NonceCounterEndianness::Little) => message_pair |= 0b0010_0000,
+            Some(NonceCounterEndianness::Big) => message_pair |= 0b0100_0000,
+            None => {}
+        }
+
+        if !self.replay_counter_matched {
+            message_pair |= 0b1000_0000;
+        }
+
+        message_pair
+    }
+
+    /// `WPA*02` line of the hashcat 22000 format, None without an SSID
+    #[must_use]
+    pub fn to_hashcat_22000(&self) -> Option<String> {
+        Some(format!(
+            "WPA*02*{}*{}*{}*{}*{}*{}*{:02x}",
+            hex(&self.mic),
+            hex(self.bssid.as_bytes()),
+            hex(self.station.as_bytes()),
+            hex(self.ssid.as_ref()?),
+            hex(&self.anonce),
+            hex(&self.eapol_without_mic()),
+            self.hashcat_message_pair(),
+        ))
+    }
+}
+
+/// `WPA*01` line of the hashcat 22000 format
+fn pmkid_to_hashcat_22000(
+    bssid: MacAddress,
+    station: MacAddress,
+    ssid: &[u8],
+    pmkid: &[u8; 16],
+    from_authenticator: bool,
+) -> String {
+    format!(
+        "WPA*01*{}*{}*{}*{}***{:02x}",
+        hex(pmkid),
+        hex(bssid.as_bytes()),
+        hex(station.as_bytes()),
+        hex(ssid),
+        if from_authenticator { 0x01 } else { 0x10 },
+    )
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Writes handshakes and PMKIDs as hashcat 22000 lines, skipping duplicates
+pub struct Hashcat22000Writer<W: io::Write> {
+    writer: W,
+    lines: HashSet<String>,
+}
+
+impl<W: io::Write> Hashcat22000Writer<W> {
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+            lines: HashSet::new(),
+        }
+    }
+
+    /// false when the handshake has no SSID or was already written
+    pub fn write_handshake(&mut self, handshake: &Handshake) -> io::Result<bool> {
+        match handshake.to_hashcat_22000() {
+            Some(line) => self.write_line(line),
+            None => Ok(false),
+        }
+    }
+
+    /// PMKIDs of a frame, with the SSID of its BSSID, returns how many new
+    /// lines were written
+    pub fn write_pmkids(&mut self, frame: &Frame, ssid: &[u8]) -> io::Result<usize> {
+        // message 1 comes from the AP, (re)association requests from the
+        // station
+        let from_authenticator = frame.type_() == FrameType::Data;
+
+        let mut written = 0;
+        for (bssid, station, pmkid) in frame.pmkids() {
+            let line = pmkid_to_hashcat_22000(bssid, station, ssid, &pmkid, from_authenticator);
+            if self.write_line(line)? {
+                written += 1;
+            }
+        }
+
+        Ok(written)
+    }
+
+    pub fn get_ref(&self) -> &W {
+        &self.writer
+    }
+
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+
+    fn write_line(&mut self, line: String) -> io::Result<bool> {
+        if self.lines.contains(&line) {
+            return Ok(false);
+        }
+
+        writeln!(self.writer, "{}", line)?;
+        self.lines.insert(line);
+
+        Ok(true)
+    }
+}
@@ -0,0 +1,145 @@
+use super::*;
+
+/// OUI of the 802.11 KDEs
+pub const KDE_OUI: [u8; 3] = [0x00, 0x0f, 0xac];
+
+/// An element or KDE of the Key Data field
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum KeyDataElement {
+    /// RSN element, in messages 2 and 3
+    RSN(Vec<u8>),
+    /// GTK KDE
+    GTK {
+        key_id: u8,
+        /// the GTK is used for transmit too
+        tx: bool,
+        gtk: Vec<u8>,
+    }, // 1
+    /// MAC address KDE
+    MACAddress(MacAddress), // 3
+    /// PMKID KDE
+    PMKID([u8; 16]), // 4
+    /// Nonce KDE
+    Nonce([u8; 32]), // 6
+    /// Lifetime KDE, in seconds
+    Lifetime(u32), // 7
+    /// Error KDE
+    Error {
+        /// handshake the error is about, 1 for SMK and 2 for STK
+        mui: u16,
+        error_type: u16,
+    }, // 8
+    /// IGTK KDE
+    IGTK {
+        key_id: u16,
+        /// IGTK packet number
+        ipn: u64,
+        igtk: Vec<u8>,
+    }, // 9
+    /// Key ID KDE
+    KeyID(u8), // 10
+    /// Operating Channel Information KDE
+    OCI(Vec<u8>), // 13
+    /// BIGTK KDE
+    BIGTK {
+        key_id: u16,
+        /// BIGTK packet number
+        bipn: u64,
+        bigtk: Vec<u8>,
+    }, // 14
+    /// any other KDE, with its OUI and data type
+    KDE {
+        oui: [u8; 3],
+        data_type: u8,
+        data: Vec<u8>,
+    },
+    /// any other element
+    Element(u8, Vec<u8>),
+}
+
+impl KeyDataElement {
+    /// Elements and KDEs of an unencrypted Key Data field, up to the padding
+    #[must_use]
+    pub fn parse(bytes: &[u8]) -> Vec<Self> {
+        let mut elements = Vec::new();
+
+        let mut i = 0;
+        while (i + 2) <= bytes.len() {
+            let id = bytes[i];
+            let length = bytes[i + 1] as usize;
+            // padding is 0xdd followed by zeros
+            if id == 0xdd && length == 0 {
+                break;
+            }
+            i += 2;
+            if (i + length) > bytes.len() {
+                break;
+            }
+            elements.push(Self::from_bytes(id, &bytes[i..(i + length)]));
+            i += length;
+        }
+
+        elements
+    }
+
+    fn from_bytes(id: u8, bytes: &[u8]) -> Self {
+        match id {
+            0x30 => return Self::RSN(bytes.to_vec()),
+            0xdd if bytes.len() >= 4 => {}
+            _ => return Self::Element(id, bytes.to_vec()),
+        }
+
+        let oui = [bytes[0], bytes[1], bytes[2]];
+        let data_type = bytes[3];
+        let data = &bytes[4..];
+
+        let kde = if oui == KDE_OUI {
+            match data_type {
+                1 if data.len() >= 2 => Some(Self::GTK {
+                    key_id: data[0] & 0b0000_0011,
+                    tx: (data[0] & 0b0000_0100) != 0,
+                    gtk: data[2..].to_vec(),
+                }),
+                3 if data.len() >= 6 => MacAddress::from_bytes(&data[0..6])
+                    .ok()
+                    .map(Self::MACAddress),
+                4 if data.len() >= 16 => {
+                    let mut pmkid = [0; 16];
+                    pmkid.copy_from_slice(&data[0..16]);
+                    Some(Self::PMKID(pmkid))
+                }
+                6 if data.len() >= 32 => {
+                    let mut nonce = [0; 32];
+                    nonce.copy_from_slice(&data[0..32]);
+                    Some(Self::Nonce(nonce))
+                }
+                7 if data.len() >= 4 => Some(Self::Lifetime(BigEndian::read_u32(&data[0..4]))),
+                8 if data.len() >= 4 => Some(Self::Error {
+                    mui: BigEndian::read_u16(&data[0..2]),
+                    error_type: BigEndian::read_u16(&data[2..4]),
+                }),
+                9 if data.len() >= 8 => Some(Self::IGTK {
+                    key_id: LittleEndian::read_u16(&data[0..2]),
+                    ipn: LittleEndian::read_u48(&data[2..8]),
+                    igtk: data[8..].to_vec(),
+                }),
+                10 if !data.is_empty() => Some(Self::KeyID(data[0])),
+                13 => Some(Self::OCI(data.to_vec())),
+                14 if data.len() >= 8 => Some(Self::BIGTK {
+                    key_id: LittleEndian::read_u16(&data[0..2]),
+                    bipn: LittleEndian::read_u48(&data[2..8]),
+                    bigtk: data[8..].to_vec(),
+                }),
+                _ => None,
+            }
+        } else {
+            None
+        };
+
+        kde.unwrap_or_else(|| Self::KDE {
+            oui,
+            data_type,
+            data: data.to_vec(),
+        })
+    }
+}
@@ -0,0 +1,412 @@
+mod handshake;
+mod hashcat;
+mod key_data;
+mod pmkid;
+
+pub use self::{handshake::*, hashcat::*, key_data::*};
+use super::*;
+use byteorder::{BigEndian, ByteOrder, LittleEndian};
+
+impl DataFrame<'_> {
+    /// EAPOL frame of an unprotected frame
+    pub fn eapol(&self) -> Option<EAPOL<'_>> {
+        match self.llc_snap()?.next_layer() {
+            LlcSnapLayer::EAPOL(bytes) if bytes.len() >= EAPOL::HEADER_LENGTH => {
+                Some(EAPOL::new(bytes))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// 802.1X EAPOL frame
+///
+/// Protocol version, packet type and body length, followed by the body.
+pub struct EAPOL<'a> {
+    bytes: &'a [u8],
+}
+
+pub enum EAPOLLayer<'a> {
+    Key(EAPOLKey<'a>),
+}
+
+impl<'a> EAPOL<'a> {
+    pub const HEADER_LENGTH: usize = 4;
+
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+
+    pub fn bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    /// 1: 802.1X-2001, 2: 802.1X-2004, 3: 802.1X-2010
+    pub fn version(&self) -> u8 {
+        self.bytes[0]
+    }
+
+    pub fn packet_type(&self) -> EAPOLPacketType {
+        EAPOLPacketType::from_u8(self.bytes[1])
+    }
+
+    pub fn body_length(&self) -> u16 {
+        BigEndian::read_u16(&self.bytes[2..4])
+    }
+
+    /// Body, without any padding after it
+    pub fn body(&self) -> &'a [u8] {
+        let end = (Self::HEADER_LENGTH + self.body_length() as usize).min(self.bytes.len());
+        &self.bytes[Self::HEADER_LENGTH..end]
+    }
+
+    /// Header and body, without any padding after it
+    pub fn frame(&self) -> &'a [u8] {
+        let end = (Self::HEADER_LENGTH + self.body_length() as usize).min(self.bytes.len());
+        &self.bytes[..end]
+    }
+
+    pub fn next_layer(&self) -> Option<EAPOLLayer<'a>> {
+        match self.packet_type() {
+            EAPOLPacketType::Key if self.body().len() >= EAPOLKey::MIN_LENGTH => {
+                Some(EAPOLLayer::Key(EAPOLKey::new(self.body())))
+            }
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum EAPOLPacketType {
+    /// EAP Packet
+    EAP, // 0
+    /// EAPOL-Start
+    Start, // 1
+    /// EAPOL-Logoff
+    Logoff, // 2
+    /// EAPOL-Key
+    Key, // 3
+    /// EAPOL-Encapsulated-ASF-Alert
+    EncapsulatedASFAlert, // 4
+    /// EAPOL-MKA
+    MKA, // 5
+    /// 6-255 EAPOL-Announcement and others
+    Reserved(u8),
+}
+
+impl EAPOLPacketType {
+    #[must_use]
+    pub fn from_u8(n: u8) -> Self {
+        match n {
+            0 => Self::EAP,
+            1 => Self::Start,
+            2 => Self::Logoff,
+            3 => Self::Key,
+            4 => Self::EncapsulatedASFAlert,
+            5 => Self::MKA,
+            other => Self::Reserved(other),
+        }
+    }
+
+    #[must_use]
+    pub fn into_u8(self) -> u8 {
+        match self {
+            Self::EAP => 0,
+            Self::Start => 1,
+            Self::Logoff => 2,
+            Self::Key => 3,
+            Self::EncapsulatedASFAlert => 4,
+            Self::MKA => 5,
+            Self::Reserved(other) => other,
+        }
+    }
+}
+
+/// EAPOL-Key frame body
+pub struct EAPOLKey<'a> {
+    bytes: &'a [u8],
+    mic_length: usize,
+}
+
+impl<'a> EAPOLKey<'a> {
+    /// With a 16 byte MIC and no key data
+    pub const MIN_LENGTH: usize = 95;
+    /// Offset of the MIC, right after the key ID
+    pub const MIC_START: usize = 77;
+
+    /// With the 16 byte MIC of every AKM except the SHA-384 and FILS ones
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self::with_mic_length(bytes, 16)
+    }
+
+    /// `mic_length` is 24 for the SHA-384 AKMs and 0 for FILS
+    pub fn with_mic_length(bytes: &'a [u8], mic_length: usize) -> Self {
+        Self { bytes, mic_length }
+    }
+
+    pub fn bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    pub fn descriptor_type(&self) -> EAPOLKeyDescriptorType {
+        EAPOLKeyDescriptorType::from_u8(self.bytes[0])
+    }
+
+    pub fn key_information(&self) -> KeyInformation {
+        KeyInformation::from_u16(BigEndian::read_u16(&self.bytes[1..3]))
+    }
+
+    /// Length of the pairwise cipher's temporal key, in messages 1 and 3
+    pub fn key_length(&self) -> u16 {
+        BigEndian::read_u16(&self.bytes[3..5])
+    }
+
+    pub fn replay_counter(&self) -> u64 {
+        BigEndian::read_u64(&self.bytes[5..13])
+    }
+
+    /// ANonce in messages 1 and 3, SNonce in message 2
+    pub fn nonce(&self) -> &'a [u8] {
+        &self.bytes[13..45]
+    }
+
+    pub fn iv(&self) -> &'a [u8] {
+        &self.bytes[45..61]
+    }
+
+    /// Receive sequence counter of the GTK
+    pub fn rsc(&self) -> u64 {
+        LittleEndian::read_u64(&self.bytes[61..69])
+    }
+
+    pub fn mic(&self) -> Option<&'a [u8]> {
+        self.bytes
+            .get(Self::MIC_START..(Self::MIC_START + self.mic_length))
+    }
+
+    pub fn key_data_length(&self) -> Option<u16> {
+        let start = Self::MIC_START + self.mic_length;
+        self.bytes.get(start..(start + 2)).map(BigEndian::read_u16)
+    }
+
+    /// Key Data, encrypted when `key_information().encrypted_key_data` is set
+    pub fn key_data(&self) -> Option<&'a [u8]> {
+        let start = Self::MIC_START + self.mic_length + 2;
+        self.bytes
+            .get(start..(start + self.key_data_length()? as usize))
+    }
+
+    /// Elements and KDEs of unencrypted Key Data
+    pub fn key_data_elements(&self) -> Vec<KeyDataElement> {
+        if self.key_information().encrypted_key_data {
+            return Vec::new();
+        }
+
+        self.key_data()
+            .map(KeyDataElement::parse)
+            .unwrap_or_default()
+    }
+
+    /// PMKID KDE of message 1
+    pub fn pmkid(&self) -> Option<[u8; 16]> {
+        self.key_data_elements()
+            .into_iter()
+            .find_map(|element| match element {
+                KeyDataElement::PMKID(pmkid) => Some(pmkid),
+                _ => None,
+            })
+    }
+
+    /// Which handshake message this is, None for requests and frames that do
+    /// not fit any
+    pub fn message(&self) -> Option<EAPOLKeyMessage> {
+        let key_information = self.key_information();
+        if key_information.request || key_information.error {
+            return None;
+        }
+
+        let message = match (
+            key_information.pairwise,
+            key_information.ack,
+            key_information.mic,
+        ) {
+            (true, true, false) => EAPOLKeyMessage::Message1,
+            (true, true, true) => EAPOLKeyMessage::Message3,
+            (true, false, true) => {
+                // Secure is also set in message 2 of a rekey and unset in a
+                // WPA message 4, message 4 has no nonce and key data
+                let nonce_is_zero = self.nonce().iter().all(|&b| b == 0);
+                if nonce_is_zero && self.key_data_length().unwrap_or(0) == 0 {
+                    EAPOLKeyMessage::Message4
+                } else {
+                    EAPOLKeyMessage::Message2
+                }
+            }
+            (false, true, true) => EAPOLKeyMessage::GroupMessage1,
+            (false, false, true) => EAPOLKeyMessage::GroupMessage2,
+            _ => return None,
+        };
+
+        Some(message)
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum EAPOLKeyMessage {
+    /// 4-way handshake message 1, ANonce from the authenticator
+    Message1,
+    /// 4-way handshake message 2, SNonce and MIC from the supplicant
+    Message2,
+    /// 4-way handshake message 3, install the PTK and GTK
+    Message3,
+    /// 4-way handshake message 4
+    Message4,
+    /// group key handshake message 1, new GTK
+    GroupMessage1,
+    /// group key handshake message 2
+    GroupMessage2,
+}
+
+impl EAPOLKeyMessage {
+    #[must_use]
+    pub fn is_four_way(self) -> bool {
+        !matches!(self, Self::GroupMessage1 | Self::GroupMessage2)
+    }
+
+    /// Sent by the authenticator
+    #[must_use]
+    pub fn from_authenticator(self) -> bool {
+        matches!(self, Self::Message1 | Self::Message3 | Self::GroupMessage1)
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum EAPOLKeyDescriptorType {
+    /// RC4, 802.1X
+    RC4, // 1
+    /// 802.11 (RSN)
+    RSN, // 2
+    /// WPA
+    WPA, // 254
+    Reserved(u8),
+}
+
+impl EAPOLKeyDescriptorType {
+    #[must_use]
+    pub fn from_u8(n: u8) -> Self {
+        match n {
+            1 => Self::RC4,
+            2 => Self::RSN,
+            254 => Self::WPA,
+            other => Self::Reserved(other),
+        }
+    }
+
+    #[must_use]
+    pub fn into_u8(self) -> u8 {
+        match self {
+            Self::RC4 => 1,
+            Self::RSN => 2,
+            Self::WPA => 254,
+            Self::Reserved(other) => other,
+        }
+    }
+}
+
+/// Key Descriptor Version of the Key Information field
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum KeyDescriptorVersion {
+    /// MIC and key wrap defined by the AKM
+    AKMDefined, // 0
+    /// HMAC-MD5 MIC, RC4 key wrap (TKIP)
+    HMACMD5RC4, // 1
+    /// HMAC-SHA1-128 MIC, AES key wrap (CCMP)
+    HMACSHA1AES, // 2
+    /// AES-128-CMAC MIC, AES key wrap
+    AESCMACAES, // 3
+    Reserved(u8),
+}
+
+impl KeyDescriptorVersion {
+    #[must_use]
+    pub fn from_u8(n: u8) -> Self {
+        match n {
+            0 => Self::AKMDefined,
+            1 => Self::HMACMD5RC4,
+            2 => Self::HMACSHA1AES,
+            3 => Self::AESCMACAES,
+            other => Self::Reserved(other),
+        }
+    }
+
+    #[must_use]
+    pub fn into_u8(self) -> u8 {
+        match self {
+            Self::AKMDefined => 0,
+            Self::HMACMD5RC4 => 1,
+            Self::HMACSHA1AES => 2,
+            Self::AESCMACAES => 3,
+            Self::Reserved(other) => other,
+        }
+    }
+}
+
+/// Key Information field of the EAPOL-Key frame
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct KeyInformation {
+    pub descriptor_version: KeyDescriptorVersion,
+    /// 1: pairwise, 0: group
+    pub pairwise: bool,
+    /// only used by WPA, for group keys
+    pub key_index: u8,
+    pub install: bool,
+    pub ack: bool,
+    pub mic: bool,
+    pub secure: bool,
+    pub error: bool,
+    pub request: bool,
+    pub encrypted_key_data: bool,
+    pub smk_message: bool,
+}
+
+impl KeyInformation {
+    #[must_use]
+    pub fn from_u16(n: u16) -> Self {
+        let bit = |i: u16| (n & (1 << i)) != 0;
+
+        Self {
+            descriptor_version: KeyDescriptorVersion::from_u8((n & 0b111) as u8),
+            pairwise: bit(3),
+            key_index: ((n >> 4) & 0b11) as u8,
+            install: bit(6),
+            ack: bit(7),
+            mic: bit(8),
+            secure: bit(9),
+            error: bit(10),
+            request: bit(11),
+            encrypted_key_data: bit(12),
+            smk_message: bit(13),
+        }
+    }
+
+    #[must_use]
+    pub fn into_u16(self) -> u16 {
+        let flags = [
+            (self.pairwise, 3),
+            (self.install, 6),
+            (self.ack, 7),
+            (self.mic, 8),
+            (self.secure, 9),
+            (self.error, 10),
+            (self.request, 11),
+            (self.encrypted_key_data, 12),
+            (self.smk_message, 13),
+        ];
+
+        flags.iter().filter(|(flag, _)| *flag).fold(
+            u16::from(self.descriptor_version.into_u8() & 0b111)
+                | (u16::from(self.key_index & 0b11) << 4),
+            |n, (_, i)| n | (1 << i),
+        )
+    }
+}
@@ -0,0 +1,69 @@
+use super::*;
+
+impl Frame<'_> {
+    /// (BSSID, station, PMKID) of the PMKID KDEs of EAPOL-Key message 1 and
+    /// of the RSN element of (re)association requests, without zeroed PMKIDs
+    pub fn pmkids(&self) -> Vec<(MacAddress, MacAddress, [u8; 16])> {
+        let (bssid, station, pmkids) = match self.next_layer() {
+            Some(FrameLayer::Data(data_frame)) => {
+                match (data_frame.bssid_address(), data_frame.station_address()) {
+                    (Some(bssid), Some(station)) => (bssid, station, eapol_pmkids(&data_frame)),
+                    _ => return Vec::new(),
+                }
+            }
+            Some(FrameLayer::Management(management_frame)) => {
+                let pmkids = match management_frame.next_layer() {
+                    Some(ManagementFrameLayer::AssociationRequest(frame)) => {
+                        rsn_pmkids(frame.tagged_parameters().ok())
+                    }
+                    Some(ManagementFrameLayer::ReassociationRequest(frame)) => {
+                        rsn_pmkids(frame.tagged_parameters().ok())
+                    }
+                    _ => Vec::new(),
+                };
+
+                // sent by the station to the AP
+                match (
+                    management_frame.bssid_address(),
+                    management_frame.transmitter_address(),
+                ) {
+                    (Some(bssid), Some(station)) => (bssid, station, pmkids),
+                    _ => return Vec::new(),
+                }
+            }
+            _ => return Vec::new(),
+        };
+
+        pmkids
+            .into_iter()
+            .filter(|pmkid| pmkid.iter().any(|&b| b != 0))
+            .map(|pmkid| (bssid, station, pmkid))
+            .collect()
+    }
+}
+
+fn eapol_pmkids(data_frame: &DataFrame) -> Vec<[u8; 16]> {
+    let eapol = match data_frame.eapol() {
+        Some(eapol) => eapol,
+        None => return Vec::new(),
+    };
+
+    match eapol.next_layer() {
+        Some(EAPOLLayer::Key(key)) if key.message() == Some(EAPOLKeyMessage::Message1) => key
+            .key_data_elements()
+            .into_iter()
+            .filter_map(|element| match element {
+                KeyDataElement::PMKID(pmkid) => Some(pmkid),
+                _ => None,
+            })
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+fn rsn_pmkids(tagged_parameters: Option<TaggedParameters>) -> Vec<[u8; 16]> {
+    match tagged_parameters.and_then(|tagged_parameters| tagged_parameters.rsn()) {
+        Some(RSNVersion::Standard(rsn)) => rsn.pmkids,
+        _ => Vec::new(),
+    }
+}
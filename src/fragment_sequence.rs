@@ -7,7 +7,7 @@ pub trait FragmentSequenceTrait: FrameTrait {
 
     /// Fragment Number
     fn fragment_number(&self) -> u8 {
-        (self.bytes()[Self::FRAGMENT_SEQUENCE_START] & 0b0000_1111) as u8
+        self.bytes()[Self::FRAGMENT_SEQUENCE_START] & 0b0000_1111
     }
 
     /// Sequence Number
@@ -19,7 +19,7 @@ impl FrameBuilder {
     }
 
     #[must_use]
-    pub fn build(&self) -> Frame {
+    pub fn build(&self) -> Frame<'_> {
         Frame::new(self.bytes().to_vec())
     }
 }
@@ -56,6 +56,7 @@ pub trait FrameBuilderTrait {
             (self.bytes()[1] & !0b0000_0001) | (if to_ds { 0b0000_0001 } else { 0b0000_0000 });
     }
 
+    #[allow(clippy::wrong_self_convention)]
     fn from_ds(&mut self, from_ds: bool) {
         self.bytes_mut()[1] =
             (self.bytes()[1] & !0b0000_0010) | (if from_ds { 0b0000_0010 } else { 0b0000_0000 });
@@ -124,6 +125,7 @@ pub trait FrameBuilderTrait {
 }
 
 #[test]
+#[allow(clippy::bool_assert_comparison)]
 fn test_frame_builder() {
     let mut frame_builder = FrameBuilder::new();
 
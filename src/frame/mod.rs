@@ -124,7 +124,7 @@ pub trait FrameTrait {
         } else {
             let n = LittleEndian::read_u16(&self.bytes()[2..4]) & 0b0011_1111_1111_1111;
             // valid range 1-2007
-            if n < 1 || n > 2007 {
+            if !(1..=2007).contains(&n) {
                 DurationID::Reserved(n)
             } else {
                 DurationID::AssociationID(n)
@@ -1,9 +1,18 @@
+mod channel;
 mod control;
+mod crc;
 mod data;
+mod eapol;
 mod fragment_sequence;
 mod frame;
 mod management;
+mod operating_class;
 mod types;
 
-pub use self::{control::*, data::*, fragment_sequence::*, frame::*, management::*, types::*};
+pub use self::{
+    channel::*, control::*, data::*, eapol::*, fragment_sequence::*, frame::*, management::*,
+    operating_class::*, types::*,
+};
 pub use eui48::MacAddress;
+
+use self::crc::crc32;
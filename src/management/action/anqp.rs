@@ -0,0 +1,475 @@
+use super::*;
+use byteorder::{ByteOrder, LittleEndian};
+
+/// OUI type of the Hotspot 2.0 ANQP vendor specific elements
+pub const HOTSPOT20_ANQP_OUI_TYPE: u8 = 0x11;
+
+/// Access Network Query Protocol elements from a GAS query
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ANQP {
+    /// in the order they appear, a truncated last element is dropped
+    pub elements: Vec<ANQPElement>,
+}
+
+impl ANQP {
+    /// Parses little-endian 16-bit Info ID, 16-bit length elements
+    #[must_use]
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        let mut elements = Vec::new();
+
+        let mut i = 0;
+        let len = bytes.len();
+        while (i + 4) <= len {
+            let info_id = LittleEndian::read_u16(&bytes[i..(i + 2)]);
+            let length = LittleEndian::read_u16(&bytes[(i + 2)..(i + 4)]) as usize;
+            i += 4;
+
+            if (i + length) > len {
+                break;
+            }
+            elements.push(ANQPElement::from(info_id, &bytes[i..(i + length)]));
+            i += length;
+        }
+
+        Self { elements }
+    }
+
+    #[must_use]
+    pub fn venue_name(&self) -> Option<&ANQPVenueName> {
+        self.elements.iter().find_map(|element| match element {
+            ANQPElement::VenueName(venue_name) => Some(venue_name),
+            _ => None,
+        })
+    }
+
+    #[must_use]
+    pub fn nai_realms(&self) -> Option<&[NAIRealm]> {
+        self.elements.iter().find_map(|element| match element {
+            ANQPElement::NAIRealm(nai_realms) => Some(nai_realms.as_ref()),
+            _ => None,
+        })
+    }
+
+    #[must_use]
+    pub fn cellular_networks(&self) -> Option<&[PLMN]> {
+        self.elements.iter().find_map(|element| match element {
+            ANQPElement::ThreeGPPCellularNetwork(plmns) => Some(plmns.as_ref()),
+            _ => None,
+        })
+    }
+
+    #[must_use]
+    pub fn domain_names(&self) -> Option<&[Vec<u8>]> {
+        self.elements.iter().find_map(|element| match element {
+            ANQPElement::DomainName(domain_names) => Some(domain_names.as_ref()),
+            _ => None,
+        })
+    }
+
+    /// Every Hotspot 2.0 ANQP element
+    pub fn hotspot20(&self) -> impl Iterator<Item = &HotSpot20ANQPElement> + '_ {
+        self.elements.iter().filter_map(|element| match element {
+            ANQPElement::HotSpot20(hotspot20) => Some(hotspot20),
+            _ => None,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ANQPElement {
+    /// 256, Info IDs requested by the STA
+    QueryList(Vec<u16>),
+    /// 257, Info IDs supported by the AP
+    CapabilityList(Vec<u16>),
+    /// 258
+    VenueName(ANQPVenueName),
+    /// 261, OIs of the roaming consortiums and SSPs reachable through the AP
+    RoamingConsortium(Vec<Vec<u8>>),
+    /// 263
+    NAIRealm(Vec<NAIRealm>),
+    /// 264
+    ThreeGPPCellularNetwork(Vec<PLMN>),
+    /// 268
+    DomainName(Vec<Vec<u8>>),
+    /// 56797, Hotspot 2.0
+    HotSpot20(HotSpot20ANQPElement),
+    /// 56797, any other vendor, starting with the OUI
+    VendorSpecific(Vec<u8>),
+    /// unknown elements, or known elements that failed to decode
+    Other(u16, Vec<u8>),
+}
+
+impl ANQPElement {
+    #[must_use]
+    pub fn from(info_id: u16, data: &[u8]) -> Self {
+        Self::decode(info_id, data).unwrap_or_else(|| Self::Other(info_id, data.to_vec()))
+    }
+
+    fn decode(info_id: u16, data: &[u8]) -> Option<Self> {
+        Some(match info_id {
+            256 => Self::QueryList(read_info_ids(data)?),
+            257 => Self::CapabilityList(read_info_ids(data)?),
+            258 => Self::VenueName(ANQPVenueName::from_bytes(data)?),
+            261 => Self::RoamingConsortium(read_duples(data)?),
+            263 => Self::NAIRealm(NAIRealm::read_list(data)?),
+            264 => Self::ThreeGPPCellularNetwork(PLMN::read_list(data)?),
+            268 => Self::DomainName(read_duples(data)?),
+            56797 => {
+                if data.len() >= 4
+                    && data[0..3] == WIFI_ALLIANCE_OUI
+                    && data[3] == HOTSPOT20_ANQP_OUI_TYPE
+                {
+                    Self::HotSpot20(HotSpot20ANQPElement::from_bytes(&data[4..])?)
+                } else {
+                    Self::VendorSpecific(data.to_vec())
+                }
+            }
+            _ => return None,
+        })
+    }
+}
+
+/// List of 16-bit Info IDs, the vendor specific part of a Capability List is
+/// left out
+fn read_info_ids(bytes: &[u8]) -> Option<Vec<u16>> {
+    let mut info_ids = Vec::new();
+
+    for info_id in bytes.chunks(2) {
+        if info_id.len() < 2 {
+            return None;
+        }
+        let info_id = LittleEndian::read_u16(info_id);
+        info_ids.push(info_id);
+
+        if info_id == 56797 {
+            break;
+        }
+    }
+
+    Some(info_ids)
+}
+
+/// List of fields each prefixed with an 8-bit length
+fn read_duples(bytes: &[u8]) -> Option<Vec<Vec<u8>>> {
+    let mut duples = Vec::new();
+
+    let mut i = 0;
+    while i < bytes.len() {
+        let length = bytes[i] as usize;
+        i += 1;
+        if (i + length) > bytes.len() {
+            return None;
+        }
+        duples.push(bytes[i..(i + length)].to_vec());
+        i += length;
+    }
+
+    Some(duples)
+}
+
+/// Name with an ISO-639 language code, from Venue Name and Operator Friendly
+/// Name elements
+#[derive(Debug, Clone, PartialEq)]
+pub struct ANQPName {
+    /// two or three letters, padded with a zero
+    pub language: [u8; 3],
+    pub name: Vec<u8>,
+}
+
+impl ANQPName {
+    fn read_list(bytes: &[u8]) -> Option<Vec<Self>> {
+        read_duples(bytes)?
+            .into_iter()
+            .map(|duple| {
+                if duple.len() < 3 {
+                    return None;
+                }
+                let mut language = [0; 3];
+                language.copy_from_slice(&duple[0..3]);
+
+                Some(Self {
+                    language,
+                    name: duple[3..].to_vec(),
+                })
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ANQPVenueName {
+    pub venue_info: VenueInfo,
+    pub names: Vec<ANQPName>,
+}
+
+impl ANQPVenueName {
+    fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() < 2 {
+            return None;
+        }
+
+        Some(Self {
+            venue_info: VenueInfo::from_bytes(&bytes[0..2]),
+            names: ANQPName::read_list(&bytes[2..])?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct NAIRealm {
+    /// the realm is UTF-8 rather than RFC 4282 encoded
+    pub utf8: bool,
+    /// one or more realms separated by semicolons
+    pub realm: Vec<u8>,
+    pub eap_methods: Vec<NAIRealmEAPMethod>,
+}
+
+impl NAIRealm {
+    fn read_list(bytes: &[u8]) -> Option<Vec<Self>> {
+        if bytes.len() < 2 {
+            return None;
+        }
+        let count = LittleEndian::read_u16(&bytes[0..2]);
+
+        let mut nai_realms = Vec::new();
+        let mut i = 2;
+        for _ in 0..count {
+            if (i + 2) > bytes.len() {
+                return None;
+            }
+            let length = LittleEndian::read_u16(&bytes[i..(i + 2)]) as usize;
+            i += 2;
+            if (i + length) > bytes.len() {
+                return None;
+            }
+            nai_realms.push(Self::from_bytes(&bytes[i..(i + length)])?);
+            i += length;
+        }
+
+        Some(nai_realms)
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        // encoding, realm length
+        if bytes.len() < 2 {
+            return None;
+        }
+        let utf8 = (bytes[0] & 0b0000_0001) != 0;
+        let realm_length = bytes[1] as usize;
+        let mut i = 2;
+        if (i + realm_length + 1) > bytes.len() {
+            return None;
+        }
+        let realm = bytes[i..(i + realm_length)].to_vec();
+        i += realm_length;
+
+        let eap_method_count = bytes[i];
+        i += 1;
+
+        let mut eap_methods = Vec::new();
+        for _ in 0..eap_method_count {
+            if i >= bytes.len() {
+                return None;
+            }
+            let length = bytes[i] as usize;
+            i += 1;
+            if (i + length) > bytes.len() {
+                return None;
+            }
+            eap_methods.push(NAIRealmEAPMethod::from_bytes(&bytes[i..(i + length)])?);
+            i += length;
+        }
+
+        Some(Self {
+            utf8,
+            realm,
+            eap_methods,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct NAIRealmEAPMethod {
+    /// EAP type, e.g. 13: EAP-TLS, 21: EAP-TTLS, 18: EAP-SIM, 23: EAP-AKA
+    pub eap_method: u8,
+    /// Authentication Parameter ID and value
+    pub authentication_parameters: Vec<(u8, Vec<u8>)>,
+}
+
+impl NAIRealmEAPMethod {
+    fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() < 2 {
+            return None;
+        }
+        let eap_method = bytes[0];
+        let count = bytes[1];
+
+        let mut authentication_parameters = Vec::new();
+        let mut i = 2;
+        for _ in 0..count {
+            if (i + 2) > bytes.len() {
+                return None;
+            }
+            let id = bytes[i];
+            let length = bytes[i + 1] as usize;
+            i += 2;
+            if (i + length) > bytes.len() {
+                return None;
+            }
+            authentication_parameters.push((id, bytes[i..(i + length)].to_vec()));
+            i += length;
+        }
+
+        Some(Self {
+            eap_method,
+            authentication_parameters,
+        })
+    }
+}
+
+/// Public Land Mobile Network ID
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PLMN {
+    /// Mobile Country Code, 3 digits
+    pub mcc: String,
+    /// Mobile Network Code, 2 or 3 digits
+    pub mnc: String,
+}
+
+impl PLMN {
+    /// 3GPP TS 24.234 PLMN list, inside the Generic Container
+    fn read_list(bytes: &[u8]) -> Option<Vec<Self>> {
+        // GUD version, UDHL
+        if bytes.len() < 2 || bytes[0] != 0 {
+            return None;
+        }
+        let udhl = bytes[1] as usize;
+        let bytes = bytes.get(2..(2 + udhl))?;
+
+        let mut plmns = Vec::new();
+        let mut i = 0;
+        while (i + 2) <= bytes.len() {
+            let iei = bytes[i];
+            let length = bytes[i + 1] as usize;
+            i += 2;
+            if (i + length) > bytes.len() {
+                return None;
+            }
+
+            // PLMN List
+            if iei == 0 && length >= 1 {
+                let count = bytes[i] as usize;
+                if (1 + count * 3) > length {
+                    return None;
+                }
+                for plmn in bytes[(i + 1)..(i + 1 + count * 3)].chunks(3) {
+                    plmns.push(Self::from_bytes(plmn));
+                }
+            }
+            i += length;
+        }
+
+        Some(plmns)
+    }
+
+    fn from_bytes(bytes: &[u8]) -> Self {
+        let digit = |n: u8| char::from(b'0' + (n & 0b0000_1111));
+
+        let mcc = [bytes[0] & 0x0f, bytes[0] >> 4, bytes[1] & 0x0f]
+            .iter()
+            .map(|&n| digit(n))
+            .collect();
+
+        // the third MNC digit is 0xf for 2 digit MNCs
+        let mut mnc: String = [bytes[2] & 0x0f, bytes[2] >> 4]
+            .iter()
+            .map(|&n| digit(n))
+            .collect();
+        if (bytes[1] >> 4) != 0x0f {
+            mnc.push(digit(bytes[1] >> 4));
+        }
+
+        Self { mcc, mnc }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum HotSpot20ANQPElement {
+    /// 1, subtypes requested by the STA
+    QueryList(Vec<u8>),
+    /// 2, subtypes supported by the AP
+    CapabilityList(Vec<u8>),
+    /// 3
+    OperatorFriendlyName(Vec<ANQPName>),
+    /// 4
+    WANMetrics(HotSpot20WANMetrics),
+    /// 5, IP protocol, port and status, status 0: closed, 1: open, 2: unknown
+    ConnectionCapability(Vec<(u8, u16, u8)>),
+    /// 7, operating classes the AP uses
+    OperatingClassIndication(Vec<u8>),
+    /// unknown subtypes, or known subtypes that failed to decode
+    Other(u8, Vec<u8>),
+}
+
+impl HotSpot20ANQPElement {
+    /// Parses the element body following the OUI and OUI type
+    fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        // subtype, reserved
+        if bytes.len() < 2 {
+            return None;
+        }
+        let subtype = bytes[0];
+        let data = &bytes[2..];
+
+        Some(Self::decode(subtype, data).unwrap_or_else(|| Self::Other(subtype, data.to_vec())))
+    }
+
+    fn decode(subtype: u8, data: &[u8]) -> Option<Self> {
+        Some(match subtype {
+            1 => Self::QueryList(data.to_vec()),
+            2 => Self::CapabilityList(data.to_vec()),
+            3 => Self::OperatorFriendlyName(ANQPName::read_list(data)?),
+            4 if data.len() == 13 => Self::WANMetrics(HotSpot20WANMetrics::from_bytes(data)),
+            5 if data.len() % 4 == 0 => Self::ConnectionCapability(
+                data.chunks(4)
+                    .map(|tuple| (tuple[0], LittleEndian::read_u16(&tuple[1..3]), tuple[3]))
+                    .collect(),
+            ),
+            7 => Self::OperatingClassIndication(data.to_vec()),
+            _ => return None,
+        })
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct HotSpot20WANMetrics {
+    /// 1: link up, 2: link down, 3: link in test state
+    pub link_status: u8,
+    pub symmetric_link: bool,
+    pub at_capacity: bool,
+    /// in kbps
+    pub downlink_speed: u32,
+    /// in kbps
+    pub uplink_speed: u32,
+    /// scaled to 255
+    pub downlink_load: u8,
+    /// scaled to 255
+    pub uplink_load: u8,
+    /// Load Measurement Duration, in tenths of a second
+    pub load_measurement_duration: u16,
+}
+
+impl HotSpot20WANMetrics {
+    fn from_bytes(bytes: &[u8]) -> Self {
+        Self {
+            link_status: bytes[0] & 0b0000_0011,
+            symmetric_link: (bytes[0] & 0b0000_0100) != 0,
+            at_capacity: (bytes[0] & 0b0000_1000) != 0,
+            downlink_speed: LittleEndian::read_u32(&bytes[1..5]),
+            uplink_speed: LittleEndian::read_u32(&bytes[5..9]),
+            downlink_load: bytes[9],
+            uplink_load: bytes[10],
+            load_measurement_duration: LittleEndian::read_u16(&bytes[11..13]),
+        }
+    }
+}
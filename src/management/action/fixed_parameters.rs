@@ -0,0 +1,117 @@
+use super::*;
+
+pub trait ActionFixedParametersTrait: FrameTrait {
+    const FIXED_PARAMETERS_START: usize = 24;
+    const FIXED_PARAMETERS_END: usize = Self::FIXED_PARAMETERS_START + 2;
+
+    fn category(&self) -> ActionCategory {
+        ActionCategory::from_u8(self.bytes()[Self::FIXED_PARAMETERS_START])
+    }
+
+    /// Meaning depends on the category
+    fn action(&self) -> u8 {
+        self.bytes()[Self::FIXED_PARAMETERS_START + 1]
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ActionCategory {
+    /// Spectrum Management
+    SpectrumManagement, // 0
+    /// QoS
+    QoS, // 1
+    /// Block Ack
+    BlockAck, // 3
+    /// Public
+    Public, // 4
+    /// Radio Measurement
+    RadioMeasurement, // 5
+    /// Fast BSS Transition
+    FastBSSTransition, // 6
+    /// HT
+    HT, // 7
+    /// SA Query
+    SAQuery, // 8
+    /// Protected Dual of Public Action
+    ProtectedDualOfPublicAction, // 9
+    /// Wireless Network Management
+    WNM, // 10
+    /// Unprotected Wireless Network Management
+    UnprotectedWNM, // 11
+    /// Tunneled Direct Link Setup
+    TDLS, // 12
+    /// Mesh
+    Mesh, // 13
+    /// Multihop
+    Multihop, // 14
+    /// Self Protected
+    SelfProtected, // 15
+    /// VHT
+    VHT, // 21
+    /// HE
+    HE, // 30
+    /// Protected HE
+    ProtectedHE, // 31
+    /// Vendor Specific Protected
+    VendorSpecificProtected, // 126
+    /// Vendor Specific
+    VendorSpecific, // 127
+    /// 2, 16-20, 22-29, 32-125 Reserved, 128-255 Error
+    Reserved(u8),
+}
+
+impl ActionCategory {
+    #[must_use]
+    pub fn from_u8(n: u8) -> Self {
+        match n {
+            0 => Self::SpectrumManagement,
+            1 => Self::QoS,
+            3 => Self::BlockAck,
+            4 => Self::Public,
+            5 => Self::RadioMeasurement,
+            6 => Self::FastBSSTransition,
+            7 => Self::HT,
+            8 => Self::SAQuery,
+            9 => Self::ProtectedDualOfPublicAction,
+            10 => Self::WNM,
+            11 => Self::UnprotectedWNM,
+            12 => Self::TDLS,
+            13 => Self::Mesh,
+            14 => Self::Multihop,
+            15 => Self::SelfProtected,
+            21 => Self::VHT,
+            30 => Self::HE,
+            31 => Self::ProtectedHE,
+            126 => Self::VendorSpecificProtected,
+            127 => Self::VendorSpecific,
+            other => Self::Reserved(other),
+        }
+    }
+
+    #[must_use]
+    pub fn into_u8(self) -> u8 {
+        match self {
+            Self::SpectrumManagement => 0,
+            Self::QoS => 1,
+            Self::BlockAck => 3,
+            Self::Public => 4,
+            Self::RadioMeasurement => 5,
+            Self::FastBSSTransition => 6,
+            Self::HT => 7,
+            Self::SAQuery => 8,
+            Self::ProtectedDualOfPublicAction => 9,
+            Self::WNM => 10,
+            Self::UnprotectedWNM => 11,
+            Self::TDLS => 12,
+            Self::Mesh => 13,
+            Self::Multihop => 14,
+            Self::SelfProtected => 15,
+            Self::VHT => 21,
+            Self::HE => 30,
+            Self::ProtectedHE => 31,
+            Self::VendorSpecificProtected => 126,
+            Self::VendorSpecific => 127,
+            Self::Reserved(other) => other,
+        }
+    }
+}
@@ -0,0 +1,224 @@
+use super::*;
+use byteorder::{ByteOrder, LittleEndian};
+
+/// Advertisement Protocol element followed by the query length and query,
+/// shared by the GAS frames that carry a query
+fn read_advertisement_protocol_and_query(bytes: &[u8]) -> Option<(AdvertisementProtocol, &[u8])> {
+    let mut i = 0;
+    let len = bytes.len();
+
+    if (i + 2) > len || TagName::from(bytes[i]) != TagName::AdvertisementProtocol {
+        return None;
+    }
+    let element_length = bytes[i + 1] as usize;
+    i += 2;
+    if (i + element_length) > len {
+        return None;
+    }
+    let advertisement_protocol =
+        AdvertisementProtocol::from_bytes(&bytes[i..(i + element_length)])?;
+    i += element_length;
+
+    if (i + 2) > len {
+        return None;
+    }
+    let query_length = LittleEndian::read_u16(&bytes[i..(i + 2)]) as usize;
+    i += 2;
+    if (i + query_length) > len {
+        return None;
+    }
+
+    Some((advertisement_protocol, &bytes[i..(i + query_length)]))
+}
+
+/// Decodes `query` as ANQP if the advertisement protocol is ANQP
+fn read_anqp(bytes: &[u8]) -> Option<ANQP> {
+    let (advertisement_protocol, query) = read_advertisement_protocol_and_query(bytes)?;
+    if !advertisement_protocol.has_anqp() {
+        return None;
+    }
+
+    Some(ANQP::from_bytes(query))
+}
+
+pub struct GASInitialRequestFrame<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> GASInitialRequestFrame<'a> {
+    const QUERY_START: usize = Self::FIXED_PARAMETERS_END + 1;
+
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+
+    pub fn dialog_token(&self) -> u8 {
+        self.bytes()[Self::FIXED_PARAMETERS_END]
+    }
+
+    pub fn advertisement_protocol(&self) -> Option<AdvertisementProtocol> {
+        read_advertisement_protocol_and_query(&self.bytes()[Self::QUERY_START..])
+            .map(|(advertisement_protocol, _)| advertisement_protocol)
+    }
+
+    pub fn query_request(&self) -> Option<&[u8]> {
+        read_advertisement_protocol_and_query(&self.bytes()[Self::QUERY_START..])
+            .map(|(_, query)| query)
+    }
+
+    /// Query request decoded as ANQP, normally a single Query List
+    pub fn anqp(&self) -> Option<ANQP> {
+        read_anqp(&self.bytes()[Self::QUERY_START..])
+    }
+}
+
+pub struct GASInitialResponseFrame<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> GASInitialResponseFrame<'a> {
+    const QUERY_START: usize = Self::FIXED_PARAMETERS_END + 5;
+
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+
+    pub fn dialog_token(&self) -> u8 {
+        self.bytes()[Self::FIXED_PARAMETERS_END]
+    }
+
+    pub fn status_code(&self) -> StatusCode {
+        StatusCode::from_u16(LittleEndian::read_u16(
+            &self.bytes()[(Self::FIXED_PARAMETERS_END + 1)..(Self::FIXED_PARAMETERS_END + 3)],
+        ))
+    }
+
+    /// in TUs, non-zero when the response has to be fetched with GAS Comeback
+    /// Requests
+    pub fn comeback_delay(&self) -> u16 {
+        LittleEndian::read_u16(
+            &self.bytes()[(Self::FIXED_PARAMETERS_END + 3)..(Self::FIXED_PARAMETERS_END + 5)],
+        )
+    }
+
+    pub fn advertisement_protocol(&self) -> Option<AdvertisementProtocol> {
+        read_advertisement_protocol_and_query(&self.bytes()[Self::QUERY_START..])
+            .map(|(advertisement_protocol, _)| advertisement_protocol)
+    }
+
+    /// Empty when the response is delivered with GAS Comeback Responses
+    pub fn query_response(&self) -> Option<&[u8]> {
+        read_advertisement_protocol_and_query(&self.bytes()[Self::QUERY_START..])
+            .map(|(_, query)| query)
+    }
+
+    pub fn anqp(&self) -> Option<ANQP> {
+        read_anqp(&self.bytes()[Self::QUERY_START..])
+    }
+}
+
+pub struct GASComebackRequestFrame<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> GASComebackRequestFrame<'a> {
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+
+    pub fn dialog_token(&self) -> u8 {
+        self.bytes()[Self::FIXED_PARAMETERS_END]
+    }
+}
+
+pub struct GASComebackResponseFrame<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> GASComebackResponseFrame<'a> {
+    const QUERY_START: usize = Self::FIXED_PARAMETERS_END + 6;
+
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+
+    pub fn dialog_token(&self) -> u8 {
+        self.bytes()[Self::FIXED_PARAMETERS_END]
+    }
+
+    pub fn status_code(&self) -> StatusCode {
+        StatusCode::from_u16(LittleEndian::read_u16(
+            &self.bytes()[(Self::FIXED_PARAMETERS_END + 1)..(Self::FIXED_PARAMETERS_END + 3)],
+        ))
+    }
+
+    pub fn fragment_id(&self) -> u8 {
+        self.bytes()[Self::FIXED_PARAMETERS_END + 3] & 0b0111_1111
+    }
+
+    /// More GAS Fragments, the query response continues in the next comeback
+    /// response
+    pub fn more_fragments(&self) -> bool {
+        (self.bytes()[Self::FIXED_PARAMETERS_END + 3] & 0b1000_0000) != 0
+    }
+
+    /// in TUs
+    pub fn comeback_delay(&self) -> u16 {
+        LittleEndian::read_u16(
+            &self.bytes()[(Self::FIXED_PARAMETERS_END + 4)..(Self::FIXED_PARAMETERS_END + 6)],
+        )
+    }
+
+    pub fn advertisement_protocol(&self) -> Option<AdvertisementProtocol> {
+        read_advertisement_protocol_and_query(&self.bytes()[Self::QUERY_START..])
+            .map(|(advertisement_protocol, _)| advertisement_protocol)
+    }
+
+    /// One fragment of the query response, concatenate the fragments in
+    /// `fragment_id` order and use `ANQP::from_bytes` on the result
+    pub fn query_response(&self) -> Option<&[u8]> {
+        read_advertisement_protocol_and_query(&self.bytes()[Self::QUERY_START..])
+            .map(|(_, query)| query)
+    }
+
+    /// Only complete when this is the single fragment of the response
+    pub fn anqp(&self) -> Option<ANQP> {
+        read_anqp(&self.bytes()[Self::QUERY_START..])
+    }
+}
+
+impl FrameTrait for GASInitialRequestFrame<'_> {
+    fn bytes(&self) -> &[u8] {
+        self.bytes
+    }
+}
+impl FragmentSequenceTrait for GASInitialRequestFrame<'_> {}
+impl ManagementFrameTrait for GASInitialRequestFrame<'_> {}
+impl ActionFixedParametersTrait for GASInitialRequestFrame<'_> {}
+
+impl FrameTrait for GASInitialResponseFrame<'_> {
+    fn bytes(&self) -> &[u8] {
+        self.bytes
+    }
+}
+impl FragmentSequenceTrait for GASInitialResponseFrame<'_> {}
+impl ManagementFrameTrait for GASInitialResponseFrame<'_> {}
+impl ActionFixedParametersTrait for GASInitialResponseFrame<'_> {}
+
+impl FrameTrait for GASComebackRequestFrame<'_> {
+    fn bytes(&self) -> &[u8] {
+        self.bytes
+    }
+}
+impl FragmentSequenceTrait for GASComebackRequestFrame<'_> {}
+impl ManagementFrameTrait for GASComebackRequestFrame<'_> {}
+impl ActionFixedParametersTrait for GASComebackRequestFrame<'_> {}
+
+impl FrameTrait for GASComebackResponseFrame<'_> {
+    fn bytes(&self) -> &[u8] {
+        self.bytes
+    }
+}
+impl FragmentSequenceTrait for GASComebackResponseFrame<'_> {}
+impl ManagementFrameTrait for GASComebackResponseFrame<'_> {}
+impl ActionFixedParametersTrait for GASComebackResponseFrame<'_> {}
@@ -0,0 +1,54 @@
+mod anqp;
+mod fixed_parameters;
+mod gas;
+mod p2p;
+mod public;
+mod radio_measurement;
+mod wnm;
+
+pub use self::{
+    anqp::*, fixed_parameters::*, gas::*, p2p::*, public::*, radio_measurement::*, wnm::*,
+};
+use super::*;
+
+pub struct ActionFrame<'a> {
+    bytes: &'a [u8],
+}
+
+pub enum ActionFrameLayer<'a> {
+    Public(PublicActionFrame<'a>),
+    RadioMeasurement(RadioMeasurementActionFrame<'a>),
+    WNM(WNMActionFrame<'a>),
+}
+
+impl<'a> ActionFrame<'a> {
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+
+    pub fn next_layer(&self) -> Option<ActionFrameLayer<'_>> {
+        if self.bytes().len() < Self::FIXED_PARAMETERS_END {
+            return None;
+        }
+
+        match self.category() {
+            ActionCategory::Public => Some(ActionFrameLayer::Public(PublicActionFrame::new(
+                self.bytes(),
+            ))),
+            ActionCategory::RadioMeasurement => Some(ActionFrameLayer::RadioMeasurement(
+                RadioMeasurementActionFrame::new(self.bytes()),
+            )),
+            ActionCategory::WNM => Some(ActionFrameLayer::WNM(WNMActionFrame::new(self.bytes()))),
+            _ => None,
+        }
+    }
+}
+
+impl FrameTrait for ActionFrame<'_> {
+    fn bytes(&self) -> &[u8] {
+        self.bytes
+    }
+}
+impl FragmentSequenceTrait for ActionFrame<'_> {}
+impl ManagementFrameTrait for ActionFrame<'_> {}
+impl ActionFixedParametersTrait for ActionFrame<'_> {}
@@ -0,0 +1,97 @@
+use super::*;
+
+/// Wi-Fi Direct public action frame
+///
+/// Category, public action, Wi-Fi Alliance OUI, OUI type 9, OUI subtype and
+/// dialog token, followed by P2P and WPS elements.
+pub struct P2PPublicActionFrame<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> P2PPublicActionFrame<'a> {
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+
+    pub fn p2p_subtype(&self) -> P2PPublicActionSubtype {
+        P2PPublicActionSubtype::from_u8(self.bytes()[Self::FIXED_PARAMETERS_END + 4])
+    }
+
+    pub fn dialog_token(&self) -> u8 {
+        self.bytes()[Self::FIXED_PARAMETERS_END + 5]
+    }
+
+    pub fn p2p(&self) -> Option<P2P> {
+        self.tagged_parameters().ok()?.p2p()
+    }
+}
+
+impl FrameTrait for P2PPublicActionFrame<'_> {
+    fn bytes(&self) -> &[u8] {
+        self.bytes
+    }
+}
+impl FragmentSequenceTrait for P2PPublicActionFrame<'_> {}
+impl ManagementFrameTrait for P2PPublicActionFrame<'_> {}
+impl ActionFixedParametersTrait for P2PPublicActionFrame<'_> {}
+impl TaggedParametersTrait for P2PPublicActionFrame<'_> {
+    const TAGGED_PARAMETERS_START: usize = 32;
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum P2PPublicActionSubtype {
+    /// GO Negotiation Request
+    GONegotiationRequest, // 0
+    /// GO Negotiation Response
+    GONegotiationResponse, // 1
+    /// GO Negotiation Confirmation
+    GONegotiationConfirmation, // 2
+    /// P2P Invitation Request
+    InvitationRequest, // 3
+    /// P2P Invitation Response
+    InvitationResponse, // 4
+    /// Device Discoverability Request
+    DeviceDiscoverabilityRequest, // 5
+    /// Device Discoverability Response
+    DeviceDiscoverabilityResponse, // 6
+    /// Provision Discovery Request
+    ProvisionDiscoveryRequest, // 7
+    /// Provision Discovery Response
+    ProvisionDiscoveryResponse, // 8
+    /// 9-255 Reserved
+    Reserved(u8),
+}
+
+impl P2PPublicActionSubtype {
+    #[must_use]
+    pub fn from_u8(n: u8) -> Self {
+        match n {
+            0 => Self::GONegotiationRequest,
+            1 => Self::GONegotiationResponse,
+            2 => Self::GONegotiationConfirmation,
+            3 => Self::InvitationRequest,
+            4 => Self::InvitationResponse,
+            5 => Self::DeviceDiscoverabilityRequest,
+            6 => Self::DeviceDiscoverabilityResponse,
+            7 => Self::ProvisionDiscoveryRequest,
+            8 => Self::ProvisionDiscoveryResponse,
+            other => Self::Reserved(other),
+        }
+    }
+
+    #[must_use]
+    pub fn into_u8(self) -> u8 {
+        match self {
+            Self::GONegotiationRequest => 0,
+            Self::GONegotiationResponse => 1,
+            Self::GONegotiationConfirmation => 2,
+            Self::InvitationRequest => 3,
+            Self::InvitationResponse => 4,
+            Self::DeviceDiscoverabilityRequest => 5,
+            Self::DeviceDiscoverabilityResponse => 6,
+            Self::ProvisionDiscoveryRequest => 7,
+            Self::ProvisionDiscoveryResponse => 8,
+            Self::Reserved(other) => other,
+        }
+    }
+}
@@ -0,0 +1,150 @@
+use super::*;
+
+pub struct PublicActionFrame<'a> {
+    bytes: &'a [u8],
+}
+
+pub enum PublicActionFrameLayer<'a> {
+    P2P(P2PPublicActionFrame<'a>),
+    GASInitialRequest(GASInitialRequestFrame<'a>),
+    GASInitialResponse(GASInitialResponseFrame<'a>),
+    GASComebackRequest(GASComebackRequestFrame<'a>),
+    GASComebackResponse(GASComebackResponseFrame<'a>),
+}
+
+impl<'a> PublicActionFrame<'a> {
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+
+    pub fn public_action(&self) -> PublicAction {
+        PublicAction::from_u8(self.action())
+    }
+
+    /// OUI and OUI type of a Vendor Specific public action
+    pub fn vendor_specific(&self) -> Option<VendorSpecific<'_>> {
+        if self.public_action() != PublicAction::VendorSpecific {
+            return None;
+        }
+
+        VendorSpecific::new(&self.bytes()[Self::FIXED_PARAMETERS_END..])
+    }
+
+    pub fn next_layer(&self) -> Option<PublicActionFrameLayer<'_>> {
+        let len = self.bytes().len();
+
+        // dialog token, then status code, fragment ID and comeback delay
+        match self.public_action() {
+            PublicAction::GASInitialRequest if len > Self::FIXED_PARAMETERS_END => {
+                Some(PublicActionFrameLayer::GASInitialRequest(
+                    GASInitialRequestFrame::new(self.bytes()),
+                ))
+            }
+            PublicAction::GASInitialResponse if len >= Self::FIXED_PARAMETERS_END + 5 => {
+                Some(PublicActionFrameLayer::GASInitialResponse(
+                    GASInitialResponseFrame::new(self.bytes()),
+                ))
+            }
+            PublicAction::GASComebackRequest if len > Self::FIXED_PARAMETERS_END => {
+                Some(PublicActionFrameLayer::GASComebackRequest(
+                    GASComebackRequestFrame::new(self.bytes()),
+                ))
+            }
+            PublicAction::GASComebackResponse if len >= Self::FIXED_PARAMETERS_END + 6 => {
+                Some(PublicActionFrameLayer::GASComebackResponse(
+                    GASComebackResponseFrame::new(self.bytes()),
+                ))
+            }
+            PublicAction::VendorSpecific => {
+                let vendor_specific = self.vendor_specific()?;
+
+                if vendor_specific.oui() == WIFI_ALLIANCE_OUI
+                    && vendor_specific.oui_type() == Some(P2P_OUI_TYPE)
+                    && len >= P2PPublicActionFrame::TAGGED_PARAMETERS_START
+                {
+                    return Some(PublicActionFrameLayer::P2P(P2PPublicActionFrame::new(
+                        self.bytes(),
+                    )));
+                }
+
+                None
+            }
+            _ => None,
+        }
+    }
+}
+
+impl FrameTrait for PublicActionFrame<'_> {
+    fn bytes(&self) -> &[u8] {
+        self.bytes
+    }
+}
+impl FragmentSequenceTrait for PublicActionFrame<'_> {}
+impl ManagementFrameTrait for PublicActionFrame<'_> {}
+impl ActionFixedParametersTrait for PublicActionFrame<'_> {}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum PublicAction {
+    /// 20/40 BSS Coexistence Management
+    BSSCoexistenceManagement, // 0
+    /// Extended Channel Switch Announcement
+    ExtendedChannelSwitchAnnouncement, // 4
+    /// Measurement Pilot
+    MeasurementPilot, // 7
+    /// Vendor Specific
+    VendorSpecific, // 9
+    /// GAS Initial Request
+    GASInitialRequest, // 10
+    /// GAS Initial Response
+    GASInitialResponse, // 11
+    /// GAS Comeback Request
+    GASComebackRequest, // 12
+    /// GAS Comeback Response
+    GASComebackResponse, // 13
+    /// TDLS Discovery Response
+    TDLSDiscoveryResponse, // 14
+    /// Fine Timing Measurement Request
+    FTMRequest, // 32
+    /// Fine Timing Measurement
+    FTM, // 33
+    /// any other public action
+    Reserved(u8),
+}
+
+impl PublicAction {
+    #[must_use]
+    pub fn from_u8(n: u8) -> Self {
+        match n {
+            0 => Self::BSSCoexistenceManagement,
+            4 => Self::ExtendedChannelSwitchAnnouncement,
+            7 => Self::MeasurementPilot,
+            9 => Self::VendorSpecific,
+            10 => Self::GASInitialRequest,
+            11 => Self::GASInitialResponse,
+            12 => Self::GASComebackRequest,
+            13 => Self::GASComebackResponse,
+            14 => Self::TDLSDiscoveryResponse,
+            32 => Self::FTMRequest,
+            33 => Self::FTM,
+            other => Self::Reserved(other),
+        }
+    }
+
+    #[must_use]
+    pub fn into_u8(self) -> u8 {
+        match self {
+            Self::BSSCoexistenceManagement => 0,
+            Self::ExtendedChannelSwitchAnnouncement => 4,
+            Self::MeasurementPilot => 7,
+            Self::VendorSpecific => 9,
+            Self::GASInitialRequest => 10,
+            Self::GASInitialResponse => 11,
+            Self::GASComebackRequest => 12,
+            Self::GASComebackResponse => 13,
+            Self::TDLSDiscoveryResponse => 14,
+            Self::FTMRequest => 32,
+            Self::FTM => 33,
+            Self::Reserved(other) => other,
+        }
+    }
+}
@@ -0,0 +1,157 @@
+use super::*;
+
+pub struct RadioMeasurementActionFrame<'a> {
+    bytes: &'a [u8],
+}
+
+pub enum RadioMeasurementActionFrameLayer<'a> {
+    NeighborReportRequest(NeighborReportRequestFrame<'a>),
+    NeighborReportResponse(NeighborReportResponseFrame<'a>),
+}
+
+impl<'a> RadioMeasurementActionFrame<'a> {
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+
+    pub fn radio_measurement_action(&self) -> RadioMeasurementAction {
+        RadioMeasurementAction::from_u8(self.action())
+    }
+
+    pub fn next_layer(&self) -> Option<RadioMeasurementActionFrameLayer<'_>> {
+        // dialog token
+        if self.bytes().len() <= Self::FIXED_PARAMETERS_END {
+            return None;
+        }
+
+        match self.radio_measurement_action() {
+            RadioMeasurementAction::NeighborReportRequest => {
+                Some(RadioMeasurementActionFrameLayer::NeighborReportRequest(
+                    NeighborReportRequestFrame::new(self.bytes()),
+                ))
+            }
+            RadioMeasurementAction::NeighborReportResponse => {
+                Some(RadioMeasurementActionFrameLayer::NeighborReportResponse(
+                    NeighborReportResponseFrame::new(self.bytes()),
+                ))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl FrameTrait for RadioMeasurementActionFrame<'_> {
+    fn bytes(&self) -> &[u8] {
+        self.bytes
+    }
+}
+impl FragmentSequenceTrait for RadioMeasurementActionFrame<'_> {}
+impl ManagementFrameTrait for RadioMeasurementActionFrame<'_> {}
+impl ActionFixedParametersTrait for RadioMeasurementActionFrame<'_> {}
+
+/// Dialog token, followed by optional SSID, LCI and Location Civic
+/// Measurement Request elements
+pub struct NeighborReportRequestFrame<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> NeighborReportRequestFrame<'a> {
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+
+    pub fn dialog_token(&self) -> u8 {
+        self.bytes()[Self::FIXED_PARAMETERS_END]
+    }
+}
+
+impl FrameTrait for NeighborReportRequestFrame<'_> {
+    fn bytes(&self) -> &[u8] {
+        self.bytes
+    }
+}
+impl FragmentSequenceTrait for NeighborReportRequestFrame<'_> {}
+impl ManagementFrameTrait for NeighborReportRequestFrame<'_> {}
+impl ActionFixedParametersTrait for NeighborReportRequestFrame<'_> {}
+impl TaggedParametersTrait for NeighborReportRequestFrame<'_> {
+    const TAGGED_PARAMETERS_START: usize = 27;
+}
+
+/// Dialog token, followed by Neighbor Report elements
+pub struct NeighborReportResponseFrame<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> NeighborReportResponseFrame<'a> {
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+
+    pub fn dialog_token(&self) -> u8 {
+        self.bytes()[Self::FIXED_PARAMETERS_END]
+    }
+
+    pub fn neighbor_reports(&self) -> Vec<NeighborReport> {
+        self.tagged_parameters()
+            .map(|tagged_parameters| tagged_parameters.neighbor_reports())
+            .unwrap_or_default()
+    }
+}
+
+impl FrameTrait for NeighborReportResponseFrame<'_> {
+    fn bytes(&self) -> &[u8] {
+        self.bytes
+    }
+}
+impl FragmentSequenceTrait for NeighborReportResponseFrame<'_> {}
+impl ManagementFrameTrait for NeighborReportResponseFrame<'_> {}
+impl ActionFixedParametersTrait for NeighborReportResponseFrame<'_> {}
+impl TaggedParametersTrait for NeighborReportResponseFrame<'_> {
+    const TAGGED_PARAMETERS_START: usize = 27;
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum RadioMeasurementAction {
+    /// Radio Measurement Request
+    RadioMeasurementRequest, // 0
+    /// Radio Measurement Report
+    RadioMeasurementReport, // 1
+    /// Link Measurement Request
+    LinkMeasurementRequest, // 2
+    /// Link Measurement Report
+    LinkMeasurementReport, // 3
+    /// Neighbor Report Request
+    NeighborReportRequest, // 4
+    /// Neighbor Report Response
+    NeighborReportResponse, // 5
+    /// 6-255 Reserved
+    Reserved(u8),
+}
+
+impl RadioMeasurementAction {
+    #[must_use]
+    pub fn from_u8(n: u8) -> Self {
+        match n {
+            0 => Self::RadioMeasurementRequest,
+            1 => Self::RadioMeasurementReport,
+            2 => Self::LinkMeasurementRequest,
+            3 => Self::LinkMeasurementReport,
+            4 => Self::NeighborReportRequest,
+            5 => Self::NeighborReportResponse,
+            other => Self::Reserved(other),
+        }
+    }
+
+    #[must_use]
+    pub fn into_u8(self) -> u8 {
+        match self {
+            Self::RadioMeasurementRequest => 0,
+            Self::RadioMeasurementReport => 1,
+            Self::LinkMeasurementRequest => 2,
+            Self::LinkMeasurementReport => 3,
+            Self::NeighborReportRequest => 4,
+            Self::NeighborReportResponse => 5,
+            Self::Reserved(other) => other,
+        }
+    }
+}
@@ -0,0 +1,318 @@
+use super::*;
+use byteorder::{ByteOrder, LittleEndian};
+
+/// Neighbor Report elements of a BSS Transition Candidate List Entries field
+fn read_candidate_list(bytes: &[u8]) -> Vec<NeighborReport> {
+    TaggedParameterIterator::new(bytes)
+        .map_while(Result::ok)
+        .filter(|(tag_name, _)| *tag_name == TagName::NeighborReport)
+        .filter_map(|(_, bytes)| NeighborReport::from_bytes(bytes))
+        .collect()
+}
+
+pub struct WNMActionFrame<'a> {
+    bytes: &'a [u8],
+}
+
+pub enum WNMActionFrameLayer<'a> {
+    BSSTransitionManagementQuery(BSSTransitionManagementQueryFrame<'a>),
+    BSSTransitionManagementRequest(BSSTransitionManagementRequestFrame<'a>),
+    BSSTransitionManagementResponse(BSSTransitionManagementResponseFrame<'a>),
+}
+
+impl<'a> WNMActionFrame<'a> {
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+
+    pub fn wnm_action(&self) -> WNMAction {
+        WNMAction::from_u8(self.action())
+    }
+
+    pub fn next_layer(&self) -> Option<WNMActionFrameLayer<'_>> {
+        let len = self.bytes().len();
+
+        // dialog token, then query reason, status code and BSS termination
+        // delay, or request mode, disassociation timer and validity interval
+        match self.wnm_action() {
+            WNMAction::BSSTransitionManagementQuery if len >= Self::FIXED_PARAMETERS_END + 2 => {
+                Some(WNMActionFrameLayer::BSSTransitionManagementQuery(
+                    BSSTransitionManagementQueryFrame::new(self.bytes()),
+                ))
+            }
+            WNMAction::BSSTransitionManagementRequest if len >= Self::FIXED_PARAMETERS_END + 5 => {
+                Some(WNMActionFrameLayer::BSSTransitionManagementRequest(
+                    BSSTransitionManagementRequestFrame::new(self.bytes()),
+                ))
+            }
+            WNMAction::BSSTransitionManagementResponse if len >= Self::FIXED_PARAMETERS_END + 3 => {
+                Some(WNMActionFrameLayer::BSSTransitionManagementResponse(
+                    BSSTransitionManagementResponseFrame::new(self.bytes()),
+                ))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl FrameTrait for WNMActionFrame<'_> {
+    fn bytes(&self) -> &[u8] {
+        self.bytes
+    }
+}
+impl FragmentSequenceTrait for WNMActionFrame<'_> {}
+impl ManagementFrameTrait for WNMActionFrame<'_> {}
+impl ActionFixedParametersTrait for WNMActionFrame<'_> {}
+
+pub struct BSSTransitionManagementQueryFrame<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> BSSTransitionManagementQueryFrame<'a> {
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+
+    pub fn dialog_token(&self) -> u8 {
+        self.bytes()[Self::FIXED_PARAMETERS_END]
+    }
+
+    pub fn bss_transition_query_reason(&self) -> u8 {
+        self.bytes()[Self::FIXED_PARAMETERS_END + 1]
+    }
+
+    pub fn candidate_list(&self) -> Vec<NeighborReport> {
+        read_candidate_list(&self.bytes()[(Self::FIXED_PARAMETERS_END + 2)..])
+    }
+}
+
+impl FrameTrait for BSSTransitionManagementQueryFrame<'_> {
+    fn bytes(&self) -> &[u8] {
+        self.bytes
+    }
+}
+impl FragmentSequenceTrait for BSSTransitionManagementQueryFrame<'_> {}
+impl ManagementFrameTrait for BSSTransitionManagementQueryFrame<'_> {}
+impl ActionFixedParametersTrait for BSSTransitionManagementQueryFrame<'_> {}
+
+pub struct BSSTransitionManagementRequestFrame<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> BSSTransitionManagementRequestFrame<'a> {
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+
+    pub fn dialog_token(&self) -> u8 {
+        self.bytes()[Self::FIXED_PARAMETERS_END]
+    }
+
+    pub fn request_mode(&self) -> BSSTransitionRequestMode {
+        BSSTransitionRequestMode::from_u8(self.bytes()[Self::FIXED_PARAMETERS_END + 1])
+    }
+
+    /// in beacon intervals, 0 when the AP does not disassociate the station
+    pub fn disassociation_timer(&self) -> u16 {
+        let start = Self::FIXED_PARAMETERS_END + 2;
+        LittleEndian::read_u16(&self.bytes()[start..(start + 2)])
+    }
+
+    /// in beacon intervals
+    pub fn validity_interval(&self) -> u8 {
+        self.bytes()[Self::FIXED_PARAMETERS_END + 4]
+    }
+
+    /// BSS termination TSF and duration in minutes
+    pub fn bss_termination_duration(&self) -> Option<(u64, u16)> {
+        if !self.request_mode().bss_termination_included {
+            return None;
+        }
+
+        // subelement ID and length
+        let start = Self::FIXED_PARAMETERS_END + 7;
+        let bytes = self.bytes().get(start..(start + 10))?;
+
+        Some((
+            LittleEndian::read_u64(&bytes[0..8]),
+            LittleEndian::read_u16(&bytes[8..10]),
+        ))
+    }
+
+    pub fn session_information_url(&self) -> Option<&[u8]> {
+        if !self.request_mode().ess_disassociation_imminent {
+            return None;
+        }
+
+        let start = self.session_information_url_start();
+        let length = *self.bytes().get(start)? as usize;
+        self.bytes().get((start + 1)..(start + 1 + length))
+    }
+
+    pub fn candidate_list(&self) -> Vec<NeighborReport> {
+        if !self.request_mode().preferred_candidate_list_included {
+            return Vec::new();
+        }
+
+        let mut start = self.session_information_url_start();
+        if self.request_mode().ess_disassociation_imminent {
+            match self.bytes().get(start) {
+                Some(&length) => start += 1 + length as usize,
+                None => return Vec::new(),
+            }
+        }
+
+        read_candidate_list(self.bytes().get(start..).unwrap_or_default())
+    }
+
+    fn session_information_url_start(&self) -> usize {
+        let start = Self::FIXED_PARAMETERS_END + 5;
+        if self.request_mode().bss_termination_included {
+            start + 12
+        } else {
+            start
+        }
+    }
+}
+
+impl FrameTrait for BSSTransitionManagementRequestFrame<'_> {
+    fn bytes(&self) -> &[u8] {
+        self.bytes
+    }
+}
+impl FragmentSequenceTrait for BSSTransitionManagementRequestFrame<'_> {}
+impl ManagementFrameTrait for BSSTransitionManagementRequestFrame<'_> {}
+impl ActionFixedParametersTrait for BSSTransitionManagementRequestFrame<'_> {}
+
+/// Request Mode field of the BSS Transition Management Request frame
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct BSSTransitionRequestMode {
+    pub preferred_candidate_list_included: bool,
+    pub abridged: bool,
+    pub disassociation_imminent: bool,
+    pub bss_termination_included: bool,
+    pub ess_disassociation_imminent: bool,
+}
+
+impl BSSTransitionRequestMode {
+    #[must_use]
+    pub fn from_u8(n: u8) -> Self {
+        Self {
+            preferred_candidate_list_included: (n & 0b0000_0001) != 0,
+            abridged: (n & 0b0000_0010) != 0,
+            disassociation_imminent: (n & 0b0000_0100) != 0,
+            bss_termination_included: (n & 0b0000_1000) != 0,
+            ess_disassociation_imminent: (n & 0b0001_0000) != 0,
+        }
+    }
+}
+
+pub struct BSSTransitionManagementResponseFrame<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> BSSTransitionManagementResponseFrame<'a> {
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+
+    pub fn dialog_token(&self) -> u8 {
+        self.bytes()[Self::FIXED_PARAMETERS_END]
+    }
+
+    /// 0 when the station accepts the transition
+    pub fn status_code(&self) -> u8 {
+        self.bytes()[Self::FIXED_PARAMETERS_END + 1]
+    }
+
+    /// in minutes
+    pub fn bss_termination_delay(&self) -> u8 {
+        self.bytes()[Self::FIXED_PARAMETERS_END + 2]
+    }
+
+    /// BSS the station moves to, present when the transition is accepted
+    pub fn target_bssid(&self) -> Option<MacAddress> {
+        if self.status_code() != 0 {
+            return None;
+        }
+
+        let start = Self::FIXED_PARAMETERS_END + 3;
+        MacAddress::from_bytes(self.bytes().get(start..(start + 6))?).ok()
+    }
+
+    pub fn candidate_list(&self) -> Vec<NeighborReport> {
+        let mut start = Self::FIXED_PARAMETERS_END + 3;
+        if self.status_code() == 0 {
+            start += 6;
+        }
+
+        read_candidate_list(self.bytes().get(start..).unwrap_or_default())
+    }
+}
+
+impl FrameTrait for BSSTransitionManagementResponseFrame<'_> {
+    fn bytes(&self) -> &[u8] {
+        self.bytes
+    }
+}
+impl FragmentSequenceTrait for BSSTransitionManagementResponseFrame<'_> {}
+impl ManagementFrameTrait for BSSTransitionManagementResponseFrame<'_> {}
+impl ActionFixedParametersTrait for BSSTransitionManagementResponseFrame<'_> {}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum WNMAction {
+    /// Event Request
+    EventRequest, // 0
+    /// Event Report
+    EventReport, // 1
+    /// Diagnostic Request
+    DiagnosticRequest, // 2
+    /// Diagnostic Report
+    DiagnosticReport, // 3
+    /// Location Configuration Request
+    LocationConfigurationRequest, // 4
+    /// Location Configuration Response
+    LocationConfigurationResponse, // 5
+    /// BSS Transition Management Query
+    BSSTransitionManagementQuery, // 6
+    /// BSS Transition Management Request
+    BSSTransitionManagementRequest, // 7
+    /// BSS Transition Management Response
+    BSSTransitionManagementResponse, // 8
+    /// any other WNM action
+    Reserved(u8),
+}
+
+impl WNMAction {
+    #[must_use]
+    pub fn from_u8(n: u8) -> Self {
+        match n {
+            0 => Self::EventRequest,
+            1 => Self::EventReport,
+            2 => Self::DiagnosticRequest,
+            3 => Self::DiagnosticReport,
+            4 => Self::LocationConfigurationRequest,
+            5 => Self::LocationConfigurationResponse,
+            6 => Self::BSSTransitionManagementQuery,
+            7 => Self::BSSTransitionManagementRequest,
+            8 => Self::BSSTransitionManagementResponse,
+            other => Self::Reserved(other),
+        }
+    }
+
+    #[must_use]
+    pub fn into_u8(self) -> u8 {
+        match self {
+            Self::EventRequest => 0,
+            Self::EventReport => 1,
+            Self::DiagnosticRequest => 2,
+            Self::DiagnosticReport => 3,
+            Self::LocationConfigurationRequest => 4,
+            Self::LocationConfigurationResponse => 5,
+            Self::BSSTransitionManagementQuery => 6,
+            Self::BSSTransitionManagementRequest => 7,
+            Self::BSSTransitionManagementResponse => 8,
+            Self::Reserved(other) => other,
+        }
+    }
+}
@@ -26,7 +26,7 @@ pub trait BeaconFixedParametersTrait: FrameTrait {
     }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct CapabilitiesInfo {
     /// 0: Transmitter is a STA
     /// 1: Transmitter is an AP
@@ -23,7 +23,7 @@ impl ManagementFrameBuilder {
     }
 
     #[must_use]
-    pub fn build(&self) -> ManagementFrame {
+    pub fn build(&self) -> ManagementFrame<'_> {
         ManagementFrame::new(self.bytes().to_vec())
     }
 }
@@ -71,6 +71,7 @@ pub trait ManagementFrameBuilderTrait: FrameBuilderTrait + FragmentSequenceBuild
 }
 
 #[test]
+#[allow(clippy::bool_assert_comparison)]
 fn test_management_frame_builder() {
     let mut management_frame_builder = ManagementFrameBuilder::new_blank();
 
@@ -24,7 +24,7 @@ impl DeauthenticationFrameBuilder {
     }
 
     #[must_use]
-    pub fn build(&self) -> DeauthenticationFrame {
+    pub fn build(&self) -> DeauthenticationFrame<'_> {
         DeauthenticationFrame::new(self.bytes().to_vec())
     }
 }
@@ -42,6 +42,7 @@ impl ManagementFrameBuilderTrait for DeauthenticationFrameBuilder {}
 impl DeauthenticationFixedParametersBuilderTrait for DeauthenticationFrameBuilder {}
 
 #[test]
+#[allow(clippy::bool_assert_comparison)]
 fn test_deauthentication_frame_builder() {
     let mut deauthentication_frame_builder = DeauthenticationFrameBuilder::new();
 
@@ -22,7 +22,7 @@ impl DisassociateFrameBuilder {
     }
 
     #[must_use]
-    pub fn build(&self) -> DisassociateFrame {
+    pub fn build(&self) -> DisassociateFrame<'_> {
         DisassociateFrame::new(self.bytes().to_vec())
     }
 }
@@ -40,6 +40,7 @@ impl ManagementFrameBuilderTrait for DisassociateFrameBuilder {}
 impl DisassociateFixedParametersBuilderTrait for DisassociateFrameBuilder {}
 
 #[test]
+#[allow(clippy::bool_assert_comparison)]
 fn test_disassociate_frame_builder() {
     let mut disassociate_frame_builder = DisassociateFrameBuilder::new();
 
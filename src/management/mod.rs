@@ -1,3 +1,4 @@
+mod action;
 mod association_request;
 mod association_response;
 mod authentication;
@@ -7,12 +8,13 @@ mod deauthentication;
 mod disassociate;
 mod probe_request;
 mod probe_response;
+mod reassociation_request;
 mod tagged_parameters;
 
 pub use self::{
-    association_request::*, association_response::*, authentication::*, beacon::*, builder::*,
-    deauthentication::*, disassociate::*, probe_request::*, probe_response::*,
-    tagged_parameters::*,
+    action::*, association_request::*, association_response::*, authentication::*, beacon::*,
+    builder::*, deauthentication::*, disassociate::*, probe_request::*, probe_response::*,
+    reassociation_request::*, tagged_parameters::*,
 };
 use super::*;
 use std::borrow::Cow;
@@ -30,6 +32,8 @@ pub enum ManagementFrameLayer<'a> {
     Disassociate(DisassociateFrame<'a>),
     AssociationRequest(AssociationRequestFrame<'a>),
     AssociationResponse(AssociationResponseFrame<'a>),
+    ReassociationRequest(ReassociationRequestFrame<'a>),
+    Action(ActionFrame<'a>),
 }
 
 impl<'a> ManagementFrame<'a> {
@@ -72,6 +76,14 @@ impl<'a> ManagementFrame<'a> {
                         AssociationResponseFrame::new(self.bytes()),
                     ))
                 }
+                ManagementSubtype::ReassociationRequest => {
+                    Some(ManagementFrameLayer::ReassociationRequest(
+                        ReassociationRequestFrame::new(self.bytes()),
+                    ))
+                }
+                ManagementSubtype::Action | ManagementSubtype::ActionNoAck => {
+                    Some(ManagementFrameLayer::Action(ActionFrame::new(self.bytes())))
+                }
                 _ => None,
             },
             _ => unreachable!(),
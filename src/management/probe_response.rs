@@ -8,6 +8,15 @@ impl<'a> ProbeResponseFrame<'a> {
     pub fn new(bytes: &'a [u8]) -> Self {
         Self { bytes }
     }
+
+    /// BSSs advertised in the Multiple BSSID elements, see
+    /// `TaggedParameters::nontransmitted_bsss`
+    pub fn nontransmitted_bsss(&self) -> Vec<NontransmittedBSS<'_>> {
+        match (self.tagged_parameters(), self.bssid_address()) {
+            (Ok(tagged_parameters), Some(bssid)) => tagged_parameters.nontransmitted_bsss(bssid),
+            _ => Vec::new(),
+        }
+    }
 }
 impl FrameTrait for ProbeResponseFrame<'_> {
     fn bytes(&self) -> &[u8] {
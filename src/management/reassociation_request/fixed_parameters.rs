@@ -0,0 +1,27 @@
+use super::*;
+use byteorder::{ByteOrder, LittleEndian};
+
+pub trait ReassociationRequestFixedParametersTrait: FrameTrait {
+    const FIXED_PARAMETERS_START: usize = 24;
+    const FIXED_PARAMETERS_END: usize = Self::FIXED_PARAMETERS_START + 10;
+
+    fn capabilities_info(&self) -> CapabilitiesInfo {
+        CapabilitiesInfo::from_bytes(
+            &self.bytes()[(Self::FIXED_PARAMETERS_START)..(Self::FIXED_PARAMETERS_START + 2)],
+        )
+    }
+
+    fn listen_interval(&self) -> u16 {
+        LittleEndian::read_u16(
+            &self.bytes()[(Self::FIXED_PARAMETERS_START + 2)..(Self::FIXED_PARAMETERS_START + 4)],
+        )
+    }
+
+    /// AP the station is currently associated with
+    fn current_ap_address(&self) -> MacAddress {
+        MacAddress::from_bytes(
+            &self.bytes()[(Self::FIXED_PARAMETERS_START + 4)..(Self::FIXED_PARAMETERS_START + 10)],
+        )
+        .unwrap()
+    }
+}
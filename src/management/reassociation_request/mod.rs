@@ -0,0 +1,40 @@
+mod fixed_parameters;
+
+pub use self::fixed_parameters::*;
+use super::*;
+
+pub struct ReassociationRequestFrame<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> ReassociationRequestFrame<'a> {
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+}
+impl FrameTrait for ReassociationRequestFrame<'_> {
+    fn bytes(&self) -> &[u8] {
+        self.bytes
+    }
+}
+impl FragmentSequenceTrait for ReassociationRequestFrame<'_> {}
+impl ManagementFrameTrait for ReassociationRequestFrame<'_> {}
+impl ReassociationRequestFixedParametersTrait for ReassociationRequestFrame<'_> {}
+impl TaggedParametersTrait for ReassociationRequestFrame<'_> {
+    const TAGGED_PARAMETERS_START: usize = Self::FIXED_PARAMETERS_END;
+}
+
+use std::fmt;
+impl fmt::Display for ReassociationRequestFrame<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ReassociationRequest")?;
+
+        if let Some(transmitter_address) = self.transmitter_address() {
+            write!(f, " tx: {}", transmitter_address)?;
+        }
+
+        write!(f, " rx: {}", self.receiver_address())?;
+
+        Ok(())
+    }
+}
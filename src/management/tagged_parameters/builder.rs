@@ -0,0 +1,770 @@
+use super::*;
+use std::{error::Error, fmt};
+
+/// Most rates a Supported Rates element can hold, the rest go in an Extended
+/// Supported Rates element
+const MAX_SUPPORTED_RATES: usize = 8;
+
+/// Why a tag could not be added
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum TaggedParameterError {
+    /// The tag data is longer than the 255 bytes a tag can hold
+    TooLong(usize),
+    /// The RSN element has later fields set without the group cipher suite
+    MissingGroupCipherSuite,
+    /// The rate is not a multiple of 500 kbps up to 63.5 Mbps, or reads as a
+    /// membership selector
+    InvalidRate(Rate),
+}
+
+impl fmt::Display for TaggedParameterError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::TooLong(length) => write!(
+                f,
+                "TaggedParameterError: {} bytes of tag data, at most 255 fit",
+                length
+            ),
+            Self::MissingGroupCipherSuite => write!(
+                f,
+                "TaggedParameterError: RSN fields set without the group cipher suite"
+            ),
+            Self::InvalidRate(rate) => write!(f, "TaggedParameterError: invalid rate {:?}", rate),
+        }
+    }
+}
+
+impl Error for TaggedParameterError {}
+
+#[derive(Default)]
+pub struct TaggedParametersBuilder {
+    bytes: Vec<u8>,
+}
+impl TaggedParametersBuilder {
+    #[must_use]
+    pub fn new() -> Self {
+        Self { bytes: Vec::new() }
+    }
+
+    #[must_use]
+    pub fn bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    #[must_use]
+    pub fn build(&self) -> TaggedParameters<'static> {
+        let mut tagged_parameters = TaggedParameters::new();
+
+        for (tag_name, tag_data) in TaggedParameterIterator::new(&self.bytes).flatten() {
+            tagged_parameters.add(tag_name, tag_data.to_vec());
+        }
+
+        tagged_parameters
+    }
+
+    /// Appends a tag, nothing is added if `tag_data` is longer than 255 bytes
+    pub fn add(&mut self, tag_name: TagName, tag_data: &[u8]) -> Result<(), TaggedParameterError> {
+        let length = u8::try_from(tag_data.len())
+            .map_err(|_| TaggedParameterError::TooLong(tag_data.len()))?;
+
+        self.bytes.push(tag_name.into());
+        self.bytes.push(length);
+        self.bytes.extend_from_slice(tag_data);
+        Ok(())
+    }
+
+    pub fn ssid(&mut self, ssid: &[u8]) -> Result<(), TaggedParameterError> {
+        self.add(TagName::SSID, ssid)
+    }
+
+    /// Appends a Supported Rates element with the first 8 rates, and an
+    /// Extended Supported Rates element with the rest if there are more
+    ///
+    /// Nothing is added if `rates` is empty, has a rate that can not be
+    /// encoded, or has more than 263 rates.
+    pub fn rates(&mut self, rates: &[Rate]) -> Result<(), TaggedParameterError> {
+        let bytes = rates
+            .iter()
+            .map(|&rate| {
+                rate.into_u8()
+                    .ok_or(TaggedParameterError::InvalidRate(rate))
+            })
+            .collect::<Result<Vec<u8>, _>>()?;
+        if bytes.is_empty() {
+            return Ok(());
+        }
+
+        let (supported_rates, extended_supported_rates) =
+            bytes.split_at(bytes.len().min(MAX_SUPPORTED_RATES));
+        if extended_supported_rates.len() > 255 {
+            return Err(TaggedParameterError::TooLong(
+                extended_supported_rates.len(),
+            ));
+        }
+        self.add(TagName::SupportedRates, supported_rates)?;
+        if !extended_supported_rates.is_empty() {
+            self.add(TagName::ExtendedSupportedRates, extended_supported_rates)?;
+        }
+        Ok(())
+    }
+
+    pub fn extended_capabilities(
+        &mut self,
+        extended_capabilities: &ExtendedCapabilities,
+    ) -> Result<(), TaggedParameterError> {
+        self.add(TagName::ExtendedCapabilities, &extended_capabilities.bytes)
+    }
+
+    pub fn country(&mut self, country: &Country) -> Result<(), TaggedParameterError> {
+        self.add(TagName::CountryInformation, &country.to_bytes())
+    }
+
+    pub fn multiple_bssid(
+        &mut self,
+        multiple_bssid: &MultipleBSSID,
+    ) -> Result<(), TaggedParameterError> {
+        self.add(TagName::MultipleBSSID, &multiple_bssid.to_bytes())
+    }
+
+    pub fn traffic_indication_map(
+        &mut self,
+        traffic_indication_map: &TrafficIndicationMap,
+    ) -> Result<(), TaggedParameterError> {
+        self.add(
+            TagName::TrafficIndicationMap,
+            &traffic_indication_map.to_bytes(),
+        )
+    }
+
+    /// Nothing is added when `rsn` has later fields set without the group
+    /// cipher suite
+    pub fn rsn(&mut self, rsn: &RSN) -> Result<(), TaggedParameterError> {
+        let tag_data = rsn
+            .to_bytes()
+            .ok_or(TaggedParameterError::MissingGroupCipherSuite)?;
+        self.add(TagName::RSNInformation, &tag_data)
+    }
+
+    /// Appends a Vendor Specific element, `data` follows the OUI
+    pub fn vendor_specific(
+        &mut self,
+        oui: [u8; 3],
+        data: &[u8],
+    ) -> Result<(), TaggedParameterError> {
+        let mut tag_data = oui.to_vec();
+        tag_data.extend_from_slice(data);
+        self.add(TagName::VendorSpecific, &tag_data)
+    }
+
+    pub fn wmm_information(
+        &mut self,
+        wmm_information: &WMMInformation,
+    ) -> Result<(), TaggedParameterError> {
+        self.add(TagName::VendorSpecific, &wmm_information.to_bytes())
+    }
+
+    pub fn wmm_parameter(
+        &mut self,
+        wmm_parameter: &WMMParameter,
+    ) -> Result<(), TaggedParameterError> {
+        self.add(TagName::VendorSpecific, &wmm_parameter.to_bytes())
+    }
+}
+
+#[test]
+fn test_tagged_parameters_builder() {
+    let rsn = RSN {
+        group_cipher_suite: Some(CipherSuite::Standard(CipherSuiteType::TKIP)),
+        pairwise_cipher_suites: vec![
+            CipherSuite::Standard(CipherSuiteType::CCMP),
+            CipherSuite::Vendor([0x00, 0x50, 0xf2], 2),
+        ],
+        akm_suites: vec![
+            AKMSuite::Standard(AKMSuiteType::PSK),
+            AKMSuite::Standard(AKMSuiteType::SAE),
+        ],
+        capabilities: Some(RSNCapabilities {
+            pre_auth: true,
+            pairwise: false,
+            ptksa_replay_counter_value: 3,
+            gtksa_replay_counter_value: 2,
+            management_frame_protection_required: false,
+            management_frame_protection_capable: true,
+            joint_multi_band_rsna: false,
+            peerkey: false,
+        }),
+        pmkids: vec![[0x11; 16], [0x22; 16]],
+        group_management_cipher_suite: Some(CipherSuite::Standard(CipherSuiteType::BIP)),
+    };
+
+    let mut tagged_parameters_builder = TaggedParametersBuilder::new();
+    tagged_parameters_builder.ssid(b"hello").unwrap();
+    tagged_parameters_builder.rsn(&rsn).unwrap();
+
+    let tagged_parameters = tagged_parameters_builder.build();
+
+    assert_eq!(tagged_parameters.ssid(), Some(&b"hello"[..]), "ssid");
+    assert_eq!(
+        tagged_parameters.rsn(),
+        Some(RSNVersion::Standard(rsn)),
+        "rsn"
+    );
+
+    // only the fields up to the last one set are written
+    let mut rsn = RSN {
+        group_cipher_suite: Some(CipherSuite::Standard(CipherSuiteType::CCMP)),
+        pairwise_cipher_suites: vec![CipherSuite::Standard(CipherSuiteType::CCMP)],
+        ..Default::default()
+    };
+    assert_eq!(
+        rsn.to_bytes(),
+        Some(vec![
+            0x01, 0x00, 0x00, 0x0f, 0xac, 0x04, 0x01, 0x00, 0x00, 0x0f, 0xac, 0x04
+        ]),
+        "rsn bytes"
+    );
+
+    // the group cipher suite can not be left out in front of later fields
+    rsn.group_cipher_suite = None;
+    assert_eq!(rsn.to_bytes(), None, "rsn without group cipher suite");
+    assert_eq!(
+        TaggedParametersBuilder::new().rsn(&rsn),
+        Err(TaggedParameterError::MissingGroupCipherSuite),
+        "builder rsn without group cipher suite"
+    );
+
+    // a tag holds at most 255 bytes
+    let mut tagged_parameters_builder = TaggedParametersBuilder::new();
+    assert_eq!(
+        tagged_parameters_builder.ssid(&[0; 256]),
+        Err(TaggedParameterError::TooLong(256)),
+        "ssid too long"
+    );
+    assert_eq!(tagged_parameters_builder.bytes(), &[], "nothing added");
+}
+
+#[test]
+fn test_tagged_parameters_builder_wmm() {
+    let wmm_parameter = WMMParameter {
+        version: 1,
+        qos_info: WMMAPQoSInfo {
+            parameter_set_count: 5,
+            uapsd: true,
+        }
+        .into_u8(),
+        ac_parameters: [
+            WMMACParameter {
+                aci: AccessCategory::BestEffort,
+                aifsn: 3,
+                acm: false,
+                ecw_min: 4,
+                ecw_max: 10,
+                txop_limit: 0,
+            },
+            WMMACParameter {
+                aci: AccessCategory::Background,
+                aifsn: 7,
+                acm: false,
+                ecw_min: 4,
+                ecw_max: 10,
+                txop_limit: 0,
+            },
+            WMMACParameter {
+                aci: AccessCategory::Video,
+                aifsn: 2,
+                acm: false,
+                ecw_min: 3,
+                ecw_max: 4,
+                txop_limit: 94,
+            },
+            WMMACParameter {
+                aci: AccessCategory::Voice,
+                aifsn: 2,
+                acm: true,
+                ecw_min: 2,
+                ecw_max: 3,
+                txop_limit: 47,
+            },
+        ],
+    };
+
+    let mut tagged_parameters_builder = TaggedParametersBuilder::new();
+    tagged_parameters_builder
+        .wmm_parameter(&wmm_parameter)
+        .unwrap();
+    tagged_parameters_builder
+        .wmm_information(&WMMInformation {
+            version: 1,
+            qos_info: 0b0000_1111,
+        })
+        .unwrap();
+
+    let tagged_parameters = tagged_parameters_builder.build();
+
+    assert_eq!(
+        tagged_parameters.wmm_parameter(),
+        Some(wmm_parameter),
+        "wmm_parameter"
+    );
+    assert_eq!(
+        tagged_parameters.wmm_parameter().unwrap().ap_qos_info(),
+        WMMAPQoSInfo {
+            parameter_set_count: 5,
+            uapsd: true,
+        },
+        "ap_qos_info"
+    );
+
+    let sta_qos_info = tagged_parameters.wmm_information().unwrap().sta_qos_info();
+    assert!(sta_qos_info.ac_vo_uapsd, "ac_vo_uapsd");
+    assert!(sta_qos_info.ac_be_uapsd, "ac_be_uapsd");
+    assert_eq!(sta_qos_info.max_sp_length, 0, "max_sp_length");
+}
+
+#[test]
+fn test_tagged_parameters_builder_interworking() {
+    let mut tagged_parameters_builder = TaggedParametersBuilder::new();
+    tagged_parameters_builder
+        .add(
+            TagName::Interworking,
+            &[0x12, 0x02, 0x08, 0x00, 0x11, 0x22, 0xaa, 0xbb, 0xcc],
+        )
+        .unwrap();
+    tagged_parameters_builder
+        .add(TagName::AdvertisementProtocol, &[0x7f, 0x00])
+        .unwrap();
+    tagged_parameters_builder
+        .add(
+            TagName::RoamingConsortium,
+            &[0x00, 0x43, 0x00, 0x1b, 0xc5, 0x04, 0x50, 0x6f, 0x9a],
+        )
+        .unwrap();
+    tagged_parameters_builder
+        .vendor_specific(WIFI_ALLIANCE_OUI, &[HOTSPOT20_OUI_TYPE, 0x12, 0x34, 0x12])
+        .unwrap();
+
+    let tagged_parameters = tagged_parameters_builder.build();
+
+    assert_eq!(
+        tagged_parameters.interworking(),
+        Some(Interworking {
+            access_network_type: AccessNetworkType::ChargeablePublic,
+            internet: true,
+            asra: false,
+            esr: false,
+            uesa: false,
+            venue_info: Some(VenueInfo { group: 2, type_: 8 }),
+            hessid: Some(MacAddress::new([0x00, 0x11, 0x22, 0xaa, 0xbb, 0xcc])),
+        }),
+        "interworking"
+    );
+    assert!(
+        tagged_parameters
+            .advertisement_protocol()
+            .unwrap()
+            .has_anqp(),
+        "advertisement_protocol"
+    );
+    assert_eq!(
+        tagged_parameters.roaming_consortium(),
+        Some(RoamingConsortium {
+            number_of_anqp_ois: 0,
+            ois: vec![vec![0x00, 0x1b, 0xc5], vec![0x04, 0x50, 0x6f, 0x9a]],
+        }),
+        "roaming_consortium"
+    );
+    assert_eq!(
+        tagged_parameters.hotspot20_indication(),
+        Some(HotSpot20Indication {
+            dgaf_disabled: false,
+            release_number: 1,
+            pps_mo_id: Some(0x1234),
+            anqp_domain_id: None,
+        }),
+        "hotspot20_indication"
+    );
+}
+
+#[test]
+fn test_tagged_parameters_builder_traffic_indication_map() {
+    let mut traffic_indication_map = TrafficIndicationMap::new(0, 3);
+    traffic_indication_map.multicast = true;
+    traffic_indication_map.set_buffered_traffic(20, true);
+    traffic_indication_map.set_buffered_traffic(35, true);
+    traffic_indication_map.set_buffered_traffic(100, true);
+    traffic_indication_map.set_buffered_traffic(100, false);
+
+    // AID 20 is in octet 2, which is even so the bitmap starts there
+    assert_eq!(
+        traffic_indication_map.to_bytes(),
+        [0x00, 0x03, 0x03, 0x10, 0x00, 0x08],
+        "traffic_indication_map bytes"
+    );
+
+    let mut tagged_parameters_builder = TaggedParametersBuilder::new();
+    tagged_parameters_builder
+        .traffic_indication_map(&traffic_indication_map)
+        .unwrap();
+
+    let tagged_parameters = tagged_parameters_builder.build();
+    let traffic_indication_map = tagged_parameters.traffic_indication_map().unwrap();
+
+    assert!(traffic_indication_map.is_dtim(), "is_dtim");
+    assert!(traffic_indication_map.multicast, "multicast");
+    assert!(traffic_indication_map.has_buffered_traffic(20), "aid 20");
+    assert!(traffic_indication_map.has_buffered_traffic(35), "aid 35");
+    assert!(!traffic_indication_map.has_buffered_traffic(1), "aid 1");
+    assert!(!traffic_indication_map.has_buffered_traffic(100), "aid 100");
+    assert_eq!(
+        traffic_indication_map.buffered_aids(),
+        [20, 35],
+        "buffered_aids"
+    );
+
+    let mut traffic_indication_map = traffic_indication_map;
+    traffic_indication_map.set_buffered_traffic(20, false);
+    traffic_indication_map.set_buffered_traffic(35, false);
+    assert_eq!(
+        traffic_indication_map,
+        TrafficIndicationMap {
+            multicast: true,
+            ..TrafficIndicationMap::new(0, 3)
+        },
+        "empty traffic_indication_map"
+    );
+}
+
+#[test]
+fn test_tagged_parameters_builder_country() {
+    let country = Country {
+        country_string: *b"US",
+        environment: CountryEnvironment::Any,
+        triplets: vec![
+            CountryTriplet::Subband {
+                first_channel: 1,
+                number_of_channels: 11,
+                max_transmit_power: 30,
+            },
+            CountryTriplet::Operating {
+                operating_extension_identifier: 201,
+                operating_class: 118,
+                coverage_class: 0,
+            },
+            CountryTriplet::Subband {
+                first_channel: 52,
+                number_of_channels: 4,
+                max_transmit_power: 23,
+            },
+        ],
+    };
+
+    // 3 + 9 bytes, no padding
+    assert_eq!(country.to_bytes().len(), 12, "country bytes");
+
+    let mut tagged_parameters_builder = TaggedParametersBuilder::new();
+    tagged_parameters_builder.country(&country).unwrap();
+
+    let tagged_parameters = tagged_parameters_builder.build();
+    let decoded = tagged_parameters.country().unwrap();
+
+    assert_eq!(decoded, country, "country");
+    assert_eq!(decoded.subbands().count(), 1, "subbands");
+    assert_eq!(
+        decoded.operating_class_subbands(118),
+        [CountryTriplet::Subband {
+            first_channel: 52,
+            number_of_channels: 4,
+            max_transmit_power: 23,
+        }],
+        "operating_class_subbands"
+    );
+
+    // padded, indoor only
+    let country = Country::from_bytes(&[b'D', b'E', b'I', 36, 4, 0xec, 0]).unwrap();
+    assert_eq!(
+        country.environment,
+        CountryEnvironment::Indoor,
+        "environment"
+    );
+    assert_eq!(
+        country.triplets,
+        [CountryTriplet::Subband {
+            first_channel: 36,
+            number_of_channels: 4,
+            max_transmit_power: -20,
+        }],
+        "triplets"
+    );
+}
+
+#[test]
+fn test_tagged_parameters_builder_extended_capabilities() {
+    let mut extended_capabilities = ExtendedCapabilities::new();
+    extended_capabilities.set(ExtendedCapability::BSSTransition, true);
+    extended_capabilities.set(ExtendedCapability::Interworking, true);
+    extended_capabilities.set(ExtendedCapability::FTMResponder, true);
+    extended_capabilities.set(ExtendedCapability::SAEPasswordIdentifiersInUse, true);
+    extended_capabilities.set(ExtendedCapability::Interworking, false);
+
+    assert_eq!(extended_capabilities.bytes.len(), 11, "length");
+
+    let mut tagged_parameters_builder = TaggedParametersBuilder::new();
+    tagged_parameters_builder
+        .extended_capabilities(&extended_capabilities)
+        .unwrap();
+
+    let tagged_parameters = tagged_parameters_builder.build();
+    let extended_capabilities = tagged_parameters.extended_capabilities().unwrap();
+
+    assert!(
+        extended_capabilities.has(ExtendedCapability::BSSTransition),
+        "bss_transition"
+    );
+    assert!(
+        !extended_capabilities.has(ExtendedCapability::Interworking),
+        "interworking"
+    );
+    assert!(
+        !extended_capabilities.has(ExtendedCapability::LocalMACAddressPolicy),
+        "past the end"
+    );
+    assert_eq!(
+        extended_capabilities.capabilities(),
+        [
+            ExtendedCapability::BSSTransition,
+            ExtendedCapability::FTMResponder,
+            ExtendedCapability::SAEPasswordIdentifiersInUse,
+        ],
+        "capabilities"
+    );
+    assert_eq!(
+        extended_capabilities.max_msdus_in_amsdu(),
+        None,
+        "max_msdus_in_amsdu"
+    );
+}
+
+#[test]
+fn test_tagged_parameters_builder_bss_load() {
+    let bss_load = BSSLoad {
+        station_count: 4,
+        channel_utilization: 51,
+        available_admission_capacity: 31250,
+    };
+    let power_capability = PowerCapability {
+        min_transmit_power: -5,
+        max_transmit_power: 20,
+    };
+
+    let mut tagged_parameters_builder = TaggedParametersBuilder::new();
+    tagged_parameters_builder
+        .add(TagName::QBSSLoadElement, &bss_load.to_bytes())
+        .unwrap();
+    tagged_parameters_builder
+        .add(TagName::PowerCapabilities, &power_capability.to_bytes())
+        .unwrap();
+
+    let tagged_parameters = tagged_parameters_builder.build();
+
+    assert_eq!(tagged_parameters.bss_load(), Some(bss_load), "bss_load");
+    assert_eq!(
+        (bss_load.channel_utilization_percent() * 10.0).round(),
+        200.0,
+        "channel_utilization_percent"
+    );
+    assert_eq!(
+        tagged_parameters.power_capability(),
+        Some(power_capability),
+        "power_capability"
+    );
+}
+
+#[test]
+fn test_tagged_parameters_builder_rates() {
+    let rates = [
+        Rate::new(6000, true),
+        Rate::new(9000, false),
+        Rate::new(12000, true),
+        Rate::new(18000, false),
+        Rate::new(24000, true),
+        Rate::new(36000, false),
+        Rate::new(48000, false),
+        Rate::new(54000, false),
+        Rate::HTPhy,
+        Rate::SAEHashToElementOnly,
+    ];
+
+    let mut tagged_parameters_builder = TaggedParametersBuilder::new();
+    tagged_parameters_builder.rates(&rates).unwrap();
+
+    assert_eq!(
+        tagged_parameters_builder.bytes(),
+        [1, 8, 0x8c, 0x12, 0x98, 0x24, 0xb0, 0x48, 0x60, 0x6c, 50, 2, 0xff, 0xfb],
+        "rates bytes"
+    );
+
+    let tagged_parameters = tagged_parameters_builder.build();
+
+    assert_eq!(
+        tagged_parameters.rates().collect::<Vec<_>>(),
+        rates,
+        "rates"
+    );
+    assert_eq!(
+        tagged_parameters.basic_rates(),
+        [
+            Rate::new(6000, true),
+            Rate::new(12000, true),
+            Rate::new(24000, true),
+        ],
+        "basic_rates"
+    );
+    assert_eq!(
+        tagged_parameters.bss_membership_selectors(),
+        [Rate::HTPhy, Rate::SAEHashToElementOnly],
+        "bss_membership_selectors"
+    );
+
+    // selectors are not rates
+    assert_eq!(
+        tagged_parameters.supported_rates(),
+        Some(vec![6.0, 9.0, 12.0, 18.0, 24.0, 36.0, 48.0, 54.0]),
+        "supported_rates"
+    );
+    assert_eq!(
+        Rate::from_u8(0x7f),
+        Rate::new(63500, false),
+        "non-basic 127"
+    );
+
+    // rates that can not be encoded are refused, and no rates add nothing
+    assert_eq!(Rate::new(63500, false).into_u8(), Some(0x7f), "63.5 Mbps");
+    assert_eq!(Rate::new(63500, true).into_u8(), None, "basic 63.5 Mbps");
+    let mut tagged_parameters_builder = TaggedParametersBuilder::new();
+    assert_eq!(
+        tagged_parameters_builder.rates(&[Rate::new(6000, true), Rate::new(5500, false)]),
+        Ok(()),
+        "5.5 Mbps"
+    );
+    for rate in [Rate::new(6100, false), Rate::new(64000, false)] {
+        assert_eq!(
+            tagged_parameters_builder.rates(&[Rate::new(6000, true), rate]),
+            Err(TaggedParameterError::InvalidRate(rate)),
+            "invalid rate"
+        );
+    }
+    assert_eq!(tagged_parameters_builder.rates(&[]), Ok(()), "no rates");
+    assert_eq!(
+        tagged_parameters_builder.bytes(),
+        &[1, 2, 0x8c, 0x0b],
+        "only the valid rates"
+    );
+}
+
+#[test]
+fn test_tagged_parameters_builder_multiple_bssid() {
+    let rsn = [
+        1, 0, 0x00, 0x0f, 0xac, 4, 1, 0, 0x00, 0x0f, 0xac, 4, 1, 0, 0x00, 0x0f, 0xac, 2, 0, 0,
+    ];
+
+    // open guest network that does not inherit RSN
+    let mut guest = vec![83, 2, 0x01, 0x00, 0, 5];
+    guest.extend_from_slice(b"guest");
+    guest.extend_from_slice(&[85, 3, 1, 3, 0, 255, 3, 56, 1, 48, 0]);
+    // protected network split across two subelements
+    let iot = vec![83, 2, 0x11, 0x00, 0, 3, b'i', b'o', b't'];
+    let iot_continued = vec![85, 1, 3];
+
+    let mut multiple_bssid = vec![3, 0, guest.len() as u8];
+    multiple_bssid.extend_from_slice(&guest);
+    multiple_bssid.extend_from_slice(&[0, iot.len() as u8]);
+    multiple_bssid.extend_from_slice(&iot);
+    multiple_bssid.extend_from_slice(&[0, iot_continued.len() as u8]);
+    multiple_bssid.extend_from_slice(&iot_continued);
+
+    let mut tagged_parameters_builder = TaggedParametersBuilder::new();
+    tagged_parameters_builder.ssid(b"main").unwrap();
+    tagged_parameters_builder
+        .add(TagName::DSParameter, &[6])
+        .unwrap();
+    tagged_parameters_builder
+        .add(TagName::RSNInformation, &rsn)
+        .unwrap();
+    tagged_parameters_builder
+        .add(TagName::MultipleBSSID, &multiple_bssid)
+        .unwrap();
+
+    let tagged_parameters = tagged_parameters_builder.build();
+
+    let decoded = tagged_parameters.multiple_bssid();
+    assert_eq!(decoded.len(), 1, "multiple_bssid");
+    assert_eq!(decoded[0].max_bssid_indicator, 3, "max_bssid_indicator");
+    assert_eq!(
+        decoded[0].nontransmitted_bssid_profiles,
+        [guest, [iot, iot_continued].concat()],
+        "nontransmitted_bssid_profiles"
+    );
+    assert_eq!(
+        MultipleBSSID::from_bytes(&decoded[0].to_bytes()).as_ref(),
+        Some(&decoded[0]),
+        "to_bytes"
+    );
+
+    let bsss = tagged_parameters.nontransmitted_bsss("00:11:22:33:44:57".parse().unwrap());
+    assert_eq!(bsss.len(), 2, "nontransmitted_bsss");
+
+    assert_eq!(
+        bsss[0].bssid,
+        "00:11:22:33:44:50".parse().unwrap(),
+        "guest bssid"
+    );
+    assert_eq!(bsss[0].bssid_index, 1, "guest bssid_index");
+    assert_eq!(bsss[0].dtim_period, Some(3), "guest dtim_period");
+    assert_eq!(bsss[0].ssid(), Some(&b"guest"[..]), "guest ssid");
+    assert!(!bsss[0].capabilities_info.privacy, "guest privacy");
+    assert_eq!(bsss[0].rsn(), None, "guest rsn");
+    assert_eq!(
+        bsss[0].tagged_parameters.channel(),
+        Some(6),
+        "guest channel"
+    );
+
+    assert_eq!(
+        bsss[1].bssid,
+        "00:11:22:33:44:52".parse().unwrap(),
+        "iot bssid"
+    );
+    assert_eq!(bsss[1].dtim_period, None, "iot dtim_period");
+    assert_eq!(bsss[1].ssid(), Some(&b"iot"[..]), "iot ssid");
+    assert!(bsss[1].capabilities_info.privacy, "iot privacy");
+    assert_eq!(bsss[1].rsn(), tagged_parameters.rsn(), "iot rsn");
+
+    // a profile longer than a subelement, the byte after the first 255 is
+    // the Nontransmitted BSSID Capability element ID
+    let mut long = vec![83, 2, 0x11, 0x00, 85, 1, 4, 221, 250];
+    long.extend((0..250).map(|i| if i == 246 { 83 } else { i as u8 }));
+    long.extend_from_slice(&[221, 3, 0x00, 0x50, 0xf2]);
+    let multiple_bssid = MultipleBSSID {
+        max_bssid_indicator: 3,
+        nontransmitted_bssid_profiles: vec![long],
+    };
+    let bytes = multiple_bssid.to_bytes();
+    assert_eq!(bytes[2], 254, "first subelement length");
+    assert_eq!(bytes[254 + 4], 10, "second subelement length");
+    assert_eq!(
+        MultipleBSSID::from_bytes(&bytes),
+        Some(multiple_bssid),
+        "long profile"
+    );
+
+    // an empty profile would be read as a continuation of the one before
+    let multiple_bssid = MultipleBSSID {
+        max_bssid_indicator: 3,
+        nontransmitted_bssid_profiles: vec![vec![83, 2, 0x11, 0x00], Vec::new()],
+    };
+    assert_eq!(
+        multiple_bssid.to_bytes(),
+        [3, 0, 4, 83, 2, 0x11, 0x00],
+        "empty profile"
+    );
+}
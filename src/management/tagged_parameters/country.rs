@@ -0,0 +1,206 @@
+use super::*;
+
+impl TaggedParameters<'_> {
+    #[must_use]
+    pub fn country(&self) -> Option<Country> {
+        self.get_bytes(TagName::CountryInformation)
+            .and_then(Country::from_bytes)
+    }
+}
+
+/// Country element (7)
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Country {
+    /// ISO 3166-1 alpha-2 code, e.g. `b"US"`
+    pub country_string: [u8; 2],
+    pub environment: CountryEnvironment,
+    /// in the order they appear, subband triplets following an operating
+    /// triplet belong to that operating class
+    pub triplets: Vec<CountryTriplet>,
+}
+
+impl Country {
+    #[must_use]
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() < 3 {
+            return None;
+        }
+
+        let mut country_string = [0; 2];
+        country_string.copy_from_slice(&bytes[0..2]);
+
+        // a trailing pad byte keeps the element length even
+        let triplets = bytes[3..]
+            .chunks_exact(3)
+            .map(CountryTriplet::from_bytes)
+            .collect();
+
+        Some(Self {
+            country_string,
+            environment: CountryEnvironment::from_u8(bytes[2]),
+            triplets,
+        })
+    }
+
+    /// Element body, padded to an even length
+    #[must_use]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = self.country_string.to_vec();
+        bytes.push(self.environment.into_u8());
+        for triplet in &self.triplets {
+            bytes.extend_from_slice(&triplet.to_bytes());
+        }
+        if bytes.len() % 2 != 0 {
+            bytes.push(0);
+        }
+        bytes
+    }
+
+    /// Subband triplets that are not part of an operating class
+    pub fn subbands(&self) -> impl Iterator<Item = &CountryTriplet> + '_ {
+        self.triplets
+            .iter()
+            .take_while(|triplet| matches!(triplet, CountryTriplet::Subband { .. }))
+    }
+
+    /// Channel triplets following the operating triplet for `operating_class`
+    #[must_use]
+    pub fn operating_class_subbands(&self, operating_class: u8) -> Vec<CountryTriplet> {
+        self.triplets
+            .iter()
+            .skip_while(|triplet| match triplet {
+                CountryTriplet::Operating {
+                    operating_class: n, ..
+                } => *n != operating_class,
+                CountryTriplet::Subband { .. } => true,
+            })
+            .skip(1)
+            .take_while(|triplet| matches!(triplet, CountryTriplet::Subband { .. }))
+            .copied()
+            .collect()
+    }
+
+    /// Operating class table of the operating triplets, from the table number
+    /// in the third octet or else the country
+    #[must_use]
+    pub fn region(&self) -> Option<Region> {
+        match self.environment {
+            CountryEnvironment::OperatingClassTable(n) => Region::from_table_number(n),
+            _ => Region::from_country_code(self.country_string),
+        }
+    }
+
+    /// Known operating classes of the operating triplets
+    #[must_use]
+    pub fn operating_classes(&self) -> Vec<&'static OperatingClass> {
+        let Some(region) = self.region() else {
+            return Vec::new();
+        };
+
+        self.triplets
+            .iter()
+            .filter_map(|triplet| match triplet {
+                CountryTriplet::Operating {
+                    operating_class, ..
+                } => region.operating_class(*operating_class),
+                CountryTriplet::Subband { .. } => None,
+            })
+            .collect()
+    }
+}
+
+/// Third octet of the country string
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum CountryEnvironment {
+    /// ' ', indoor and outdoor
+    Any, // 0x20
+    /// 'O'
+    Outdoor, // 0x4f
+    /// 'I'
+    Indoor, // 0x49
+    /// 'X', noncountry entity
+    NonCountry, // 0x58
+    /// operating class table number, e.g. 4 for the global table
+    OperatingClassTable(u8), // 0x01-0x1f
+    Reserved(u8),
+}
+
+impl CountryEnvironment {
+    #[must_use]
+    pub fn from_u8(n: u8) -> Self {
+        match n {
+            0x20 => Self::Any,
+            0x4f => Self::Outdoor,
+            0x49 => Self::Indoor,
+            0x58 => Self::NonCountry,
+            0x01..=0x1f => Self::OperatingClassTable(n),
+            other => Self::Reserved(other),
+        }
+    }
+
+    #[must_use]
+    pub fn into_u8(self) -> u8 {
+        match self {
+            Self::Any => 0x20,
+            Self::Outdoor => 0x4f,
+            Self::Indoor => 0x49,
+            Self::NonCountry => 0x58,
+            Self::OperatingClassTable(n) => n,
+            Self::Reserved(other) => other,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum CountryTriplet {
+    Subband {
+        first_channel: u8,
+        number_of_channels: u8,
+        /// in dBm
+        max_transmit_power: i8,
+    },
+    /// first octet of 201 or more
+    Operating {
+        operating_extension_identifier: u8,
+        operating_class: u8,
+        /// air propagation time, 0-31 in units of 3 microseconds
+        coverage_class: u8,
+    },
+}
+
+impl CountryTriplet {
+    fn from_bytes(bytes: &[u8]) -> Self {
+        if bytes[0] >= 201 {
+            Self::Operating {
+                operating_extension_identifier: bytes[0],
+                operating_class: bytes[1],
+                coverage_class: bytes[2],
+            }
+        } else {
+            Self::Subband {
+                first_channel: bytes[0],
+                number_of_channels: bytes[1],
+                max_transmit_power: bytes[2] as i8,
+            }
+        }
+    }
+
+    fn to_bytes(self) -> [u8; 3] {
+        match self {
+            Self::Subband {
+                first_channel,
+                number_of_channels,
+                max_transmit_power,
+            } => [first_channel, number_of_channels, max_transmit_power as u8],
+            Self::Operating {
+                operating_extension_identifier,
+                operating_class,
+                coverage_class,
+            } => [
+                operating_extension_identifier,
+                operating_class,
+                coverage_class,
+            ],
+        }
+    }
+}
@@ -0,0 +1,129 @@
+use super::*;
+use byteorder::{ByteOrder, LittleEndian};
+
+impl TaggedParameters<'_> {
+    #[must_use]
+    pub fn erp_information(&self) -> Option<ERPInformation> {
+        self.get_bytes(TagName::ERPInformation)
+            .and_then(|bytes| bytes.first())
+            .map(|&b| ERPInformation::from_u8(b))
+    }
+
+    #[must_use]
+    pub fn bss_load(&self) -> Option<BSSLoad> {
+        self.get_bytes(TagName::QBSSLoadElement)
+            .and_then(BSSLoad::from_bytes)
+    }
+
+    #[must_use]
+    pub fn power_capability(&self) -> Option<PowerCapability> {
+        self.get_bytes(TagName::PowerCapabilities)
+            .and_then(PowerCapability::from_bytes)
+    }
+}
+
+/// ERP Information element (42)
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ERPInformation {
+    /// a non-ERP (802.11b) station is associated, or was heard nearby
+    pub non_erp_present: bool,
+    /// ERP stations have to use protection (RTS/CTS or CTS-to-self)
+    pub use_protection: bool,
+    /// long preambles have to be used
+    pub barker_preamble_mode: bool,
+}
+
+impl ERPInformation {
+    #[must_use]
+    pub fn from_u8(b: u8) -> Self {
+        Self {
+            non_erp_present: (b & 0b0000_0001) != 0,
+            use_protection: (b & 0b0000_0010) != 0,
+            barker_preamble_mode: (b & 0b0000_0100) != 0,
+        }
+    }
+
+    #[must_use]
+    pub fn into_u8(self) -> u8 {
+        let mut b = 0;
+        if self.non_erp_present {
+            b |= 0b0000_0001;
+        }
+        if self.use_protection {
+            b |= 0b0000_0010;
+        }
+        if self.barker_preamble_mode {
+            b |= 0b0000_0100;
+        }
+        b
+    }
+}
+
+/// BSS Load element (11)
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct BSSLoad {
+    /// associated stations
+    pub station_count: u16,
+    /// time the medium was busy, scaled to 255
+    pub channel_utilization: u8,
+    /// in units of 32 microseconds per second
+    pub available_admission_capacity: u16,
+}
+
+impl BSSLoad {
+    #[must_use]
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() < 5 {
+            return None;
+        }
+
+        Some(Self {
+            station_count: LittleEndian::read_u16(&bytes[0..2]),
+            channel_utilization: bytes[2],
+            available_admission_capacity: LittleEndian::read_u16(&bytes[3..5]),
+        })
+    }
+
+    #[must_use]
+    pub fn to_bytes(&self) -> [u8; 5] {
+        let mut bytes = [0; 5];
+        LittleEndian::write_u16(&mut bytes[0..2], self.station_count);
+        bytes[2] = self.channel_utilization;
+        LittleEndian::write_u16(&mut bytes[3..5], self.available_admission_capacity);
+        bytes
+    }
+
+    /// 0.0 to 100.0
+    #[must_use]
+    pub fn channel_utilization_percent(&self) -> f64 {
+        f64::from(self.channel_utilization) * 100.0 / 255.0
+    }
+}
+
+/// Power Capability element (33)
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct PowerCapability {
+    /// in dBm
+    pub min_transmit_power: i8,
+    /// in dBm
+    pub max_transmit_power: i8,
+}
+
+impl PowerCapability {
+    #[must_use]
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() < 2 {
+            return None;
+        }
+
+        Some(Self {
+            min_transmit_power: bytes[0] as i8,
+            max_transmit_power: bytes[1] as i8,
+        })
+    }
+
+    #[must_use]
+    pub fn to_bytes(&self) -> [u8; 2] {
+        [self.min_transmit_power as u8, self.max_transmit_power as u8]
+    }
+}
@@ -0,0 +1,419 @@
+use super::*;
+
+impl TaggedParameters<'_> {
+    #[must_use]
+    pub fn extended_capabilities(&self) -> Option<ExtendedCapabilities> {
+        self.get_bytes(TagName::ExtendedCapabilities)
+            .map(ExtendedCapabilities::from_bytes)
+    }
+}
+
+/// Extended Capabilities element (127)
+///
+/// A bitfield of any length, bits past the end of the element are not set.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct ExtendedCapabilities {
+    pub bytes: Vec<u8>,
+}
+
+impl ExtendedCapabilities {
+    #[must_use]
+    pub fn new() -> Self {
+        Self { bytes: Vec::new() }
+    }
+
+    #[must_use]
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        Self {
+            bytes: bytes.to_vec(),
+        }
+    }
+
+    #[must_use]
+    pub fn bit(&self, bit: u16) -> bool {
+        self.bytes
+            .get((bit / 8) as usize)
+            .is_some_and(|b| (b & (1 << (bit % 8))) != 0)
+    }
+
+    /// Grows the bitfield when setting a bit past its end
+    pub fn set_bit(&mut self, bit: u16, value: bool) {
+        let octet = (bit / 8) as usize;
+        if octet >= self.bytes.len() {
+            if !value {
+                return;
+            }
+            self.bytes.resize(octet + 1, 0);
+        }
+
+        if value {
+            self.bytes[octet] |= 1 << (bit % 8);
+        } else {
+            self.bytes[octet] &= !(1 << (bit % 8));
+        }
+    }
+
+    #[must_use]
+    pub fn has(&self, capability: ExtendedCapability) -> bool {
+        self.bit(capability.into_u16())
+    }
+
+    pub fn set(&mut self, capability: ExtendedCapability, value: bool) {
+        self.set_bit(capability.into_u16(), value);
+    }
+
+    /// Every set bit, in order
+    #[must_use]
+    pub fn capabilities(&self) -> Vec<ExtendedCapability> {
+        (0..(self.bytes.len() as u16 * 8))
+            .filter(|&bit| self.bit(bit))
+            .map(ExtendedCapability::from_u16)
+            .collect()
+    }
+
+    /// Service Interval Granularity, shortest service interval in
+    /// milliseconds
+    #[must_use]
+    pub fn service_interval_granularity(&self) -> u8 {
+        let n =
+            u8::from(self.bit(41)) | (u8::from(self.bit(42)) << 1) | (u8::from(self.bit(43)) << 2);
+        (n + 1) * 5
+    }
+
+    /// Max Number Of MSDUs In A-MSDU, None when there is no limit
+    #[must_use]
+    pub fn max_msdus_in_amsdu(&self) -> Option<u8> {
+        let n = u8::from(self.bit(63)) | (u8::from(self.bit(64)) << 1);
+        match n {
+            0 => None,
+            n => Some(1 << (6 - n)),
+        }
+    }
+}
+
+/// Named single-bit fields of the Extended Capabilities element
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ExtendedCapability {
+    /// 20/40 BSS Coexistence Management Support
+    BSSCoexistenceManagement, // 0
+    /// Extended Channel Switching
+    ExtendedChannelSwitching, // 2
+    /// PSMP Capability
+    PSMP, // 4
+    /// S-PSMP Support
+    SPSMP, // 6
+    /// Event
+    Event, // 7
+    /// Diagnostics
+    Diagnostics, // 8
+    /// Multicast Diagnostics
+    MulticastDiagnostics, // 9
+    /// Location Tracking
+    LocationTracking, // 10
+    /// Flexible Multicast Service
+    FMS, // 11
+    /// Proxy ARP Service
+    ProxyARP, // 12
+    /// Collocated Interference Reporting
+    CollocatedInterferenceReporting, // 13
+    /// Civic Location
+    CivicLocation, // 14
+    /// Geospatial Location
+    GeospatialLocation, // 15
+    /// Traffic Filtering Service
+    TFS, // 16
+    /// WNM Sleep Mode
+    WNMSleepMode, // 17
+    /// TIM Broadcast
+    TIMBroadcast, // 18
+    /// BSS Transition
+    BSSTransition, // 19
+    /// QoS Traffic Capability
+    QoSTrafficCapability, // 20
+    /// AC Station Count
+    ACStationCount, // 21
+    /// Multiple BSSID
+    MultipleBSSID, // 22
+    /// Timing Measurement
+    TimingMeasurement, // 23
+    /// Channel Usage
+    ChannelUsage, // 24
+    /// SSID List
+    SSIDList, // 25
+    /// Directed Multicast Service
+    DMS, // 26
+    /// UTC TSF Offset
+    UTCTSFOffset, // 27
+    /// TPU Buffer STA Support
+    TPUBufferSTA, // 28
+    /// TDLS Peer PSM Support
+    TDLSPeerPSM, // 29
+    /// TDLS Channel Switching
+    TDLSChannelSwitching, // 30
+    /// Interworking
+    Interworking, // 31
+    /// QoS Map
+    QoSMap, // 32
+    /// Expedited Bandwidth Request
+    EBR, // 33
+    /// SSPN Interface
+    SSPNInterface, // 34
+    /// MSGCF Capability
+    MSGCF, // 36
+    /// TDLS Support
+    TDLSSupport, // 37
+    /// TDLS Prohibited
+    TDLSProhibited, // 38
+    /// TDLS Channel Switching Prohibited
+    TDLSChannelSwitchingProhibited, // 39
+    /// Reject Unadmitted Frame
+    RejectUnadmittedFrame, // 40
+    /// Identifier Location
+    IdentifierLocation, // 44
+    /// U-APSD Coexistence
+    UAPSDCoexistence, // 45
+    /// WNM Notification
+    WNMNotification, // 46
+    /// QAB Capability
+    QABCapability, // 47
+    /// UTF-8 SSID
+    UTF8SSID, // 48
+    /// QMF Activated
+    QMFActivated, // 49
+    /// QMF Reconfiguration Activated
+    QMFReconfigurationActivated, // 50
+    /// Robust AV Streaming
+    RobustAVStreaming, // 51
+    /// Advanced GCR
+    AdvancedGCR, // 52
+    /// Mesh GCR
+    MeshGCR, // 53
+    /// Stream Classification Service
+    SCS, // 54
+    /// QLoad Report
+    QLoadReport, // 55
+    /// Alternate EDCA
+    AlternateEDCA, // 56
+    /// Unprotected TXOP Negotiation
+    UnprotectedTXOPNegotiation, // 57
+    /// Protected TXOP Negotiation
+    ProtectedTXOPNegotiation, // 58
+    /// Protected QLoad Report
+    ProtectedQLoadReport, // 60
+    /// TDLS Wider Bandwidth
+    TDLSWiderBandwidth, // 61
+    /// Operating Mode Notification
+    OperatingModeNotification, // 62
+    /// Channel Schedule Management
+    ChannelScheduleManagement, // 65
+    /// Geodatabase Inband Enabling Signal
+    GeodatabaseInbandEnablingSignal, // 66
+    /// Network Channel Control
+    NetworkChannelControl, // 67
+    /// White Space Map
+    WhiteSpaceMap, // 68
+    /// Channel Availability Query
+    ChannelAvailabilityQuery, // 69
+    /// Fine Timing Measurement Responder
+    FTMResponder, // 70
+    /// Fine Timing Measurement Initiator
+    FTMInitiator, // 71
+    /// FILS Capability
+    FILS, // 72
+    /// Extended Spectrum Management Capable
+    ExtendedSpectrumManagement, // 73
+    /// Future Channel Guidance
+    FutureChannelGuidance, // 74
+    /// PAD
+    PAD, // 75
+    /// TWT Requester Support
+    TWTRequester, // 77
+    /// TWT Responder Support
+    TWTResponder, // 78
+    /// OBSS Narrow Bandwidth RU in UL OFDMA Tolerance Support
+    OBSSNarrowBandwidthRU, // 79
+    /// Complete List of NonTxBSSID Profiles
+    CompleteListOfNonTxBSSIDProfiles, // 80
+    /// SAE Password Identifiers In Use
+    SAEPasswordIdentifiersInUse, // 81
+    /// SAE Password Identifiers Used Exclusively
+    SAEPasswordIdentifiersUsedExclusively, // 82
+    /// Enhanced Multi-BSSID Advertisement Support
+    EnhancedMultiBSSIDAdvertisement, // 83
+    /// Beacon Protection Enabled
+    BeaconProtection, // 84
+    /// Mirrored SCS
+    MirroredSCS, // 85
+    /// Local MAC Address Policy
+    LocalMACAddressPolicy, // 87
+    /// reserved bits, and bits of multi-bit fields
+    Other(u16),
+}
+
+impl ExtendedCapability {
+    #[must_use]
+    pub fn from_u16(n: u16) -> Self {
+        match n {
+            0 => Self::BSSCoexistenceManagement,
+            2 => Self::ExtendedChannelSwitching,
+            4 => Self::PSMP,
+            6 => Self::SPSMP,
+            7 => Self::Event,
+            8 => Self::Diagnostics,
+            9 => Self::MulticastDiagnostics,
+            10 => Self::LocationTracking,
+            11 => Self::FMS,
+            12 => Self::ProxyARP,
+            13 => Self::CollocatedInterferenceReporting,
+            14 => Self::CivicLocation,
+            15 => Self::GeospatialLocation,
+            16 => Self::TFS,
+            17 => Self::WNMSleepMode,
+            18 => Self::TIMBroadcast,
+            19 => Self::BSSTransition,
+            20 => Self::QoSTrafficCapability,
+            21 => Self::ACStationCount,
+            22 => Self::MultipleBSSID,
+            23 => Self::TimingMeasurement,
+            24 => Self::ChannelUsage,
+            25 => Self::SSIDList,
+            26 => Self::DMS,
+            27 => Self::UTCTSFOffset,
+            28 => Self::TPUBufferSTA,
+            29 => Self::TDLSPeerPSM,
+            30 => Self::TDLSChannelSwitching,
+            31 => Self::Interworking,
+            32 => Self::QoSMap,
+            33 => Self::EBR,
+            34 => Self::SSPNInterface,
+            36 => Self::MSGCF,
+            37 => Self::TDLSSupport,
+            38 => Self::TDLSProhibited,
+            39 => Self::TDLSChannelSwitchingProhibited,
+            40 => Self::RejectUnadmittedFrame,
+            44 => Self::IdentifierLocation,
+            45 => Self::UAPSDCoexistence,
+            46 => Self::WNMNotification,
+            47 => Self::QABCapability,
+            48 => Self::UTF8SSID,
+            49 => Self::QMFActivated,
+            50 => Self::QMFReconfigurationActivated,
+            51 => Self::RobustAVStreaming,
+            52 => Self::AdvancedGCR,
+            53 => Self::MeshGCR,
+            54 => Self::SCS,
+            55 => Self::QLoadReport,
+            56 => Self::AlternateEDCA,
+            57 => Self::UnprotectedTXOPNegotiation,
+            58 => Self::ProtectedTXOPNegotiation,
+            60 => Self::ProtectedQLoadReport,
+            61 => Self::TDLSWiderBandwidth,
+            62 => Self::OperatingModeNotification,
+            65 => Self::ChannelScheduleManagement,
+            66 => Self::GeodatabaseInbandEnablingSignal,
+            67 => Self::NetworkChannelControl,
+            68 => Self::WhiteSpaceMap,
+            69 => Self::ChannelAvailabilityQuery,
+            70 => Self::FTMResponder,
+            71 => Self::FTMInitiator,
+            72 => Self::FILS,
+            73 => Self::ExtendedSpectrumManagement,
+            74 => Self::FutureChannelGuidance,
+            75 => Self::PAD,
+            77 => Self::TWTRequester,
+            78 => Self::TWTResponder,
+            79 => Self::OBSSNarrowBandwidthRU,
+            80 => Self::CompleteListOfNonTxBSSIDProfiles,
+            81 => Self::SAEPasswordIdentifiersInUse,
+            82 => Self::SAEPasswordIdentifiersUsedExclusively,
+            83 => Self::EnhancedMultiBSSIDAdvertisement,
+            84 => Self::BeaconProtection,
+            85 => Self::MirroredSCS,
+            87 => Self::LocalMACAddressPolicy,
+            other => Self::Other(other),
+        }
+    }
+
+    #[must_use]
+    pub fn into_u16(self) -> u16 {
+        match self {
+            Self::BSSCoexistenceManagement => 0,
+            Self::ExtendedChannelSwitching => 2,
+            Self::PSMP => 4,
+            Self::SPSMP => 6,
+            Self::Event => 7,
+            Self::Diagnostics => 8,
+            Self::MulticastDiagnostics => 9,
+            Self::LocationTracking => 10,
+            Self::FMS => 11,
+            Self::ProxyARP => 12,
+            Self::CollocatedInterferenceReporting => 13,
+            Self::CivicLocation => 14,
+            Self::GeospatialLocation => 15,
+            Self::TFS => 16,
+            Self::WNMSleepMode => 17,
+            Self::TIMBroadcast => 18,
+            Self::BSSTransition => 19,
+            Self::QoSTrafficCapability => 20,
+            Self::ACStationCount => 21,
+            Self::MultipleBSSID => 22,
+            Self::TimingMeasurement => 23,
+            Self::ChannelUsage => 24,
+            Self::SSIDList => 25,
+            Self::DMS => 26,
+            Self::UTCTSFOffset => 27,
+            Self::TPUBufferSTA => 28,
+            Self::TDLSPeerPSM => 29,
+            Self::TDLSChannelSwitching => 30,
+            Self::Interworking => 31,
+            Self::QoSMap => 32,
+            Self::EBR => 33,
+            Self::SSPNInterface => 34,
+            Self::MSGCF => 36,
+            Self::TDLSSupport => 37,
+            Self::TDLSProhibited => 38,
+            Self::TDLSChannelSwitchingProhibited => 39,
+            Self::RejectUnadmittedFrame => 40,
+            Self::IdentifierLocation => 44,
+            Self::UAPSDCoexistence => 45,
+            Self::WNMNotification => 46,
+            Self::QABCapability => 47,
+            Self::UTF8SSID => 48,
+            Self::QMFActivated => 49,
+            Self::QMFReconfigurationActivated => 50,
+            Self::RobustAVStreaming => 51,
+            Self::AdvancedGCR => 52,
+            Self::MeshGCR => 53,
+            Self::SCS => 54,
+            Self::QLoadReport => 55,
+            Self::AlternateEDCA => 56,
+            Self::UnprotectedTXOPNegotiation => 57,
+            Self::ProtectedTXOPNegotiation => 58,
+            Self::ProtectedQLoadReport => 60,
+            Self::TDLSWiderBandwidth => 61,
+            Self::OperatingModeNotification => 62,
+            Self::ChannelScheduleManagement => 65,
+            Self::GeodatabaseInbandEnablingSignal => 66,
+            Self::NetworkChannelControl => 67,
+            Self::WhiteSpaceMap => 68,
+            Self::ChannelAvailabilityQuery => 69,
+            Self::FTMResponder => 70,
+            Self::FTMInitiator => 71,
+            Self::FILS => 72,
+            Self::ExtendedSpectrumManagement => 73,
+            Self::FutureChannelGuidance => 74,
+            Self::PAD => 75,
+            Self::TWTRequester => 77,
+            Self::TWTResponder => 78,
+            Self::OBSSNarrowBandwidthRU => 79,
+            Self::CompleteListOfNonTxBSSIDProfiles => 80,
+            Self::SAEPasswordIdentifiersInUse => 81,
+            Self::SAEPasswordIdentifiersUsedExclusively => 82,
+            Self::EnhancedMultiBSSIDAdvertisement => 83,
+            Self::BeaconProtection => 84,
+            Self::MirroredSCS => 85,
+            Self::LocalMACAddressPolicy => 87,
+            Self::Other(other) => other,
+        }
+    }
+}
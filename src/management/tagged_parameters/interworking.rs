@@ -0,0 +1,338 @@
+use super::*;
+
+/// OUI type of the Hotspot 2.0 Indication vendor specific element
+pub const HOTSPOT20_OUI_TYPE: u8 = 0x10;
+
+impl TaggedParameters<'_> {
+    #[must_use]
+    pub fn interworking(&self) -> Option<Interworking> {
+        self.get_bytes(TagName::Interworking)
+            .and_then(Interworking::from_bytes)
+    }
+
+    #[must_use]
+    pub fn advertisement_protocol(&self) -> Option<AdvertisementProtocol> {
+        self.get_bytes(TagName::AdvertisementProtocol)
+            .and_then(AdvertisementProtocol::from_bytes)
+    }
+
+    #[must_use]
+    pub fn roaming_consortium(&self) -> Option<RoamingConsortium> {
+        self.get_bytes(TagName::RoamingConsortium)
+            .and_then(RoamingConsortium::from_bytes)
+    }
+
+    /// Hotspot 2.0 Indication element, present when the BSS supports Passpoint
+    #[must_use]
+    pub fn hotspot20_indication(&self) -> Option<HotSpot20Indication> {
+        self.vendor_specific_element()
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Interworking {
+    pub access_network_type: AccessNetworkType,
+    /// the network provides connectivity to the Internet
+    pub internet: bool,
+    /// Additional Step Required for Access
+    pub asra: bool,
+    /// Emergency Services Reachable
+    pub esr: bool,
+    /// Unauthenticated Emergency Service Accessible
+    pub uesa: bool,
+    pub venue_info: Option<VenueInfo>,
+    /// Homogeneous ESS identifier
+    pub hessid: Option<MacAddress>,
+}
+
+impl Interworking {
+    #[must_use]
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        // access network options, then optional venue info and HESSID
+        let (venue_info, hessid) = match bytes.len() {
+            1 => (None, None),
+            3 => (Some(VenueInfo::from_bytes(&bytes[1..3])), None),
+            7 => (None, MacAddress::from_bytes(&bytes[1..7]).ok()),
+            9 => (
+                Some(VenueInfo::from_bytes(&bytes[1..3])),
+                MacAddress::from_bytes(&bytes[3..9]).ok(),
+            ),
+            _ => return None,
+        };
+
+        let b = bytes[0];
+        Some(Self {
+            access_network_type: AccessNetworkType::from_u8(b & 0b0000_1111),
+            internet: (b & 0b0001_0000) != 0,
+            asra: (b & 0b0010_0000) != 0,
+            esr: (b & 0b0100_0000) != 0,
+            uesa: (b & 0b1000_0000) != 0,
+            venue_info,
+            hessid,
+        })
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum AccessNetworkType {
+    /// Private network
+    Private, // 0
+    /// Private network with guest access
+    PrivateWithGuestAccess, // 1
+    /// Chargeable public network
+    ChargeablePublic, // 2
+    /// Free public network
+    FreePublic, // 3
+    /// Personal device network
+    PersonalDevice, // 4
+    /// Emergency services only network
+    EmergencyServicesOnly, // 5
+    /// Test or experimental
+    TestOrExperimental, // 14
+    /// Wildcard
+    Wildcard, // 15
+    /// 6-13 Reserved
+    Reserved(u8),
+}
+
+impl AccessNetworkType {
+    #[must_use]
+    pub fn from_u8(n: u8) -> Self {
+        match n {
+            0 => Self::Private,
+            1 => Self::PrivateWithGuestAccess,
+            2 => Self::ChargeablePublic,
+            3 => Self::FreePublic,
+            4 => Self::PersonalDevice,
+            5 => Self::EmergencyServicesOnly,
+            14 => Self::TestOrExperimental,
+            15 => Self::Wildcard,
+            other => Self::Reserved(other),
+        }
+    }
+
+    #[must_use]
+    pub fn into_u8(self) -> u8 {
+        match self {
+            Self::Private => 0,
+            Self::PrivateWithGuestAccess => 1,
+            Self::ChargeablePublic => 2,
+            Self::FreePublic => 3,
+            Self::PersonalDevice => 4,
+            Self::EmergencyServicesOnly => 5,
+            Self::TestOrExperimental => 14,
+            Self::Wildcard => 15,
+            Self::Reserved(other) => other,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct VenueInfo {
+    /// 0: Unspecified, 1: Assembly, 2: Business, 3: Educational,
+    /// 4: Factory and Industrial, 5: Institutional, 6: Mercantile,
+    /// 7: Residential, 8: Storage, 9: Utility and Miscellaneous,
+    /// 10: Vehicular, 11: Outdoor
+    pub group: u8,
+    /// meaning depends on the group
+    pub type_: u8,
+}
+
+impl VenueInfo {
+    #[must_use]
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        Self {
+            group: bytes[0],
+            type_: bytes[1],
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AdvertisementProtocol {
+    pub tuples: Vec<AdvertisementProtocolTuple>,
+}
+
+impl AdvertisementProtocol {
+    #[must_use]
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let mut tuples = Vec::new();
+
+        let mut i = 0;
+        let len = bytes.len();
+        while (i + 2) <= len {
+            let info = bytes[i];
+            let protocol_id = bytes[i + 1];
+            i += 2;
+
+            let protocol = if protocol_id == 221 {
+                // length, then the OUI and vendor data
+                if i >= len || (i + 1 + bytes[i] as usize) > len {
+                    return None;
+                }
+                let vendor_length = bytes[i] as usize;
+                let protocol = AdvertisementProtocolID::VendorSpecific(
+                    bytes[(i + 1)..(i + 1 + vendor_length)].to_vec(),
+                );
+                i += 1 + vendor_length;
+                protocol
+            } else {
+                AdvertisementProtocolID::from_u8(protocol_id)
+            };
+
+            tuples.push(AdvertisementProtocolTuple {
+                query_response_length_limit: info & 0b0111_1111,
+                pame_bi: (info & 0b1000_0000) != 0,
+                protocol,
+            });
+        }
+
+        if tuples.is_empty() {
+            return None;
+        }
+
+        Some(Self { tuples })
+    }
+
+    /// Whether ANQP is one of the advertised protocols
+    #[must_use]
+    pub fn has_anqp(&self) -> bool {
+        self.tuples
+            .iter()
+            .any(|tuple| tuple.protocol == AdvertisementProtocolID::ANQP)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AdvertisementProtocolTuple {
+    /// in units of 256 octets, 127 means no limit
+    pub query_response_length_limit: u8,
+    /// Pre-Association Message Exchange BSSID Independent
+    pub pame_bi: bool,
+    pub protocol: AdvertisementProtocolID,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum AdvertisementProtocolID {
+    /// Access Network Query Protocol
+    ANQP, // 0
+    /// MIH Information Service
+    MIHInformationService, // 1
+    /// MIH Command and Event Services Capability Discovery
+    MIHCommandAndEventServices, // 2
+    /// Emergency Alert System
+    EAS, // 3
+    /// Registered Location Query Protocol
+    RLQP, // 4
+    /// OUI and vendor data
+    VendorSpecific(Vec<u8>), // 221
+    Reserved(u8),
+}
+
+impl AdvertisementProtocolID {
+    #[must_use]
+    pub fn from_u8(n: u8) -> Self {
+        match n {
+            0 => Self::ANQP,
+            1 => Self::MIHInformationService,
+            2 => Self::MIHCommandAndEventServices,
+            3 => Self::EAS,
+            4 => Self::RLQP,
+            221 => Self::VendorSpecific(Vec::new()),
+            other => Self::Reserved(other),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RoamingConsortium {
+    /// number of additional OIs that can be queried with ANQP
+    pub number_of_anqp_ois: u8,
+    /// up to three OIs
+    pub ois: Vec<Vec<u8>>,
+}
+
+impl RoamingConsortium {
+    #[must_use]
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() < 2 {
+            return None;
+        }
+
+        let oi1_length = (bytes[1] & 0b0000_1111) as usize;
+        let oi2_length = ((bytes[1] & 0b1111_0000) >> 4) as usize;
+        if (2 + oi1_length + oi2_length) > bytes.len() {
+            return None;
+        }
+
+        let mut ois = Vec::new();
+        let mut i = 2;
+        if oi1_length > 0 {
+            ois.push(bytes[i..(i + oi1_length)].to_vec());
+            i += oi1_length;
+        }
+        if oi2_length > 0 {
+            ois.push(bytes[i..(i + oi2_length)].to_vec());
+            i += oi2_length;
+        }
+        if i < bytes.len() {
+            ois.push(bytes[i..].to_vec());
+        }
+
+        Some(Self {
+            number_of_anqp_ois: bytes[0],
+            ois,
+        })
+    }
+}
+
+impl<'a> VendorSpecificElement<'a> for HotSpot20Indication {
+    const OUI: [u8; 3] = WIFI_ALLIANCE_OUI;
+    const OUI_TYPE: Option<u8> = Some(HOTSPOT20_OUI_TYPE);
+
+    fn decode(payload: &'a [u8]) -> Option<Self> {
+        Self::from_bytes(payload)
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct HotSpot20Indication {
+    /// Downstream Group-Addressed Forwarding disabled
+    pub dgaf_disabled: bool,
+    /// 0: Release 1, 1: Release 2, 2: Release 3
+    pub release_number: u8,
+    pub pps_mo_id: Option<u16>,
+    pub anqp_domain_id: Option<u16>,
+}
+
+impl HotSpot20Indication {
+    /// Parses the element body following the OUI and OUI type
+    #[must_use]
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let b = *bytes.first()?;
+        let pps_mo_id_present = (b & 0b0000_0010) != 0;
+        let anqp_domain_id_present = (b & 0b0000_0100) != 0;
+
+        let mut i = 1;
+        let mut read_u16 = |present: bool| -> Option<Option<u16>> {
+            if !present {
+                return Some(None);
+            }
+            if (i + 2) > bytes.len() {
+                return None;
+            }
+            let n = LittleEndian::read_u16(&bytes[i..(i + 2)]);
+            i += 2;
+            Some(Some(n))
+        };
+        let pps_mo_id = read_u16(pps_mo_id_present)?;
+        let anqp_domain_id = read_u16(anqp_domain_id_present)?;
+
+        Some(Self {
+            dgaf_disabled: (b & 0b0000_0001) != 0,
+            release_number: (b & 0b1111_0000) >> 4,
+            pps_mo_id,
+            anqp_domain_id,
+        })
+    }
+}
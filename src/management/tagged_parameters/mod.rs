@@ -0,0 +1,751 @@
+mod builder;
+mod country;
+mod erp;
+mod extended_capabilities;
+mod interworking;
+mod multiple_bssid;
+mod neighbor_report;
+mod operating_classes;
+mod operation;
+mod p2p;
+mod rates;
+mod tim;
+mod vendor_specific;
+mod wmm;
+mod wpa;
+mod wps;
+
+pub use self::{
+    builder::*, country::*, erp::*, extended_capabilities::*, interworking::*, multiple_bssid::*,
+    neighbor_report::*, operating_classes::*, operation::*, p2p::*, rates::*, tim::*,
+    vendor_specific::*, wmm::*, wpa::*, wps::*,
+};
+use super::*;
+use byteorder::{ByteOrder, LittleEndian};
+use std::collections::HashMap;
+use std::{error::Error, fmt};
+
+#[derive(Debug, Clone, Default)]
+pub struct TaggedParameters<'a> {
+    tags: HashMap<TagName, Cow<'a, [u8]>>,
+    /// every tag in the order it was added, including repeated tags
+    elements: Vec<(TagName, Cow<'a, [u8]>)>,
+}
+
+impl<'a> TaggedParameters<'a> {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            tags: HashMap::new(),
+            elements: Vec::new(),
+        }
+    }
+
+    pub fn add<T: Into<Cow<'a, [u8]>>>(&mut self, tag_name: TagName, tag_data: T) {
+        let tag_data = tag_data.into();
+        self.elements.push((tag_name, tag_data.clone()));
+        self.tags.insert(tag_name, tag_data);
+    }
+
+    #[must_use]
+    pub fn get_all(&self) -> &HashMap<TagName, Cow<'a, [u8]>> {
+        &self.tags
+    }
+
+    #[must_use]
+    pub fn get_bytes(&self, tag_name: TagName) -> Option<&[u8]> {
+        self.tags.get(&tag_name).map(AsRef::as_ref)
+    }
+
+    /// Every occurrence of a tag, for tags that may appear more than once
+    pub fn get_all_bytes(&self, tag_name: TagName) -> impl Iterator<Item = &[u8]> + '_ {
+        self.elements
+            .iter()
+            .filter(move |(name, _)| *name == tag_name)
+            .map(|(_, tag_data)| tag_data.as_ref())
+    }
+
+    #[must_use]
+    pub fn ssid(&self) -> Option<&[u8]> {
+        self.get_bytes(TagName::SSID).map(AsRef::as_ref)
+    }
+
+    /// in Mbit/sec, from the Supported Rates element only
+    ///
+    /// Use `rates` for basic rate flags, membership selectors and Extended
+    /// Supported Rates.
+    #[must_use]
+    pub fn supported_rates(&self) -> Option<Vec<f64>> {
+        self.tags
+            .get(&TagName::SupportedRates)
+            .map(|supported_rates| {
+                supported_rates
+                    .iter()
+                    .filter_map(|&b| Rate::from_u8(b).mbps())
+                    .collect()
+            })
+    }
+
+    #[must_use]
+    pub fn channel(&self) -> Option<u8> {
+        self.tags
+            .get(&TagName::DSParameter)
+            .map(|bytes| bytes[0])
+            // 5GHz
+            .or_else(|| self.tags.get(&TagName::HTInformation).map(|bytes| bytes[0]))
+    }
+
+    #[must_use]
+    pub fn rsn(&self) -> Option<RSNVersion> {
+        self.tags.get(&TagName::RSNInformation).and_then(|bytes| {
+            let mut i = 0;
+            let len = bytes.len();
+
+            if (i + 1) >= len {
+                return None;
+            }
+            let version = LittleEndian::read_u16(&bytes[i..=(i + 1)]);
+            Some(match version {
+                1 => {
+                    i += 2;
+                    RSNVersion::Standard(make_std_rsn(&bytes[i..]))
+                }
+                other => RSNVersion::Reserved(other),
+            })
+        })
+    }
+}
+
+fn make_std_rsn(bytes: &[u8]) -> RSN {
+    let mut i = 0;
+    let len = bytes.len();
+
+    let mut rsn = RSN::default();
+
+    if (i + 4) > len {
+        return rsn;
+    }
+    let group_cipher_suite = RSN::read_cipher_suite(&bytes[i..(i + 4)]);
+    rsn.group_cipher_suite = Some(group_cipher_suite);
+    i += 4;
+
+    if (i + 2) > len {
+        return rsn;
+    }
+    let pairwise_cipher_suite_count = LittleEndian::read_u16(&bytes[i..(i + 2)]);
+    i += 2;
+
+    for _ in 0..pairwise_cipher_suite_count {
+        if (i + 4) > len {
+            return rsn;
+        }
+        let pairwise_cipher_suite = RSN::read_cipher_suite(&bytes[i..(i + 4)]);
+        rsn.pairwise_cipher_suites.push(pairwise_cipher_suite);
+        i += 4;
+    }
+
+    if (i + 2) > len {
+        return rsn;
+    }
+    let akm_suite_count = LittleEndian::read_u16(&bytes[i..(i + 2)]);
+    i += 2;
+
+    for _ in 0..akm_suite_count {
+        if (i + 4) > len {
+            return rsn;
+        }
+        let akm_suite = RSN::read_akm_suite(&bytes[i..(i + 4)]);
+        rsn.akm_suites.push(akm_suite);
+        i += 4;
+    }
+
+    if (i + 2) > len {
+        return rsn;
+    }
+    let b = LittleEndian::read_u16(&bytes[i..(i + 2)]);
+    rsn.capabilities = Some(RSNCapabilities::from_u16(b));
+    i += 2;
+
+    if (i + 2) > len {
+        return rsn;
+    }
+    let pmkid_count = LittleEndian::read_u16(&bytes[i..(i + 2)]);
+    i += 2;
+
+    for _ in 0..pmkid_count {
+        if (i + 16) > len {
+            return rsn;
+        }
+        let mut pmkid = [0; 16];
+        pmkid.copy_from_slice(&bytes[i..(i + 16)]);
+        rsn.pmkids.push(pmkid);
+        i += 16;
+    }
+
+    if (i + 4) > len {
+        return rsn;
+    }
+    let group_management_cipher_suite = RSN::read_cipher_suite(&bytes[i..(i + 4)]);
+    rsn.group_management_cipher_suite = Some(group_management_cipher_suite);
+
+    rsn
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct RSNCapabilities {
+    /// 0: RSN Pre-Auth capabilities: Transmitter does not support pre-authentication
+    pub pre_auth: bool,
+    /// 0: RSN No Pairwise capabilities: Transmitter can support WEP default key 0 simultaneously with Pairwise key
+    pub pairwise: bool,
+
+    // {0x00, "1 replay counter per PTKSA/GTKSA/STAKeySA"},
+    // {0x01, "2 replay counters per PTKSA/GTKSA/STAKeySA"},
+    // {0x02, "4 replay counters per PTKSA/GTKSA/STAKeySA"},
+    // {0x03, "16 replay counters per PTKSA/GTKSA/STAKeySA"},
+    pub ptksa_replay_counter_value: u8,
+    pub gtksa_replay_counter_value: u8,
+
+    /// Management Frame Protection Required
+    pub management_frame_protection_required: bool,
+
+    /// Management Frame Protection Capable
+    pub management_frame_protection_capable: bool,
+
+    /// Joint Multi-band RSNA
+    pub joint_multi_band_rsna: bool,
+
+    /// PeerKey Enabled
+    pub peerkey: bool,
+}
+
+impl RSNCapabilities {
+    #[must_use]
+    pub fn from_u16(b: u16) -> Self {
+        Self {
+            pre_auth: (b & 0b0000_0000_0000_0001) != 0,
+            pairwise: (b & 0b0000_0000_0000_0010) != 0,
+            ptksa_replay_counter_value: ((b & 0b0000_0000_0000_1100) >> 2) as u8,
+            gtksa_replay_counter_value: ((b & 0b0000_0000_0011_0000) >> 4) as u8,
+            management_frame_protection_required: (b & 0b0000_0000_0100_0000) != 0,
+            management_frame_protection_capable: (b & 0b0000_0000_1000_0000) != 0,
+            joint_multi_band_rsna: (b & 0b0000_0001_0000_0000) != 0,
+            peerkey: (b & 0b0000_0010_0000_0000) != 0,
+        }
+    }
+
+    #[must_use]
+    pub fn into_u16(self) -> u16 {
+        let mut b = 0;
+        if self.pre_auth {
+            b |= 0b0000_0000_0000_0001;
+        }
+        if self.pairwise {
+            b |= 0b0000_0000_0000_0010;
+        }
+        b |= (u16::from(self.ptksa_replay_counter_value) << 2) & 0b0000_0000_0000_1100;
+        b |= (u16::from(self.gtksa_replay_counter_value) << 4) & 0b0000_0000_0011_0000;
+        if self.management_frame_protection_required {
+            b |= 0b0000_0000_0100_0000;
+        }
+        if self.management_frame_protection_capable {
+            b |= 0b0000_0000_1000_0000;
+        }
+        if self.joint_multi_band_rsna {
+            b |= 0b0000_0001_0000_0000;
+        }
+        if self.peerkey {
+            b |= 0b0000_0010_0000_0000;
+        }
+        b
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum RSNVersion {
+    Standard(RSN), // 1
+    Reserved(u16),
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct RSN {
+    pub group_cipher_suite: Option<CipherSuite>,
+    pub pairwise_cipher_suites: Vec<CipherSuite>,
+    pub akm_suites: Vec<AKMSuite>,
+    pub capabilities: Option<RSNCapabilities>,
+    pub pmkids: Vec<[u8; 16]>,
+    pub group_management_cipher_suite: Option<CipherSuite>,
+}
+
+impl RSN {
+    fn read_suite_oui_and_type(bytes: &[u8]) -> ([u8; 3], u8) {
+        let mut suite_oui = [0; 3];
+        suite_oui.clone_from_slice(&bytes[0..3]);
+        let suite_type = bytes[3];
+        (suite_oui, suite_type)
+    }
+
+    fn read_cipher_suite(bytes: &[u8]) -> CipherSuite {
+        let (oui, type_) = Self::read_suite_oui_and_type(bytes);
+
+        CipherSuite::from(oui, type_)
+    }
+
+    fn read_akm_suite(bytes: &[u8]) -> AKMSuite {
+        let (oui, type_) = Self::read_suite_oui_and_type(bytes);
+
+        AKMSuite::from(oui, type_)
+    }
+
+    /// RSN element body, starting with version 1
+    ///
+    /// Trailing fields are only written when they or a later field are set,
+    /// None when a later field is set without the group cipher suite.
+    #[must_use]
+    pub fn to_bytes(&self) -> Option<Vec<u8>> {
+        let has_group_management_cipher_suite = self.group_management_cipher_suite.is_some();
+        let has_pmkids = has_group_management_cipher_suite || !self.pmkids.is_empty();
+        let has_capabilities = has_pmkids || self.capabilities.is_some();
+        let has_akm_suites = has_capabilities || !self.akm_suites.is_empty();
+        let has_pairwise_cipher_suites = has_akm_suites || !self.pairwise_cipher_suites.is_empty();
+        let has_group_cipher_suite =
+            has_pairwise_cipher_suites || self.group_cipher_suite.is_some();
+
+        let mut bytes = vec![0; 2];
+        LittleEndian::write_u16(&mut bytes[0..2], 1);
+
+        if !has_group_cipher_suite {
+            return Some(bytes);
+        }
+        bytes.extend_from_slice(&self.group_cipher_suite?.to_bytes());
+
+        if !has_pairwise_cipher_suites {
+            return Some(bytes);
+        }
+        Self::write_u16(&mut bytes, self.pairwise_cipher_suites.len() as u16);
+        for pairwise_cipher_suite in &self.pairwise_cipher_suites {
+            bytes.extend_from_slice(&pairwise_cipher_suite.to_bytes());
+        }
+
+        if !has_akm_suites {
+            return Some(bytes);
+        }
+        Self::write_u16(&mut bytes, self.akm_suites.len() as u16);
+        for akm_suite in &self.akm_suites {
+            bytes.extend_from_slice(&akm_suite.to_bytes());
+        }
+
+        if !has_capabilities {
+            return Some(bytes);
+        }
+        let capabilities = self.capabilities.map_or(0, RSNCapabilities::into_u16);
+        Self::write_u16(&mut bytes, capabilities);
+
+        if !has_pmkids {
+            return Some(bytes);
+        }
+        Self::write_u16(&mut bytes, self.pmkids.len() as u16);
+        for pmkid in &self.pmkids {
+            bytes.extend_from_slice(pmkid);
+        }
+
+        if let Some(group_management_cipher_suite) = self.group_management_cipher_suite {
+            bytes.extend_from_slice(&group_management_cipher_suite.to_bytes());
+        }
+
+        Some(bytes)
+    }
+
+    fn write_u16(bytes: &mut Vec<u8>, n: u16) {
+        let mut buf = [0; 2];
+        LittleEndian::write_u16(&mut buf, n);
+        bytes.extend_from_slice(&buf);
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum CipherSuite {
+    Standard(CipherSuiteType),
+    Vendor([u8; 3], u8),
+}
+
+impl CipherSuite {
+    fn from(oui: [u8; 3], type_: u8) -> Self {
+        match oui {
+            [0x00, 0x0f, 0xac] => Self::Standard(CipherSuiteType::from(type_)),
+            other => Self::Vendor(other, type_),
+        }
+    }
+
+    #[must_use]
+    pub fn to_bytes(self) -> [u8; 4] {
+        match self {
+            Self::Standard(type_) => [0x00, 0x0f, 0xac, type_.into_u8()],
+            Self::Vendor([a, b, c], type_) => [a, b, c, type_],
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum CipherSuiteType {
+    UseGroupCipherSuite,             // 0
+    WEP40,                           // 1
+    TKIP,                            // 2
+    Reserved(u8),                    // 3 Reserved
+    CCMP,                            // 4 // AES (CCM)
+    WEP104,                          // 5
+    BIP,                             // 6
+    GroupAddressedTrafficNotAllowed, // 7
+    GCMP,                            // 8
+    GCMP256,                         // 9
+    CCMP256,                         // 10
+    BIPGMAC128,                      // 11
+    BIPGMAC256,                      // 12
+    BIPCMAC256,                      // 13
+                                     // 14-255 Reserved
+}
+impl CipherSuiteType {
+    fn from(type_: u8) -> Self {
+        match type_ {
+            0 => Self::UseGroupCipherSuite,
+            1 => Self::WEP40,
+            2 => Self::TKIP,
+            4 => Self::CCMP,
+            5 => Self::WEP104,
+            6 => Self::BIP,
+            7 => Self::GroupAddressedTrafficNotAllowed,
+            8 => Self::GCMP,
+            9 => Self::GCMP256,
+            10 => Self::CCMP256,
+            11 => Self::BIPGMAC128,
+            12 => Self::BIPGMAC256,
+            13 => Self::BIPCMAC256,
+            other => Self::Reserved(other),
+        }
+    }
+
+    fn into_u8(self) -> u8 {
+        match self {
+            Self::UseGroupCipherSuite => 0,
+            Self::WEP40 => 1,
+            Self::TKIP => 2,
+            Self::CCMP => 4,
+            Self::WEP104 => 5,
+            Self::BIP => 6,
+            Self::GroupAddressedTrafficNotAllowed => 7,
+            Self::GCMP => 8,
+            Self::GCMP256 => 9,
+            Self::CCMP256 => 10,
+            Self::BIPGMAC128 => 11,
+            Self::BIPGMAC256 => 12,
+            Self::BIPCMAC256 => 13,
+            Self::Reserved(other) => other,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum AKMSuite {
+    Standard(AKMSuiteType),
+    Vendor([u8; 3], u8),
+}
+
+impl AKMSuite {
+    fn from(oui: [u8; 3], type_: u8) -> Self {
+        match oui {
+            [0x00, 0x0f, 0xac] => Self::Standard(AKMSuiteType::from(type_)),
+            other => Self::Vendor(other, type_),
+        }
+    }
+
+    #[must_use]
+    pub fn to_bytes(self) -> [u8; 4] {
+        match self {
+            Self::Standard(type_) => [0x00, 0x0f, 0xac, type_.into_u8()],
+            Self::Vendor([a, b, c], type_) => [a, b, c, type_],
+        }
+    }
+}
+
+/// Authentication and Key Management Suite
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum AKMSuiteType {
+    // 0 Reserved
+    // 10-255 Reserved
+    Reserved(u8),
+    /// IEEE 802.1X with RSNA default
+    IEEE802_1X, // 1
+    /// Pre-Shared-Key
+    PSK, // 2
+    /// FT auth negotiated over IEEE 802.1X
+    FTOver802_1X, // 3
+    /// FT auth using PSK
+    FTPSK, // 4
+
+    /// IEEE 802.1X with SHA256 Key Derivation
+    IEEE802_1XSHA, // 5
+    /// PSK with SHA256 Key Derivation
+    PSKSHA, // 6
+    /// TPK Handshake
+    TDLS, // 7
+    /// SAE with SHA256
+    SAE, // 8
+    /// FT auth over SAE with SHA256
+    FTOverSAE, // 9
+}
+impl AKMSuiteType {
+    fn from(type_: u8) -> Self {
+        match type_ {
+            1 => Self::IEEE802_1X,
+            2 => Self::PSK,
+            3 => Self::FTOver802_1X,
+            4 => Self::FTPSK,
+            5 => Self::IEEE802_1XSHA,
+            6 => Self::PSKSHA,
+            7 => Self::TDLS,
+            8 => Self::SAE,
+            9 => Self::FTOverSAE,
+            other => Self::Reserved(other),
+        }
+    }
+
+    fn into_u8(self) -> u8 {
+        match self {
+            Self::IEEE802_1X => 1,
+            Self::PSK => 2,
+            Self::FTOver802_1X => 3,
+            Self::FTPSK => 4,
+            Self::IEEE802_1XSHA => 5,
+            Self::PSKSHA => 6,
+            Self::TDLS => 7,
+            Self::SAE => 8,
+            Self::FTOverSAE => 9,
+            Self::Reserved(other) => other,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Hash, Eq)]
+pub enum TagName {
+    Other(u8),
+    SSID,
+    SupportedRates,
+    DSParameter,
+    TrafficIndicationMap,
+    CountryInformation,
+    ChannelSwitchAnnouncement,
+    ERPInformation,
+    ExtendedSupportedRates,
+    RSNInformation,
+    QBSSLoadElement,
+    HTCapabilities,
+    HTInformation,
+    SupportedOperatingClasses,
+    ExtendedChannelSwitchAnnouncement,
+    MultipleBSSID,
+    NontransmittedBSSIDCapability,
+    MultipleBSSIDIndex,
+    NeighborReport,
+    ExtendedCapabilities,
+    VHTCapabilities,
+    VHTOperation,
+    PowerCapabilities,
+    Interworking,
+    AdvertisementProtocol,
+    RoamingConsortium,
+    ReducedNeighborReport,
+    VendorSpecific,
+    ElementExtension,
+}
+
+impl From<u8> for TagName {
+    fn from(tag_number: u8) -> Self {
+        match tag_number {
+            0 => TagName::SSID,
+            1 => TagName::SupportedRates,
+            3 => TagName::DSParameter,
+            5 => TagName::TrafficIndicationMap,
+            7 => TagName::CountryInformation,
+            37 => TagName::ChannelSwitchAnnouncement,
+            33 => TagName::PowerCapabilities,
+            42 => TagName::ERPInformation,
+            50 => TagName::ExtendedSupportedRates,
+            48 => TagName::RSNInformation,
+            11 => TagName::QBSSLoadElement,
+            45 => TagName::HTCapabilities,
+            52 => TagName::NeighborReport,
+            59 => TagName::SupportedOperatingClasses,
+            60 => TagName::ExtendedChannelSwitchAnnouncement,
+            71 => TagName::MultipleBSSID,
+            83 => TagName::NontransmittedBSSIDCapability,
+            85 => TagName::MultipleBSSIDIndex,
+            61 => TagName::HTInformation,
+            127 => TagName::ExtendedCapabilities,
+            107 => TagName::Interworking,
+            108 => TagName::AdvertisementProtocol,
+            111 => TagName::RoamingConsortium,
+            191 => TagName::VHTCapabilities,
+            192 => TagName::VHTOperation,
+            201 => TagName::ReducedNeighborReport,
+            221 => TagName::VendorSpecific,
+            255 => TagName::ElementExtension,
+
+            n => TagName::Other(n),
+        }
+    }
+}
+
+impl From<TagName> for u8 {
+    fn from(tag_name: TagName) -> Self {
+        match tag_name {
+            TagName::SSID => 0,
+            TagName::SupportedRates => 1,
+            TagName::DSParameter => 3,
+            TagName::TrafficIndicationMap => 5,
+            TagName::CountryInformation => 7,
+            TagName::ChannelSwitchAnnouncement => 37,
+            TagName::PowerCapabilities => 33,
+            TagName::ERPInformation => 42,
+            TagName::ExtendedSupportedRates => 50,
+            TagName::RSNInformation => 48,
+            TagName::QBSSLoadElement => 11,
+            TagName::HTCapabilities => 45,
+            TagName::NeighborReport => 52,
+            TagName::SupportedOperatingClasses => 59,
+            TagName::ExtendedChannelSwitchAnnouncement => 60,
+            TagName::MultipleBSSID => 71,
+            TagName::NontransmittedBSSIDCapability => 83,
+            TagName::MultipleBSSIDIndex => 85,
+            TagName::HTInformation => 61,
+            TagName::ExtendedCapabilities => 127,
+            TagName::Interworking => 107,
+            TagName::AdvertisementProtocol => 108,
+            TagName::RoamingConsortium => 111,
+            TagName::VHTCapabilities => 191,
+            TagName::VHTOperation => 192,
+            TagName::ReducedNeighborReport => 201,
+            TagName::VendorSpecific => 221,
+            TagName::ElementExtension => 255,
+
+            TagName::Other(n) => n,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct OverflowError {
+    required_length: usize,
+    remaining_length: usize,
+}
+
+impl OverflowError {
+    #[must_use]
+    pub fn new(required_length: usize,
+               remaining_length: usize) -> Self {
+        Self {
+            required_length,
+            remaining_length,
+        }
+    }
+}
+
+impl fmt::Display for OverflowError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "OverflowError: Expected {} bytes but only {} are remaining",
+               self.required_length,
+               self.remaining_length)
+    }
+}
+
+impl Error for OverflowError {}
+
+pub struct TaggedParameterIterator<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> TaggedParameterIterator<'a> {
+    #[must_use]
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes }
+    }
+}
+
+impl<'a> Iterator for TaggedParameterIterator<'a> {
+    type Item = Result<(TagName, &'a [u8]), OverflowError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.bytes.len() < 2 {
+            return None;
+        }
+
+        let tag_number = self.bytes[0];
+        let tag_length = self.bytes[1] as usize;
+        if self.bytes.len() < 2 + tag_length {
+            self.bytes = &self.bytes[self.bytes.len()..];
+            return Some(Err(OverflowError::new(tag_length, self.bytes.len())));
+        }
+
+        let tag_buffer = &self.bytes[2..(2+tag_length)];
+        self.bytes = &self.bytes[2+tag_length..];
+
+        Some(Ok((tag_number.into(), tag_buffer)))
+    }
+}
+
+pub trait TaggedParametersTrait: FrameTrait {
+    const TAGGED_PARAMETERS_START: usize;
+
+    fn iter_tagged_parameters(&self) -> TaggedParameterIterator<'_> {
+        TaggedParameterIterator {
+            bytes: &self.bytes()[Self::TAGGED_PARAMETERS_START..]
+        }
+    }
+
+    fn tagged_parameters(&self) -> Result<TaggedParameters<'_>, OverflowError> {
+        let mut tagged_parameters = TaggedParameters::new();
+
+        for tag in self.iter_tagged_parameters() {
+            let (tag_name, tag) = tag?;
+            tagged_parameters.add(tag_name, tag);
+        }
+
+        Ok(tagged_parameters)
+    }
+
+    fn ssid(&self) -> Option<Vec<u8>> {
+        self.tagged_parameters().ok()?.ssid().map(ToOwned::to_owned)
+    }
+
+    fn iter_vendor_specific(&self) -> VendorSpecificIterator<'_> {
+        VendorSpecificIterator::new(self.iter_tagged_parameters())
+    }
+}
+
+pub trait OptionalTaggedParametersTrait: ManagementFrameTrait {
+    fn iter_tagged_parameters(&self) -> Option<TaggedParameterIterator<'_>> {
+        let subtype = match self.subtype() {
+            FrameSubtype::Management(subtype) => subtype,
+            _ => return None
+        };
+
+        let offset = match subtype {
+            ManagementSubtype::AssociationRequest => AssociationRequestFrame::TAGGED_PARAMETERS_START,
+            ManagementSubtype::AssociationResponse => AssociationResponseFrame::TAGGED_PARAMETERS_START,
+            ManagementSubtype::ReassociationRequest => ReassociationRequestFrame::TAGGED_PARAMETERS_START,
+            ManagementSubtype::Authentication => AuthenticationFrame::TAGGED_PARAMETERS_START,
+            ManagementSubtype::Beacon => BeaconFrame::TAGGED_PARAMETERS_START,
+            ManagementSubtype::ProbeRequest => ProbeRequestFrame::TAGGED_PARAMETERS_START,
+            ManagementSubtype::ProbeResponse => ProbeResponseFrame::TAGGED_PARAMETERS_START,
+            _ => return None
+        };
+
+        if offset > self.bytes().len() {
+            return None;
+        }
+
+        Some(TaggedParameterIterator {
+            bytes: &self.bytes()[offset..]
+        })
+    }
+}
+
+impl OptionalTaggedParametersTrait for ManagementFrame<'_> {}
@@ -0,0 +1,284 @@
+use super::*;
+
+/// Element ID Extension of the Non-Inheritance element
+pub const NON_INHERITANCE_EXTENSION_ID: u8 = 56;
+
+impl<'a> TaggedParameters<'a> {
+    /// Every Multiple BSSID element
+    #[must_use]
+    pub fn multiple_bssid(&self) -> Vec<MultipleBSSID> {
+        self.get_all_bytes(TagName::MultipleBSSID)
+            .filter_map(MultipleBSSID::from_bytes)
+            .collect()
+    }
+
+    /// Nontransmitted BSSs of the Multiple BSSID elements, `transmitted_bssid`
+    /// is the BSSID of the frame carrying them
+    ///
+    /// Each BSS inherits the elements of the transmitted BSS that its profile
+    /// does not override or list in a Non-Inheritance element.
+    #[must_use]
+    pub fn nontransmitted_bsss(&self, transmitted_bssid: MacAddress) -> Vec<NontransmittedBSS<'a>> {
+        let mut bsss = Vec::new();
+
+        for multiple_bssid in self.multiple_bssid() {
+            for profile in multiple_bssid.nontransmitted_bssid_profiles {
+                let profile_elements = TaggedParameterIterator::new(&profile)
+                    .map_while(Result::ok)
+                    .map(|(tag_name, bytes)| (tag_name, bytes.to_vec()))
+                    .collect::<Vec<_>>();
+
+                if let Some(bss) = self.nontransmitted_bss(
+                    transmitted_bssid,
+                    multiple_bssid.max_bssid_indicator,
+                    profile_elements,
+                ) {
+                    bsss.push(bss);
+                }
+            }
+        }
+
+        bsss
+    }
+
+    fn nontransmitted_bss(
+        &self,
+        transmitted_bssid: MacAddress,
+        max_bssid_indicator: u8,
+        profile_elements: Vec<(TagName, Vec<u8>)>,
+    ) -> Option<NontransmittedBSS<'a>> {
+        let find = |tag_name: TagName| {
+            profile_elements
+                .iter()
+                .find(|(name, _)| *name == tag_name)
+                .map(|(_, bytes)| bytes.as_slice())
+        };
+
+        let capabilities = find(TagName::NontransmittedBSSIDCapability).filter(|b| b.len() >= 2)?;
+        let capabilities_info = CapabilitiesInfo::from_bytes(capabilities);
+        let index = find(TagName::MultipleBSSIDIndex)?;
+        let bssid_index = *index.first()?;
+        let dtim_period = index.get(1).copied();
+        let dtim_count = index.get(2).copied();
+
+        let extension_id = |name: TagName, bytes: &[u8]| {
+            if name == TagName::ElementExtension {
+                bytes.first().copied()
+            } else {
+                None
+            }
+        };
+        let non_inheritance = profile_elements
+            .iter()
+            .find(|(name, bytes)| extension_id(*name, bytes) == Some(NON_INHERITANCE_EXTENSION_ID))
+            .map(|(_, bytes)| NonInheritance::from_bytes(&bytes[1..]))
+            .unwrap_or_default();
+
+        let mut tagged_parameters = TaggedParameters::new();
+
+        // the transmitted BSS's elements, minus the ones that only describe it
+        for (name, bytes) in &self.elements {
+            let excluded = matches!(
+                name,
+                TagName::SSID
+                    | TagName::MultipleBSSID
+                    | TagName::NontransmittedBSSIDCapability
+                    | TagName::MultipleBSSIDIndex
+            );
+            let overridden = profile_elements
+                .iter()
+                .any(|(profile_name, profile_bytes)| {
+                    profile_name == name
+                        && extension_id(*profile_name, profile_bytes) == extension_id(*name, bytes)
+                });
+            let not_inherited = match extension_id(*name, bytes) {
+                Some(id) => non_inheritance.element_id_extensions.contains(&id),
+                None => non_inheritance.element_ids.contains(&u8::from(*name)),
+            };
+
+            if !excluded && !overridden && !not_inherited {
+                tagged_parameters.add(*name, bytes.clone());
+            }
+        }
+
+        for (name, bytes) in profile_elements {
+            if !matches!(
+                name,
+                TagName::NontransmittedBSSIDCapability | TagName::MultipleBSSIDIndex
+            ) && extension_id(name, &bytes) != Some(NON_INHERITANCE_EXTENSION_ID)
+            {
+                tagged_parameters.add(name, bytes);
+            }
+        }
+
+        Some(NontransmittedBSS {
+            bssid: nontransmitted_bssid(transmitted_bssid, max_bssid_indicator, bssid_index),
+            bssid_index,
+            capabilities_info,
+            dtim_period,
+            dtim_count,
+            tagged_parameters,
+        })
+    }
+}
+
+/// Multiple BSSID element (71)
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MultipleBSSID {
+    /// the BSS set has up to 2^n BSSIDs
+    pub max_bssid_indicator: u8,
+    /// elements of each Nontransmitted BSSID Profile subelement, a profile
+    /// split across subelements is joined back
+    pub nontransmitted_bssid_profiles: Vec<Vec<u8>>,
+}
+
+impl MultipleBSSID {
+    #[must_use]
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let (&max_bssid_indicator, mut rest) = bytes.split_first()?;
+
+        let mut nontransmitted_bssid_profiles: Vec<Vec<u8>> = Vec::new();
+        while rest.len() >= 2 {
+            let id = rest[0];
+            let length = rest[1] as usize;
+            if rest.len() < 2 + length {
+                return None;
+            }
+            let data = &rest[2..(2 + length)];
+            rest = &rest[(2 + length)..];
+
+            // Nontransmitted BSSID Profile
+            if id != 0 {
+                continue;
+            }
+
+            // a profile starts with the Nontransmitted BSSID Capability
+            // element, anything else continues the previous one
+            match nontransmitted_bssid_profiles.last_mut() {
+                Some(profile)
+                    if data.first().copied().map(TagName::from)
+                        != Some(TagName::NontransmittedBSSIDCapability) =>
+                {
+                    profile.extend_from_slice(data);
+                }
+                _ => nontransmitted_bssid_profiles.push(data.to_vec()),
+            }
+        }
+
+        Some(Self {
+            max_bssid_indicator,
+            nontransmitted_bssid_profiles,
+        })
+    }
+
+    /// Element body, a profile longer than a subelement is split across
+    /// subelements and an empty profile is left out, it would be read as a
+    /// continuation
+    #[must_use]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![self.max_bssid_indicator];
+        for profile in &self.nontransmitted_bssid_profiles {
+            if profile.is_empty() {
+                continue;
+            }
+
+            let mut rest = &profile[..];
+            loop {
+                // a continuation starting with the Nontransmitted BSSID
+                // Capability element would be read as a new profile
+                let mut length = rest.len().min(255);
+                while length > 1
+                    && rest.get(length).copied().map(TagName::from)
+                        == Some(TagName::NontransmittedBSSIDCapability)
+                {
+                    length -= 1;
+                }
+
+                let (data, next) = rest.split_at(length);
+                bytes.push(0);
+                bytes.push(length as u8);
+                bytes.extend_from_slice(data);
+
+                rest = next;
+                if rest.is_empty() {
+                    break;
+                }
+            }
+        }
+        bytes
+    }
+}
+
+/// Non-Inheritance element (255, extension 56)
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct NonInheritance {
+    pub element_ids: Vec<u8>,
+    pub element_id_extensions: Vec<u8>,
+}
+
+impl NonInheritance {
+    /// Parses the element body following the extension ID
+    #[must_use]
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        let mut non_inheritance = Self::default();
+
+        if let Some((&length, rest)) = bytes.split_first() {
+            let length = (length as usize).min(rest.len());
+            non_inheritance.element_ids = rest[..length].to_vec();
+
+            if let Some((&extension_length, rest)) = rest[length..].split_first() {
+                let extension_length = (extension_length as usize).min(rest.len());
+                non_inheritance.element_id_extensions = rest[..extension_length].to_vec();
+            }
+        }
+
+        non_inheritance
+    }
+}
+
+/// A BSS advertised in a Multiple BSSID element
+#[derive(Debug, Clone)]
+pub struct NontransmittedBSS<'a> {
+    pub bssid: MacAddress,
+    /// BSSID Index from the Multiple BSSID-Index element
+    pub bssid_index: u8,
+    pub capabilities_info: CapabilitiesInfo,
+    /// only in beacons
+    pub dtim_period: Option<u8>,
+    /// only in beacons
+    pub dtim_count: Option<u8>,
+    /// the profile's elements and the inherited elements of the transmitted
+    /// BSS
+    pub tagged_parameters: TaggedParameters<'a>,
+}
+
+impl NontransmittedBSS<'_> {
+    #[must_use]
+    pub fn ssid(&self) -> Option<&[u8]> {
+        self.tagged_parameters.ssid()
+    }
+
+    #[must_use]
+    pub fn rsn(&self) -> Option<RSNVersion> {
+        self.tagged_parameters.rsn()
+    }
+}
+
+/// BSSID of the nontransmitted BSS with `bssid_index`, the `max_bssid_indicator`
+/// low bits of the transmitted BSSID plus the index, modulo 2^n
+#[must_use]
+pub fn nontransmitted_bssid(
+    transmitted_bssid: MacAddress,
+    max_bssid_indicator: u8,
+    bssid_index: u8,
+) -> MacAddress {
+    let mut bytes = [0; 8];
+    bytes[2..].copy_from_slice(transmitted_bssid.as_bytes());
+    let transmitted = u64::from_be_bytes(bytes);
+
+    let mask = (1u64 << max_bssid_indicator.min(47)) - 1;
+    let low = ((transmitted & mask) + u64::from(bssid_index)) & mask;
+    let bssid = (transmitted & !mask) | low;
+
+    MacAddress::from_bytes(&bssid.to_be_bytes()[2..]).unwrap()
+}
@@ -0,0 +1,716 @@
+use super::*;
+
+impl TaggedParameters<'_> {
+    /// Every Neighbor Report element
+    #[must_use]
+    pub fn neighbor_reports(&self) -> Vec<NeighborReport> {
+        self.get_all_bytes(TagName::NeighborReport)
+            .filter_map(NeighborReport::from_bytes)
+            .collect()
+    }
+
+    /// Neighbor AP Information fields of every Reduced Neighbor Report element
+    #[must_use]
+    pub fn reduced_neighbor_report(&self) -> Vec<NeighborAPInformation> {
+        self.get_all_bytes(TagName::ReducedNeighborReport)
+            .flat_map(NeighborAPInformation::from_reduced_neighbor_report)
+            .collect()
+    }
+
+    /// 6 GHz APs the reporting AP says are co-located with it
+    #[must_use]
+    pub fn colocated_6ghz_aps(&self) -> Vec<(Channel, TBTTInformation)> {
+        self.reduced_neighbor_report()
+            .into_iter()
+            .filter_map(|neighbor_ap_information| {
+                let channel = neighbor_ap_information.channel()?;
+                if channel.band != Band::SixGHz {
+                    return None;
+                }
+
+                Some(
+                    neighbor_ap_information
+                        .tbtt_information
+                        .into_iter()
+                        .filter(|tbtt_information| {
+                            tbtt_information
+                                .bss_parameters
+                                .is_some_and(|bss_parameters| bss_parameters.colocated_ap)
+                        })
+                        .map(move |tbtt_information| (channel, tbtt_information)),
+                )
+            })
+            .flatten()
+            .collect()
+    }
+}
+
+/// Neighbor Report element (52)
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NeighborReport {
+    pub bssid: MacAddress,
+    pub bssid_information: BSSIDInformation,
+    pub operating_class: u8,
+    pub channel_number: u8,
+    pub phy_type: PhyType,
+    pub subelements: Vec<NeighborReportSubelement>,
+}
+
+impl NeighborReport {
+    #[must_use]
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() < 13 {
+            return None;
+        }
+
+        let mut subelements = Vec::new();
+        let mut i = 13;
+        while (i + 2) <= bytes.len() {
+            let id = bytes[i];
+            let length = bytes[i + 1] as usize;
+            i += 2;
+            if (i + length) > bytes.len() {
+                return None;
+            }
+            subelements.push(NeighborReportSubelement::from_bytes(
+                id,
+                &bytes[i..(i + length)],
+            ));
+            i += length;
+        }
+
+        Some(Self {
+            bssid: MacAddress::from_bytes(&bytes[0..6]).unwrap(),
+            bssid_information: BSSIDInformation::from_u32(LittleEndian::read_u32(&bytes[6..10])),
+            operating_class: bytes[10],
+            channel_number: bytes[11],
+            phy_type: PhyType::from_u8(bytes[12]),
+            subelements,
+        })
+    }
+
+    #[must_use]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = self.bssid.as_bytes().to_vec();
+        let mut bssid_information = [0; 4];
+        LittleEndian::write_u32(&mut bssid_information, self.bssid_information.into_u32());
+        bytes.extend_from_slice(&bssid_information);
+        bytes.push(self.operating_class);
+        bytes.push(self.channel_number);
+        bytes.push(self.phy_type.into_u8());
+        for subelement in &self.subelements {
+            let (id, data) = subelement.to_bytes();
+            bytes.push(id);
+            bytes.push(data.len() as u8);
+            bytes.extend_from_slice(&data);
+        }
+        bytes
+    }
+
+    /// Channel from the operating class, widened by a Wide Bandwidth Channel
+    /// subelement, `region` is the table of the operating class, either the
+    /// BSS's country or global
+    #[must_use]
+    pub fn channel(&self, region: Region) -> Option<Channel> {
+        let channel = region
+            .operating_class(self.operating_class)?
+            .channel(self.channel_number)?;
+
+        let wide_bandwidth_channel =
+            self.subelements
+                .iter()
+                .find_map(|subelement| match subelement {
+                    NeighborReportSubelement::WideBandwidthChannel(vht_operation) => {
+                        Some(vht_operation)
+                    }
+                    _ => None,
+                });
+        let Some(vht_operation) = wide_bandwidth_channel else {
+            return Some(channel);
+        };
+
+        // a 20 MHz channel, the HT Operation decides about 40 MHz
+        let primary = Channel::new(channel.band, channel.number)?;
+        let ht_operation = HTOperation {
+            primary_channel: channel.number,
+            secondary_channel_offset: SecondaryChannelOffset::None,
+            sta_channel_width: true,
+            rifs_mode: false,
+            ht_protection: 0,
+            nongreenfield_stas_present: false,
+            obss_non_ht_stas_present: false,
+            channel_center_frequency_segment_2: 0,
+        };
+
+        Some(
+            vht_operation
+                .channel(primary, &ht_operation)
+                .unwrap_or(channel),
+        )
+    }
+
+    /// BSS Transition Candidate Preference, 255 is the most preferred
+    #[must_use]
+    pub fn preference(&self) -> Option<u8> {
+        self.subelements
+            .iter()
+            .find_map(|subelement| match subelement {
+                NeighborReportSubelement::BSSTransitionCandidatePreference(preference) => {
+                    Some(*preference)
+                }
+                _ => None,
+            })
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum APReachability {
+    NotReachable, // 1
+    Unknown,      // 2
+    Reachable,    // 3
+    Reserved(u8),
+}
+
+impl APReachability {
+    #[must_use]
+    pub fn from_u8(n: u8) -> Self {
+        match n {
+            1 => Self::NotReachable,
+            2 => Self::Unknown,
+            3 => Self::Reachable,
+            other => Self::Reserved(other),
+        }
+    }
+
+    #[must_use]
+    pub fn into_u8(self) -> u8 {
+        match self {
+            Self::NotReachable => 1,
+            Self::Unknown => 2,
+            Self::Reachable => 3,
+            Self::Reserved(other) => other,
+        }
+    }
+}
+
+/// BSSID Information field of the Neighbor Report element
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct BSSIDInformation {
+    pub ap_reachability: APReachability,
+    /// same security as the reporting AP
+    pub security: bool,
+    /// same authenticator as the reporting AP
+    pub key_scope: bool,
+    pub spectrum_management: bool,
+    pub qos: bool,
+    pub apsd: bool,
+    pub radio_measurement: bool,
+    pub delayed_block_ack: bool,
+    pub immediate_block_ack: bool,
+    /// same mobility domain as the reporting AP
+    pub mobility_domain: bool,
+    pub high_throughput: bool,
+    pub very_high_throughput: bool,
+    pub ftm: bool,
+    pub high_efficiency: bool,
+    pub er_bss: bool,
+    pub colocated_ap: bool,
+    pub unsolicited_probe_responses_active: bool,
+    pub member_of_ess_with_2ghz_5ghz_colocated_ap: bool,
+    pub oct_supported_with_reporting_ap: bool,
+    pub colocated_6ghz_ap: bool,
+    pub extremely_high_throughput: bool,
+}
+
+impl BSSIDInformation {
+    #[must_use]
+    pub fn from_u32(n: u32) -> Self {
+        let bit = |i: u32| (n & (1 << i)) != 0;
+
+        Self {
+            ap_reachability: APReachability::from_u8((n & 0b11) as u8),
+            security: bit(2),
+            key_scope: bit(3),
+            spectrum_management: bit(4),
+            qos: bit(5),
+            apsd: bit(6),
+            radio_measurement: bit(7),
+            delayed_block_ack: bit(8),
+            immediate_block_ack: bit(9),
+            mobility_domain: bit(10),
+            high_throughput: bit(11),
+            very_high_throughput: bit(12),
+            ftm: bit(13),
+            high_efficiency: bit(14),
+            er_bss: bit(15),
+            colocated_ap: bit(16),
+            unsolicited_probe_responses_active: bit(17),
+            member_of_ess_with_2ghz_5ghz_colocated_ap: bit(18),
+            oct_supported_with_reporting_ap: bit(19),
+            colocated_6ghz_ap: bit(20),
+            extremely_high_throughput: bit(21),
+        }
+    }
+
+    #[must_use]
+    pub fn into_u32(self) -> u32 {
+        let flags = [
+            self.security,
+            self.key_scope,
+            self.spectrum_management,
+            self.qos,
+            self.apsd,
+            self.radio_measurement,
+            self.delayed_block_ack,
+            self.immediate_block_ack,
+            self.mobility_domain,
+            self.high_throughput,
+            self.very_high_throughput,
+            self.ftm,
+            self.high_efficiency,
+            self.er_bss,
+            self.colocated_ap,
+            self.unsolicited_probe_responses_active,
+            self.member_of_ess_with_2ghz_5ghz_colocated_ap,
+            self.oct_supported_with_reporting_ap,
+            self.colocated_6ghz_ap,
+            self.extremely_high_throughput,
+        ];
+
+        flags.iter().enumerate().filter(|(_, flag)| **flag).fold(
+            u32::from(self.ap_reachability.into_u8() & 0b11),
+            |n, (i, _)| n | (1 << (i + 2)),
+        )
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum PhyType {
+    /// Frequency-Hopping Spread Spectrum
+    FHSS, // 1
+    /// Direct Sequence Spread Spectrum
+    DSSS, // 2
+    /// Infrared baseband
+    IRBaseband, // 3
+    /// Orthogonal Frequency Division Multiplexing, 802.11a
+    OFDM, // 4
+    /// High Rate DSSS, 802.11b
+    HRDSSS, // 5
+    /// Extended Rate PHY, 802.11g
+    ERP, // 6
+    /// High Throughput, 802.11n
+    HT, // 7
+    /// Directional Multi-Gigabit, 802.11ad
+    DMG, // 8
+    /// Very High Throughput, 802.11ac
+    VHT, // 9
+    /// Television Very High Throughput, 802.11af
+    TVHT, // 10
+    /// Sub 1 GHz, 802.11ah
+    S1G, // 11
+    /// China Directional Multi-Gigabit
+    CDMG, // 12
+    /// China Millimeter-wave Multi-Gigabit
+    CMMG, // 13
+    /// High Efficiency, 802.11ax
+    HE, // 14
+    /// Extremely High Throughput, 802.11be
+    EHT, // 15
+    /// 0 and any other PHY type
+    Reserved(u8),
+}
+
+impl PhyType {
+    #[must_use]
+    pub fn from_u8(n: u8) -> Self {
+        match n {
+            1 => Self::FHSS,
+            2 => Self::DSSS,
+            3 => Self::IRBaseband,
+            4 => Self::OFDM,
+            5 => Self::HRDSSS,
+            6 => Self::ERP,
+            7 => Self::HT,
+            8 => Self::DMG,
+            9 => Self::VHT,
+            10 => Self::TVHT,
+            11 => Self::S1G,
+            12 => Self::CDMG,
+            13 => Self::CMMG,
+            14 => Self::HE,
+            15 => Self::EHT,
+            other => Self::Reserved(other),
+        }
+    }
+
+    #[must_use]
+    pub fn into_u8(self) -> u8 {
+        match self {
+            Self::FHSS => 1,
+            Self::DSSS => 2,
+            Self::IRBaseband => 3,
+            Self::OFDM => 4,
+            Self::HRDSSS => 5,
+            Self::ERP => 6,
+            Self::HT => 7,
+            Self::DMG => 8,
+            Self::VHT => 9,
+            Self::TVHT => 10,
+            Self::S1G => 11,
+            Self::CDMG => 12,
+            Self::CMMG => 13,
+            Self::HE => 14,
+            Self::EHT => 15,
+            Self::Reserved(other) => other,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum NeighborReportSubelement {
+    /// TSF Information
+    TSFInformation {
+        /// in TUs
+        tsf_offset: u16,
+        /// in TUs
+        beacon_interval: u16,
+    }, // 1
+    /// Condensed Country String
+    CondensedCountryString([u8; 2]), // 2
+    /// BSS Transition Candidate Preference
+    BSSTransitionCandidatePreference(u8), // 3
+    /// BSS Termination Duration
+    BSSTerminationDuration {
+        bss_termination_tsf: u64,
+        /// in minutes
+        duration: u16,
+    }, // 4
+    /// Bearing
+    Bearing(Vec<u8>), // 5
+    /// Wide Bandwidth Channel, laid out like the VHT Operation Information
+    WideBandwidthChannel(VHTOperation), // 6
+    /// HT Capabilities
+    HTCapabilities(Vec<u8>), // 45
+    /// HT Operation
+    HTOperation(HTOperation), // 61
+    /// VHT Capabilities
+    VHTCapabilities(Vec<u8>), // 191
+    /// VHT Operation
+    VHTOperation(VHTOperation), // 192
+    /// Vendor Specific
+    VendorSpecific(Vec<u8>), // 221
+    /// any other subelement, or a known one that is too short
+    Other(u8, Vec<u8>),
+}
+
+impl NeighborReportSubelement {
+    fn from_bytes(id: u8, bytes: &[u8]) -> Self {
+        let subelement = match id {
+            1 if bytes.len() >= 4 => Some(Self::TSFInformation {
+                tsf_offset: LittleEndian::read_u16(&bytes[0..2]),
+                beacon_interval: LittleEndian::read_u16(&bytes[2..4]),
+            }),
+            2 if bytes.len() >= 2 => Some(Self::CondensedCountryString([bytes[0], bytes[1]])),
+            3 if !bytes.is_empty() => Some(Self::BSSTransitionCandidatePreference(bytes[0])),
+            4 if bytes.len() >= 10 => Some(Self::BSSTerminationDuration {
+                bss_termination_tsf: LittleEndian::read_u64(&bytes[0..8]),
+                duration: LittleEndian::read_u16(&bytes[8..10]),
+            }),
+            5 => Some(Self::Bearing(bytes.to_vec())),
+            6 if bytes.len() >= 3 => Some(Self::WideBandwidthChannel(VHTOperation {
+                channel_width: bytes[0],
+                channel_center_frequency_segment_0: bytes[1],
+                channel_center_frequency_segment_1: bytes[2],
+                basic_vht_mcs_and_nss_set: 0,
+            })),
+            45 => Some(Self::HTCapabilities(bytes.to_vec())),
+            61 => HTOperation::from_bytes(bytes).map(Self::HTOperation),
+            191 => Some(Self::VHTCapabilities(bytes.to_vec())),
+            192 => VHTOperation::from_bytes(bytes).map(Self::VHTOperation),
+            221 => Some(Self::VendorSpecific(bytes.to_vec())),
+            _ => None,
+        };
+
+        subelement.unwrap_or_else(|| Self::Other(id, bytes.to_vec()))
+    }
+
+    /// Subelement ID and body
+    ///
+    /// HT Operation and VHT Operation only keep their decoded fields, use
+    /// `Other` to write them verbatim.
+    fn to_bytes(&self) -> (u8, Vec<u8>) {
+        match self {
+            Self::TSFInformation {
+                tsf_offset,
+                beacon_interval,
+            } => {
+                let mut bytes = vec![0; 4];
+                LittleEndian::write_u16(&mut bytes[0..2], *tsf_offset);
+                LittleEndian::write_u16(&mut bytes[2..4], *beacon_interval);
+                (1, bytes)
+            }
+            Self::CondensedCountryString(country_string) => (2, country_string.to_vec()),
+            Self::BSSTransitionCandidatePreference(preference) => (3, vec![*preference]),
+            Self::BSSTerminationDuration {
+                bss_termination_tsf,
+                duration,
+            } => {
+                let mut bytes = vec![0; 10];
+                LittleEndian::write_u64(&mut bytes[0..8], *bss_termination_tsf);
+                LittleEndian::write_u16(&mut bytes[8..10], *duration);
+                (4, bytes)
+            }
+            Self::Bearing(bytes) => (5, bytes.clone()),
+            Self::WideBandwidthChannel(vht_operation) => (
+                6,
+                vec![
+                    vht_operation.channel_width,
+                    vht_operation.channel_center_frequency_segment_0,
+                    vht_operation.channel_center_frequency_segment_1,
+                ],
+            ),
+            Self::HTCapabilities(bytes) => (45, bytes.clone()),
+            Self::HTOperation(ht_operation) => {
+                let mut bytes = vec![0; 22];
+                bytes[0] = ht_operation.primary_channel;
+                bytes[1] = ht_operation.secondary_channel_offset.into_u8()
+                    | (u8::from(ht_operation.sta_channel_width) << 2)
+                    | (u8::from(ht_operation.rifs_mode) << 3);
+                bytes[2] = ht_operation.ht_protection
+                    | (u8::from(ht_operation.nongreenfield_stas_present) << 2)
+                    | (u8::from(ht_operation.obss_non_ht_stas_present) << 4)
+                    | (ht_operation.channel_center_frequency_segment_2 << 5);
+                bytes[3] = ht_operation.channel_center_frequency_segment_2 >> 3;
+                (61, bytes)
+            }
+            Self::VHTCapabilities(bytes) => (191, bytes.clone()),
+            Self::VHTOperation(vht_operation) => {
+                let mut bytes = vec![
+                    vht_operation.channel_width,
+                    vht_operation.channel_center_frequency_segment_0,
+                    vht_operation.channel_center_frequency_segment_1,
+                    0,
+                    0,
+                ];
+                LittleEndian::write_u16(&mut bytes[3..5], vht_operation.basic_vht_mcs_and_nss_set);
+                (192, bytes)
+            }
+            Self::VendorSpecific(bytes) => (221, bytes.clone()),
+            Self::Other(id, bytes) => (*id, bytes.clone()),
+        }
+    }
+}
+
+/// Neighbor AP Information field of the Reduced Neighbor Report element (201)
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NeighborAPInformation {
+    /// 0 for the TBTT Information field types below
+    pub tbtt_information_field_type: u8,
+    /// the neighbor APs match the SSID of a probe request
+    pub filtered_neighbor_ap: bool,
+    pub operating_class: u8,
+    pub channel_number: u8,
+    pub tbtt_information: Vec<TBTTInformation>,
+}
+
+impl NeighborAPInformation {
+    /// Every Neighbor AP Information field of a Reduced Neighbor Report element
+    #[must_use]
+    pub fn from_reduced_neighbor_report(bytes: &[u8]) -> Vec<Self> {
+        let mut neighbor_ap_information = Vec::new();
+
+        let mut i = 0;
+        while (i + 4) <= bytes.len() {
+            let tbtt_information_field_type = bytes[i] & 0b0000_0011;
+            let filtered_neighbor_ap = (bytes[i] & 0b0000_0100) != 0;
+            let tbtt_information_count = usize::from(bytes[i] >> 4) + 1;
+            let tbtt_information_length = usize::from(bytes[i + 1]);
+            let operating_class = bytes[i + 2];
+            let channel_number = bytes[i + 3];
+            i += 4;
+
+            let end = i + tbtt_information_count * tbtt_information_length;
+            if end > bytes.len() {
+                break;
+            }
+            let tbtt_information = if tbtt_information_length == 0 {
+                Vec::new()
+            } else {
+                bytes[i..end]
+                    .chunks_exact(tbtt_information_length)
+                    .map(TBTTInformation::from_bytes)
+                    .collect()
+            };
+            i = end;
+
+            neighbor_ap_information.push(Self {
+                tbtt_information_field_type,
+                filtered_neighbor_ap,
+                operating_class,
+                channel_number,
+                tbtt_information,
+            });
+        }
+
+        neighbor_ap_information
+    }
+
+    /// 20 MHz primary channel from the global operating class
+    #[must_use]
+    pub fn channel(&self) -> Option<Channel> {
+        let channel = Channel::from_operating_class(self.operating_class, self.channel_number)?;
+        Channel::new(channel.band, channel.number)
+    }
+}
+
+/// TBTT Information field, the fields present depend on its length
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct TBTTInformation {
+    /// offset to the neighbor's next TBTT in TUs, 254 for 254 or more, 255 for
+    /// unknown
+    pub neighbor_ap_tbtt_offset: u8,
+    pub bssid: Option<MacAddress>,
+    /// CRC-32 of the SSID, see `short_ssid`
+    pub short_ssid: Option<u32>,
+    pub bss_parameters: Option<BSSParameters>,
+    /// maximum transmit power spectral density in units of 0.5 dBm/MHz, -128
+    /// for no limit
+    pub psd_20mhz: Option<i8>,
+}
+
+impl TBTTInformation {
+    #[must_use]
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        let len = bytes.len();
+        let has_bssid = len >= 7;
+        let has_short_ssid = len == 5 || len == 6 || len >= 11;
+        let has_bss_parameters = matches!(len, 2 | 6 | 8 | 9) || len >= 12;
+        let has_psd_20mhz = len == 9 || len >= 13;
+
+        let mut i = 1;
+        let bssid = if has_bssid {
+            i += 6;
+            Some(MacAddress::from_bytes(&bytes[1..7]).unwrap())
+        } else {
+            None
+        };
+        let short_ssid = if has_short_ssid {
+            i += 4;
+            Some(LittleEndian::read_u32(&bytes[(i - 4)..i]))
+        } else {
+            None
+        };
+        let bss_parameters = if has_bss_parameters {
+            i += 1;
+            Some(BSSParameters::from_u8(bytes[i - 1]))
+        } else {
+            None
+        };
+        let psd_20mhz = if has_psd_20mhz {
+            Some(bytes[i] as i8)
+        } else {
+            None
+        };
+
+        Self {
+            neighbor_ap_tbtt_offset: bytes.first().copied().unwrap_or(255),
+            bssid,
+            short_ssid,
+            bss_parameters,
+            psd_20mhz,
+        }
+    }
+
+    /// Whether the short SSID is that of `ssid`
+    #[must_use]
+    pub fn matches_ssid(&self, ssid: &[u8]) -> bool {
+        self.short_ssid == Some(short_ssid(ssid))
+    }
+}
+
+/// BSS Parameters subfield of the TBTT Information field
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct BSSParameters {
+    /// on-channel tunneling is recommended to reach the AP
+    pub oct_recommended: bool,
+    /// same SSID as the reporting AP
+    pub same_ssid: bool,
+    pub multiple_bssid: bool,
+    pub transmitted_bssid: bool,
+    pub member_of_ess_with_2ghz_5ghz_colocated_ap: bool,
+    pub unsolicited_probe_responses_active: bool,
+    pub colocated_ap: bool,
+}
+
+impl BSSParameters {
+    #[must_use]
+    pub fn from_u8(n: u8) -> Self {
+        Self {
+            oct_recommended: (n & 0b0000_0001) != 0,
+            same_ssid: (n & 0b0000_0010) != 0,
+            multiple_bssid: (n & 0b0000_0100) != 0,
+            transmitted_bssid: (n & 0b0000_1000) != 0,
+            member_of_ess_with_2ghz_5ghz_colocated_ap: (n & 0b0001_0000) != 0,
+            unsolicited_probe_responses_active: (n & 0b0010_0000) != 0,
+            colocated_ap: (n & 0b0100_0000) != 0,
+        }
+    }
+}
+
+/// Short SSID of `ssid`, its CRC-32
+#[must_use]
+pub fn short_ssid(ssid: &[u8]) -> u32 {
+    crc32(ssid)
+}
+
+#[test]
+fn test_reduced_neighbor_report() {
+    assert_eq!(short_ssid(b"123456789"), 0xcbf4_3926, "short_ssid");
+
+    let mut reduced_neighbor_report = Vec::new();
+    // one 12 byte TBTT Information field on 6 GHz channel 37
+    reduced_neighbor_report.extend_from_slice(&[0x00, 12, 131, 37, 10]);
+    reduced_neighbor_report.extend_from_slice(&[0x00, 0x11, 0x22, 0xaa, 0xbb, 0xff]);
+    reduced_neighbor_report.extend_from_slice(&short_ssid(b"test").to_le_bytes());
+    reduced_neighbor_report.push(0b0100_0010);
+    // two 1 byte TBTT Information fields on 5 GHz channel 149
+    reduced_neighbor_report.extend_from_slice(&[0x10, 1, 124, 149, 20, 255]);
+
+    let mut tagged_parameters_builder = TaggedParametersBuilder::new();
+    tagged_parameters_builder
+        .add(TagName::ReducedNeighborReport, &reduced_neighbor_report)
+        .unwrap();
+
+    let tagged_parameters = tagged_parameters_builder.build();
+    let neighbor_ap_information = tagged_parameters.reduced_neighbor_report();
+    assert_eq!(neighbor_ap_information.len(), 2, "neighbor_ap_information");
+
+    let tbtt_information = &neighbor_ap_information[0].tbtt_information;
+    assert_eq!(tbtt_information.len(), 1, "6 GHz tbtt_information");
+    assert_eq!(
+        tbtt_information[0].bssid,
+        Some("00:11:22:aa:bb:ff".parse().unwrap()),
+        "bssid"
+    );
+    assert!(tbtt_information[0].matches_ssid(b"test"), "short_ssid");
+    assert!(
+        tbtt_information[0].bss_parameters.unwrap().same_ssid,
+        "same_ssid"
+    );
+    assert_eq!(tbtt_information[0].psd_20mhz, None, "psd_20mhz");
+
+    let tbtt_information = &neighbor_ap_information[1].tbtt_information;
+    assert_eq!(
+        tbtt_information
+            .iter()
+            .map(|tbtt_information| tbtt_information.neighbor_ap_tbtt_offset)
+            .collect::<Vec<_>>(),
+        [20, 255],
+        "5 GHz tbtt_information"
+    );
+    assert_eq!(tbtt_information[0].bssid, None, "bssid");
+
+    let colocated_6ghz_aps = tagged_parameters.colocated_6ghz_aps();
+    assert_eq!(colocated_6ghz_aps.len(), 1, "colocated_6ghz_aps");
+    assert_eq!(colocated_6ghz_aps[0].0.frequency, 6135, "frequency");
+}
@@ -0,0 +1,201 @@
+use super::*;
+
+impl TaggedParameters<'_> {
+    #[must_use]
+    pub fn supported_operating_classes(&self) -> Option<SupportedOperatingClasses> {
+        self.get_bytes(TagName::SupportedOperatingClasses)
+            .and_then(SupportedOperatingClasses::from_bytes)
+    }
+
+    #[must_use]
+    pub fn channel_switch_announcement(&self) -> Option<ChannelSwitchAnnouncement> {
+        self.get_bytes(TagName::ChannelSwitchAnnouncement)
+            .and_then(ChannelSwitchAnnouncement::from_bytes)
+    }
+
+    #[must_use]
+    pub fn extended_channel_switch_announcement(
+        &self,
+    ) -> Option<ExtendedChannelSwitchAnnouncement> {
+        self.get_bytes(TagName::ExtendedChannelSwitchAnnouncement)
+            .and_then(ExtendedChannelSwitchAnnouncement::from_bytes)
+    }
+}
+
+/// Supported Operating Classes element (59), with global operating classes
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SupportedOperatingClasses {
+    pub current_operating_class: u8,
+    /// up to the 130 delimiter or the zero delimiter, the extension and duple
+    /// sequences after them are not decoded
+    pub operating_classes: Vec<u8>,
+}
+
+impl SupportedOperatingClasses {
+    #[must_use]
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let (&current_operating_class, rest) = bytes.split_first()?;
+
+        let operating_classes = rest
+            .iter()
+            .take_while(|&&n| n != 0 && n != 130)
+            .copied()
+            .collect();
+
+        Some(Self {
+            current_operating_class,
+            operating_classes,
+        })
+    }
+
+    #[must_use]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![self.current_operating_class];
+        bytes.extend_from_slice(&self.operating_classes);
+        bytes
+    }
+
+    /// Known global operating classes of the element
+    pub fn classes(&self) -> impl Iterator<Item = &'static OperatingClass> + '_ {
+        self.operating_classes
+            .iter()
+            .filter_map(|number| OperatingClass::global(*number))
+    }
+}
+
+/// Channel Switch Announcement element (37)
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ChannelSwitchAnnouncement {
+    /// no frames may be sent until the switch
+    pub channel_switch_mode: bool,
+    pub new_channel_number: u8,
+    /// beacon intervals until the switch
+    pub channel_switch_count: u8,
+}
+
+impl ChannelSwitchAnnouncement {
+    #[must_use]
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() < 3 {
+            return None;
+        }
+
+        Some(Self {
+            channel_switch_mode: bytes[0] == 1,
+            new_channel_number: bytes[1],
+            channel_switch_count: bytes[2],
+        })
+    }
+
+    /// New 20 MHz channel, the band does not change
+    #[must_use]
+    pub fn channel(&self, band: Band) -> Option<Channel> {
+        Channel::new(band, self.new_channel_number)
+    }
+}
+
+/// Extended Channel Switch Announcement element (60)
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ExtendedChannelSwitchAnnouncement {
+    /// no frames may be sent until the switch
+    pub channel_switch_mode: bool,
+    pub new_operating_class: u8,
+    pub new_channel_number: u8,
+    /// beacon intervals until the switch
+    pub channel_switch_count: u8,
+}
+
+impl ExtendedChannelSwitchAnnouncement {
+    #[must_use]
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() < 4 {
+            return None;
+        }
+
+        Some(Self {
+            channel_switch_mode: bytes[0] == 1,
+            new_operating_class: bytes[1],
+            new_channel_number: bytes[2],
+            channel_switch_count: bytes[3],
+        })
+    }
+
+    /// New channel, `region` is the table of the new operating class, either
+    /// the BSS's country or global
+    #[must_use]
+    pub fn channel(&self, region: Region) -> Option<Channel> {
+        region
+            .operating_class(self.new_operating_class)?
+            .channel(self.new_channel_number)
+    }
+}
+
+#[test]
+fn test_operating_class_elements() {
+    let mut tagged_parameters_builder = TaggedParametersBuilder::new();
+    tagged_parameters_builder
+        .country(&Country {
+            country_string: *b"US",
+            environment: CountryEnvironment::OperatingClassTable(4),
+            triplets: vec![
+                CountryTriplet::Operating {
+                    operating_extension_identifier: 201,
+                    operating_class: 81,
+                    coverage_class: 0,
+                },
+                CountryTriplet::Operating {
+                    operating_extension_identifier: 201,
+                    operating_class: 128,
+                    coverage_class: 0,
+                },
+            ],
+        })
+        .unwrap();
+    tagged_parameters_builder
+        .add(TagName::SupportedOperatingClasses, &[81, 81, 83, 84, 0, 1])
+        .unwrap();
+    tagged_parameters_builder
+        .add(
+            TagName::ExtendedChannelSwitchAnnouncement,
+            &[1, 128, 155, 5],
+        )
+        .unwrap();
+
+    let tagged_parameters = tagged_parameters_builder.build();
+
+    let country = tagged_parameters.country().unwrap();
+    assert_eq!(country.region(), Some(Region::Global), "region");
+    assert_eq!(
+        country
+            .operating_classes()
+            .iter()
+            .map(|operating_class| operating_class.number)
+            .collect::<Vec<_>>(),
+        [81, 128],
+        "country operating_classes"
+    );
+
+    let supported_operating_classes = tagged_parameters.supported_operating_classes().unwrap();
+    assert_eq!(
+        supported_operating_classes,
+        SupportedOperatingClasses {
+            current_operating_class: 81,
+            operating_classes: vec![81, 83, 84],
+        },
+        "supported_operating_classes"
+    );
+    assert_eq!(supported_operating_classes.classes().count(), 3, "classes");
+
+    let extended_channel_switch_announcement = tagged_parameters
+        .extended_channel_switch_announcement()
+        .unwrap();
+    assert_eq!(
+        extended_channel_switch_announcement.channel_switch_count, 5,
+        "channel_switch_count"
+    );
+    let channel = extended_channel_switch_announcement
+        .channel(Region::Global)
+        .unwrap();
+    assert_eq!(channel.number, 149, "new primary channel");
+    assert_eq!(channel.center_frequency, 5775, "new center frequency");
+}
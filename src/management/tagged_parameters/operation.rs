@@ -0,0 +1,354 @@
+use super::*;
+
+/// Element ID Extension of the HE Operation element
+pub const HE_OPERATION_EXTENSION_ID: u8 = 36;
+
+impl TaggedParameters<'_> {
+    /// Body of the first Element ID Extension element (255) with `extension_id`,
+    /// without the extension ID
+    #[must_use]
+    pub fn element_extension(&self, extension_id: u8) -> Option<&[u8]> {
+        self.get_all_bytes(TagName::ElementExtension)
+            .find(|bytes| bytes.first() == Some(&extension_id))
+            .map(|bytes| &bytes[1..])
+    }
+
+    #[must_use]
+    pub fn ht_operation(&self) -> Option<HTOperation> {
+        self.get_bytes(TagName::HTInformation)
+            .and_then(HTOperation::from_bytes)
+    }
+
+    #[must_use]
+    pub fn vht_operation(&self) -> Option<VHTOperation> {
+        self.get_bytes(TagName::VHTOperation)
+            .and_then(VHTOperation::from_bytes)
+    }
+
+    /// 6 GHz Operation Information of the HE Operation element
+    #[must_use]
+    pub fn he_6ghz_operation(&self) -> Option<HE6GHzOperation> {
+        self.element_extension(HE_OPERATION_EXTENSION_ID)
+            .and_then(HE6GHzOperation::from_he_operation)
+    }
+
+    /// Operating channel of the BSS, from the HE 6 GHz Operation Information,
+    /// or the HT and VHT Operation elements, or the DS Parameter element
+    #[must_use]
+    pub fn operating_channel(&self) -> Option<Channel> {
+        if let Some(he_6ghz_operation) = self.he_6ghz_operation() {
+            return he_6ghz_operation.channel();
+        }
+
+        let ht_operation = self.ht_operation();
+        let primary_channel = match ht_operation {
+            Some(ht_operation) => ht_operation.primary_channel,
+            None => *self.get_bytes(TagName::DSParameter)?.first()?,
+        };
+        let band = if primary_channel <= 14 {
+            Band::TwoPointFourGHz
+        } else {
+            Band::FiveGHz
+        };
+        let channel = Channel::new(band, primary_channel)?;
+
+        let ht_operation = match ht_operation {
+            Some(ht_operation) if ht_operation.sta_channel_width => ht_operation,
+            _ => return Some(channel),
+        };
+
+        if let Some(vht_operation) = self.vht_operation() {
+            if let Some(vht_channel) = vht_operation.channel(channel, &ht_operation) {
+                return Some(vht_channel);
+            }
+        }
+
+        match ht_operation.secondary_channel_offset {
+            SecondaryChannelOffset::Above => {
+                channel.with_center(ChannelWidth::MHz40, primary_channel + 2, None)
+            }
+            SecondaryChannelOffset::Below => {
+                channel.with_center(ChannelWidth::MHz40, primary_channel.checked_sub(2)?, None)
+            }
+            _ => Some(channel),
+        }
+    }
+}
+
+/// HT Operation element (61)
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct HTOperation {
+    pub primary_channel: u8,
+    pub secondary_channel_offset: SecondaryChannelOffset,
+    /// channels wider than 20 MHz may be used
+    pub sta_channel_width: bool,
+    pub rifs_mode: bool,
+    /// 0: no protection, 1: nonmember protection, 2: 20 MHz protection,
+    /// 3: non-HT mixed
+    pub ht_protection: u8,
+    pub nongreenfield_stas_present: bool,
+    pub obss_non_ht_stas_present: bool,
+    /// center channel of 160 and 80+80 MHz channels, for stations that only
+    /// support them with reduced NSS
+    pub channel_center_frequency_segment_2: u8,
+}
+
+impl HTOperation {
+    #[must_use]
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        // primary channel, HT Operation Information
+        if bytes.len() < 6 {
+            return None;
+        }
+
+        Some(Self {
+            primary_channel: bytes[0],
+            secondary_channel_offset: SecondaryChannelOffset::from_u8(bytes[1] & 0b0000_0011),
+            sta_channel_width: (bytes[1] & 0b0000_0100) != 0,
+            rifs_mode: (bytes[1] & 0b0000_1000) != 0,
+            ht_protection: bytes[2] & 0b0000_0011,
+            nongreenfield_stas_present: (bytes[2] & 0b0000_0100) != 0,
+            obss_non_ht_stas_present: (bytes[2] & 0b0001_0000) != 0,
+            channel_center_frequency_segment_2: (bytes[2] >> 5) | (bytes[3] << 3),
+        })
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum SecondaryChannelOffset {
+    /// no secondary channel
+    None, // 0
+    Above, // 1
+    Below, // 3
+    Reserved(u8),
+}
+
+impl SecondaryChannelOffset {
+    #[must_use]
+    pub fn from_u8(n: u8) -> Self {
+        match n {
+            0 => Self::None,
+            1 => Self::Above,
+            3 => Self::Below,
+            other => Self::Reserved(other),
+        }
+    }
+
+    #[must_use]
+    pub fn into_u8(self) -> u8 {
+        match self {
+            Self::None => 0,
+            Self::Above => 1,
+            Self::Below => 3,
+            Self::Reserved(other) => other,
+        }
+    }
+}
+
+/// VHT Operation element (192)
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct VHTOperation {
+    /// 0: 20 or 40 MHz, 1: 80, 160 or 80+80 MHz, 2: 160 MHz (deprecated),
+    /// 3: 80+80 MHz (deprecated)
+    pub channel_width: u8,
+    pub channel_center_frequency_segment_0: u8,
+    pub channel_center_frequency_segment_1: u8,
+    pub basic_vht_mcs_and_nss_set: u16,
+}
+
+impl VHTOperation {
+    #[must_use]
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() < 5 {
+            return None;
+        }
+
+        Some(Self {
+            channel_width: bytes[0],
+            channel_center_frequency_segment_0: bytes[1],
+            channel_center_frequency_segment_1: bytes[2],
+            basic_vht_mcs_and_nss_set: LittleEndian::read_u16(&bytes[3..5]),
+        })
+    }
+
+    /// 80 MHz or wider channel around `primary`, None for 20 and 40 MHz
+    #[must_use]
+    pub fn channel(&self, primary: Channel, ht_operation: &HTOperation) -> Option<Channel> {
+        let ccfs0 = self.channel_center_frequency_segment_0;
+        // CCFS1 moved to the HT Operation element for reduced NSS support
+        let ccfs1 = match self.channel_center_frequency_segment_1 {
+            0 => ht_operation.channel_center_frequency_segment_2,
+            n => n,
+        };
+
+        match self.channel_width {
+            1 if ccfs1 == 0 => primary.with_center(ChannelWidth::MHz80, ccfs0, None),
+            1 if ccfs1.abs_diff(ccfs0) == 8 => {
+                primary.with_center(ChannelWidth::MHz160, ccfs1, None)
+            }
+            1 if ccfs1.abs_diff(ccfs0) > 16 => {
+                primary.with_center(ChannelWidth::MHz80Plus80, ccfs0, Some(ccfs1))
+            }
+            2 => primary.with_center(ChannelWidth::MHz160, ccfs0, None),
+            3 => primary.with_center(ChannelWidth::MHz80Plus80, ccfs0, Some(ccfs1)),
+            _ => None,
+        }
+    }
+}
+
+/// 6 GHz Operation Information field of the HE Operation element
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct HE6GHzOperation {
+    pub primary_channel: u8,
+    /// 0: 20 MHz, 1: 40 MHz, 2: 80 MHz, 3: 160 or 80+80 MHz
+    pub channel_width: u8,
+    /// beacons are duplicated in every 20 MHz subchannel
+    pub duplicate_beacon: bool,
+    pub regulatory_info: u8,
+    pub channel_center_frequency_segment_0: u8,
+    pub channel_center_frequency_segment_1: u8,
+    /// in units of 1 Mbps
+    pub minimum_rate: u8,
+}
+
+impl HE6GHzOperation {
+    /// Parses the HE Operation element body following the extension ID
+    #[must_use]
+    pub fn from_he_operation(bytes: &[u8]) -> Option<Self> {
+        // HE Operation Parameters, BSS Color Information, Basic HE-MCS And
+        // NSS Set
+        if bytes.len() < 6 {
+            return None;
+        }
+        let vht_operation_information_present = (bytes[1] & 0b0100_0000) != 0;
+        let co_hosted_bss = (bytes[1] & 0b1000_0000) != 0;
+        let six_ghz_operation_information_present = (bytes[2] & 0b0000_0010) != 0;
+        if !six_ghz_operation_information_present {
+            return None;
+        }
+
+        let mut i = 6;
+        if vht_operation_information_present {
+            i += 3;
+        }
+        if co_hosted_bss {
+            i += 1;
+        }
+
+        Self::from_bytes(bytes.get(i..(i + 5))?)
+    }
+
+    #[must_use]
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() < 5 {
+            return None;
+        }
+
+        Some(Self {
+            primary_channel: bytes[0],
+            channel_width: bytes[1] & 0b0000_0011,
+            duplicate_beacon: (bytes[1] & 0b0000_0100) != 0,
+            regulatory_info: (bytes[1] & 0b0011_1000) >> 3,
+            channel_center_frequency_segment_0: bytes[2],
+            channel_center_frequency_segment_1: bytes[3],
+            minimum_rate: bytes[4],
+        })
+    }
+
+    #[must_use]
+    pub fn channel(&self) -> Option<Channel> {
+        let primary = Channel::new(Band::SixGHz, self.primary_channel)?;
+        let ccfs0 = self.channel_center_frequency_segment_0;
+        let ccfs1 = self.channel_center_frequency_segment_1;
+
+        match self.channel_width {
+            0 => Some(primary),
+            1 => primary.with_center(ChannelWidth::MHz40, ccfs0, None),
+            2 => primary.with_center(ChannelWidth::MHz80, ccfs0, None),
+            _ if ccfs1.abs_diff(ccfs0) == 8 => {
+                primary.with_center(ChannelWidth::MHz160, ccfs1, None)
+            }
+            _ => primary.with_center(ChannelWidth::MHz80Plus80, ccfs0, Some(ccfs1)),
+        }
+    }
+}
+
+#[test]
+fn test_operating_channel() {
+    // DS Parameter only
+    let mut tagged_parameters_builder = TaggedParametersBuilder::new();
+    tagged_parameters_builder
+        .add(TagName::DSParameter, &[11])
+        .unwrap();
+    let channel = tagged_parameters_builder
+        .build()
+        .operating_channel()
+        .unwrap();
+    assert_eq!(channel.band, Band::TwoPointFourGHz, "ds band");
+    assert_eq!(channel.frequency, 2462, "ds frequency");
+    assert_eq!(channel.width, ChannelWidth::MHz20, "ds width");
+
+    // HT 40 MHz below, VHT 160 MHz
+    let mut tagged_parameters_builder = TaggedParametersBuilder::new();
+    tagged_parameters_builder
+        .add(TagName::DSParameter, &[44])
+        .unwrap();
+    let mut ht_operation = [0u8; 22];
+    ht_operation[0] = 44;
+    ht_operation[1] = 0b0000_0111;
+    tagged_parameters_builder
+        .add(TagName::HTInformation, &ht_operation)
+        .unwrap();
+
+    let tagged_parameters = tagged_parameters_builder.build();
+    let channel = tagged_parameters.operating_channel().unwrap();
+    assert_eq!(channel.width, ChannelWidth::MHz40, "ht width");
+    assert_eq!(channel.center_number(), Some(42), "ht center");
+
+    tagged_parameters_builder
+        .add(TagName::VHTOperation, &[1, 42, 50, 0xfc, 0xff])
+        .unwrap();
+    let tagged_parameters = tagged_parameters_builder.build();
+    assert_eq!(
+        tagged_parameters.vht_operation(),
+        Some(VHTOperation {
+            channel_width: 1,
+            channel_center_frequency_segment_0: 42,
+            channel_center_frequency_segment_1: 50,
+            basic_vht_mcs_and_nss_set: 0xfffc,
+        }),
+        "vht_operation"
+    );
+    let channel = tagged_parameters.operating_channel().unwrap();
+    assert_eq!(channel.number, 44, "vht primary");
+    assert_eq!(channel.width, ChannelWidth::MHz160, "vht width");
+    assert_eq!(channel.center_number(), Some(50), "vht center");
+
+    // HE Operation with 6 GHz Operation Information, 80 MHz
+    let mut tagged_parameters_builder = TaggedParametersBuilder::new();
+    tagged_parameters_builder
+        .add(
+            TagName::ElementExtension,
+            &[
+                36,
+                0x00,
+                0x00,
+                0x02,
+                0x01,
+                0xfc,
+                0xff,
+                37,
+                0b0000_0010,
+                39,
+                0,
+                6,
+            ],
+        )
+        .unwrap();
+    let tagged_parameters = tagged_parameters_builder.build();
+    let channel = tagged_parameters.operating_channel().unwrap();
+    assert_eq!(channel.band, Band::SixGHz, "he band");
+    assert_eq!(channel.frequency, 6135, "he frequency");
+    assert_eq!(channel.width, ChannelWidth::MHz80, "he width");
+    assert_eq!(channel.center_frequency, 6145, "he center");
+}
@@ -0,0 +1,556 @@
+use super::*;
+use byteorder::{BigEndian, ByteOrder, LittleEndian};
+
+/// OUI type of the Wi-Fi Direct (P2P) vendor specific element
+pub const P2P_OUI_TYPE: u8 = 9;
+
+impl TaggedParameters<'_> {
+    /// Wi-Fi Direct (P2P) attributes
+    ///
+    /// P2P data longer than one element is split across consecutive P2P
+    /// elements, their payloads are joined before decoding.
+    #[must_use]
+    pub fn p2p(&self) -> Option<P2P> {
+        let mut bytes = Vec::new();
+        let mut found = false;
+
+        for vendor_specific in self.vendor_specific().filter(VendorSpecific::is::<P2P>) {
+            bytes.extend_from_slice(&vendor_specific.payload()[1..]);
+            found = true;
+        }
+
+        if !found {
+            return None;
+        }
+
+        Some(P2P::from_bytes(&bytes))
+    }
+}
+
+impl<'a> VendorSpecificElement<'a> for P2P {
+    const OUI: [u8; 3] = WIFI_ALLIANCE_OUI;
+    const OUI_TYPE: Option<u8> = Some(P2P_OUI_TYPE);
+
+    fn decode(payload: &'a [u8]) -> Option<Self> {
+        Some(Self::from_bytes(payload))
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct P2P {
+    /// in the order they appear, a truncated last attribute is dropped
+    pub attributes: Vec<P2PAttribute>,
+}
+
+impl P2P {
+    /// Parses 8-bit id, little-endian 16-bit length attributes
+    #[must_use]
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        let mut attributes = Vec::new();
+
+        let mut i = 0;
+        let len = bytes.len();
+        while (i + 3) <= len {
+            let attribute_id = bytes[i];
+            let attribute_length = LittleEndian::read_u16(&bytes[(i + 1)..(i + 3)]) as usize;
+            i += 3;
+
+            if (i + attribute_length) > len {
+                break;
+            }
+            attributes.push(P2PAttribute::from(
+                attribute_id,
+                &bytes[i..(i + attribute_length)],
+            ));
+            i += attribute_length;
+        }
+
+        Self { attributes }
+    }
+
+    #[must_use]
+    pub fn capability(&self) -> Option<P2PCapability> {
+        self.attributes
+            .iter()
+            .find_map(|attribute| match attribute {
+                P2PAttribute::Capability(capability) => Some(*capability),
+                _ => None,
+            })
+    }
+
+    #[must_use]
+    pub fn device_id(&self) -> Option<MacAddress> {
+        self.attributes
+            .iter()
+            .find_map(|attribute| match attribute {
+                P2PAttribute::DeviceID(device_id) => Some(*device_id),
+                _ => None,
+            })
+    }
+
+    #[must_use]
+    pub fn device_info(&self) -> Option<&P2PDeviceInfo> {
+        self.attributes
+            .iter()
+            .find_map(|attribute| match attribute {
+                P2PAttribute::DeviceInfo(device_info) => Some(device_info),
+                _ => None,
+            })
+    }
+
+    #[must_use]
+    pub fn group_owner_intent(&self) -> Option<P2PGroupOwnerIntent> {
+        self.attributes
+            .iter()
+            .find_map(|attribute| match attribute {
+                P2PAttribute::GroupOwnerIntent(group_owner_intent) => Some(*group_owner_intent),
+                _ => None,
+            })
+    }
+
+    #[must_use]
+    pub fn listen_channel(&self) -> Option<P2PChannel> {
+        self.attributes
+            .iter()
+            .find_map(|attribute| match attribute {
+                P2PAttribute::ListenChannel(listen_channel) => Some(*listen_channel),
+                _ => None,
+            })
+    }
+
+    #[must_use]
+    pub fn operating_channel(&self) -> Option<P2PChannel> {
+        self.attributes
+            .iter()
+            .find_map(|attribute| match attribute {
+                P2PAttribute::OperatingChannel(operating_channel) => Some(*operating_channel),
+                _ => None,
+            })
+    }
+
+    #[must_use]
+    pub fn group_info(&self) -> Option<&[P2PClientInfo]> {
+        self.attributes
+            .iter()
+            .find_map(|attribute| match attribute {
+                P2PAttribute::GroupInfo(group_info) => Some(group_info.as_ref()),
+                _ => None,
+            })
+    }
+
+    #[must_use]
+    pub fn notice_of_absence(&self) -> Option<&P2PNoticeOfAbsence> {
+        self.attributes
+            .iter()
+            .find_map(|attribute| match attribute {
+                P2PAttribute::NoticeOfAbsence(notice_of_absence) => Some(notice_of_absence),
+                _ => None,
+            })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum P2PAttribute {
+    /// 0
+    Status(u8),
+    /// 1
+    MinorReasonCode(u8),
+    /// 2
+    Capability(P2PCapability),
+    /// 3
+    DeviceID(MacAddress),
+    /// 4
+    GroupOwnerIntent(P2PGroupOwnerIntent),
+    /// 5, in units of 10 milliseconds
+    ConfigurationTimeout { group_owner: u8, client: u8 },
+    /// 6
+    ListenChannel(P2PChannel),
+    /// 7
+    GroupBSSID(MacAddress),
+    /// 8, in milliseconds
+    ExtendedListenTiming {
+        availability_period: u16,
+        availability_interval: u16,
+    },
+    /// 9
+    IntendedInterfaceAddress(MacAddress),
+    /// 10
+    Manageability(u8),
+    /// 11
+    ChannelList(P2PChannelList),
+    /// 12
+    NoticeOfAbsence(P2PNoticeOfAbsence),
+    /// 13
+    DeviceInfo(P2PDeviceInfo),
+    /// 14
+    GroupInfo(Vec<P2PClientInfo>),
+    /// 15
+    GroupID {
+        device_address: MacAddress,
+        ssid: Vec<u8>,
+    },
+    /// 16
+    Interface {
+        device_address: MacAddress,
+        interface_addresses: Vec<MacAddress>,
+    },
+    /// 17
+    OperatingChannel(P2PChannel),
+    /// 18
+    InvitationFlags(u8),
+    /// unknown attributes, or known attributes that failed to decode
+    Other(u8, Vec<u8>),
+}
+
+impl P2PAttribute {
+    #[must_use]
+    pub fn from(attribute_id: u8, data: &[u8]) -> Self {
+        Self::decode(attribute_id, data).unwrap_or_else(|| Self::Other(attribute_id, data.to_vec()))
+    }
+
+    fn decode(attribute_id: u8, data: &[u8]) -> Option<Self> {
+        Some(match (attribute_id, data.len()) {
+            (0, 1) => Self::Status(data[0]),
+            (1, 1) => Self::MinorReasonCode(data[0]),
+            (2, 2) => Self::Capability(P2PCapability {
+                device_capability: P2PDeviceCapability::from_u8(data[0]),
+                group_capability: P2PGroupCapability::from_u8(data[1]),
+            }),
+            (3, 6) => Self::DeviceID(read_mac_address(data)),
+            (4, 1) => Self::GroupOwnerIntent(P2PGroupOwnerIntent::from_u8(data[0])),
+            (5, 2) => Self::ConfigurationTimeout {
+                group_owner: data[0],
+                client: data[1],
+            },
+            (6, 5) => Self::ListenChannel(P2PChannel::from_bytes(data)),
+            (7, 6) => Self::GroupBSSID(read_mac_address(data)),
+            (8, 4) => Self::ExtendedListenTiming {
+                availability_period: LittleEndian::read_u16(&data[0..2]),
+                availability_interval: LittleEndian::read_u16(&data[2..4]),
+            },
+            (9, 6) => Self::IntendedInterfaceAddress(read_mac_address(data)),
+            (10, 1) => Self::Manageability(data[0]),
+            (11, _) => Self::ChannelList(P2PChannelList::from_bytes(data)?),
+            (12, _) => Self::NoticeOfAbsence(P2PNoticeOfAbsence::from_bytes(data)?),
+            (13, _) => Self::DeviceInfo(P2PDeviceInfo::from_bytes(data)?),
+            (14, _) => {
+                let mut group_info = Vec::new();
+                let mut i = 0;
+                while i < data.len() {
+                    let length = data[i] as usize;
+                    i += 1;
+                    if (i + length) > data.len() {
+                        return None;
+                    }
+                    group_info.push(P2PClientInfo::from_bytes(&data[i..(i + length)])?);
+                    i += length;
+                }
+                Self::GroupInfo(group_info)
+            }
+            (15, n) if (6..=38).contains(&n) => Self::GroupID {
+                device_address: read_mac_address(&data[0..6]),
+                ssid: data[6..].to_vec(),
+            },
+            (16, n) if n >= 7 => {
+                let count = data[6] as usize;
+                if n < 7 + count * 6 {
+                    return None;
+                }
+                Self::Interface {
+                    device_address: read_mac_address(&data[0..6]),
+                    interface_addresses: data[7..(7 + count * 6)]
+                        .chunks(6)
+                        .map(read_mac_address)
+                        .collect(),
+                }
+            }
+            (17, 5) => Self::OperatingChannel(P2PChannel::from_bytes(data)),
+            (18, 1) => Self::InvitationFlags(data[0]),
+            _ => return None,
+        })
+    }
+}
+
+fn read_mac_address(bytes: &[u8]) -> MacAddress {
+    MacAddress::from_bytes(&bytes[0..6]).unwrap()
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct P2PCapability {
+    pub device_capability: P2PDeviceCapability,
+    pub group_capability: P2PGroupCapability,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct P2PDeviceCapability {
+    pub service_discovery: bool,
+    pub client_discoverability: bool,
+    pub concurrent_operation: bool,
+    pub infrastructure_managed: bool,
+    pub device_limit: bool,
+    pub invitation_procedure: bool,
+}
+
+impl P2PDeviceCapability {
+    #[must_use]
+    pub fn from_u8(b: u8) -> Self {
+        Self {
+            service_discovery: (b & 0b0000_0001) != 0,
+            client_discoverability: (b & 0b0000_0010) != 0,
+            concurrent_operation: (b & 0b0000_0100) != 0,
+            infrastructure_managed: (b & 0b0000_1000) != 0,
+            device_limit: (b & 0b0001_0000) != 0,
+            invitation_procedure: (b & 0b0010_0000) != 0,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct P2PGroupCapability {
+    pub group_owner: bool,
+    pub persistent_group: bool,
+    pub group_limit: bool,
+    pub intra_bss_distribution: bool,
+    pub cross_connection: bool,
+    pub persistent_reconnect: bool,
+    pub group_formation: bool,
+    pub ip_address_allocation: bool,
+}
+
+impl P2PGroupCapability {
+    #[must_use]
+    pub fn from_u8(b: u8) -> Self {
+        Self {
+            group_owner: (b & 0b0000_0001) != 0,
+            persistent_group: (b & 0b0000_0010) != 0,
+            group_limit: (b & 0b0000_0100) != 0,
+            intra_bss_distribution: (b & 0b0000_1000) != 0,
+            cross_connection: (b & 0b0001_0000) != 0,
+            persistent_reconnect: (b & 0b0010_0000) != 0,
+            group_formation: (b & 0b0100_0000) != 0,
+            ip_address_allocation: (b & 0b1000_0000) != 0,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct P2PGroupOwnerIntent {
+    /// 0-15, 15 means the device has to be the group owner
+    pub intent: u8,
+    pub tie_breaker: bool,
+}
+
+impl P2PGroupOwnerIntent {
+    #[must_use]
+    pub fn from_u8(b: u8) -> Self {
+        Self {
+            intent: b >> 1,
+            tie_breaker: (b & 0b0000_0001) != 0,
+        }
+    }
+}
+
+/// Listen Channel and Operating Channel attributes
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct P2PChannel {
+    pub country_string: [u8; 3],
+    pub operating_class: u8,
+    pub channel: u8,
+}
+
+impl P2PChannel {
+    fn from_bytes(bytes: &[u8]) -> Self {
+        let mut country_string = [0; 3];
+        country_string.copy_from_slice(&bytes[0..3]);
+
+        Self {
+            country_string,
+            operating_class: bytes[3],
+            channel: bytes[4],
+        }
+    }
+
+    /// Channel from the global operating class
+    #[must_use]
+    pub fn to_channel(&self) -> Option<Channel> {
+        Channel::from_operating_class(self.operating_class, self.channel)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct P2PChannelList {
+    pub country_string: [u8; 3],
+    /// operating class and its channels
+    pub entries: Vec<(u8, Vec<u8>)>,
+}
+
+impl P2PChannelList {
+    fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() < 3 {
+            return None;
+        }
+        let mut country_string = [0; 3];
+        country_string.copy_from_slice(&bytes[0..3]);
+
+        let mut entries = Vec::new();
+        let mut i = 3;
+        while (i + 2) <= bytes.len() {
+            let operating_class = bytes[i];
+            let count = bytes[i + 1] as usize;
+            i += 2;
+            if (i + count) > bytes.len() {
+                return None;
+            }
+            entries.push((operating_class, bytes[i..(i + count)].to_vec()));
+            i += count;
+        }
+
+        Some(Self {
+            country_string,
+            entries,
+        })
+    }
+
+    /// Channels of the known global operating classes
+    #[must_use]
+    pub fn channels(&self) -> Vec<Channel> {
+        self.entries
+            .iter()
+            .flat_map(|(operating_class, channels)| {
+                channels.iter().filter_map(move |channel| {
+                    Channel::from_operating_class(*operating_class, *channel)
+                })
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct P2PNoticeOfAbsence {
+    pub index: u8,
+    /// in units of TU
+    pub ct_window: u8,
+    pub opportunistic_power_save: bool,
+    pub descriptors: Vec<P2PNoticeOfAbsenceDescriptor>,
+}
+
+impl P2PNoticeOfAbsence {
+    fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() < 2 || (bytes.len() - 2) % 13 != 0 {
+            return None;
+        }
+
+        Some(Self {
+            index: bytes[0],
+            ct_window: bytes[1] & 0b0111_1111,
+            opportunistic_power_save: (bytes[1] & 0b1000_0000) != 0,
+            descriptors: bytes[2..]
+                .chunks(13)
+                .map(|descriptor| P2PNoticeOfAbsenceDescriptor {
+                    count_type: descriptor[0],
+                    duration: LittleEndian::read_u32(&descriptor[1..5]),
+                    interval: LittleEndian::read_u32(&descriptor[5..9]),
+                    start_time: LittleEndian::read_u32(&descriptor[9..13]),
+                })
+                .collect(),
+        })
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct P2PNoticeOfAbsenceDescriptor {
+    /// number of absence intervals, 255 for continuous
+    pub count_type: u8,
+    /// in microseconds
+    pub duration: u32,
+    /// in microseconds
+    pub interval: u32,
+    /// lower 4 bytes of the TSF timer
+    pub start_time: u32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct P2PDeviceInfo {
+    pub device_address: MacAddress,
+    pub config_methods: WPSConfigMethods,
+    pub primary_device_type: WPSPrimaryDeviceType,
+    pub secondary_device_types: Vec<WPSPrimaryDeviceType>,
+    pub device_name: Vec<u8>,
+}
+
+impl P2PDeviceInfo {
+    fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let mut i = 0;
+        let len = bytes.len();
+
+        // device address, config methods, primary device type, secondary count
+        if (i + 17) > len {
+            return None;
+        }
+        let device_address = read_mac_address(&bytes[i..(i + 6)]);
+        i += 6;
+        let config_methods = WPSConfigMethods::from_u16(BigEndian::read_u16(&bytes[i..(i + 2)]));
+        i += 2;
+        let primary_device_type = WPSPrimaryDeviceType::from_bytes(&bytes[i..(i + 8)]);
+        i += 8;
+        let secondary_device_type_count = bytes[i] as usize;
+        i += 1;
+
+        if (i + secondary_device_type_count * 8) > len {
+            return None;
+        }
+        let secondary_device_types = bytes[i..(i + secondary_device_type_count * 8)]
+            .chunks(8)
+            .map(WPSPrimaryDeviceType::from_bytes)
+            .collect();
+        i += secondary_device_type_count * 8;
+
+        // device name is a WPS attribute
+        if (i + 4) > len {
+            return None;
+        }
+        let device_name_length = BigEndian::read_u16(&bytes[(i + 2)..(i + 4)]) as usize;
+        i += 4;
+        if (i + device_name_length) > len {
+            return None;
+        }
+        let device_name = bytes[i..(i + device_name_length)].to_vec();
+
+        Some(Self {
+            device_address,
+            config_methods,
+            primary_device_type,
+            secondary_device_types,
+            device_name,
+        })
+    }
+}
+
+/// P2P Client Info Descriptor from the Group Info attribute
+#[derive(Debug, Clone, PartialEq)]
+pub struct P2PClientInfo {
+    pub interface_address: MacAddress,
+    pub device_capability: P2PDeviceCapability,
+    pub device_info: P2PDeviceInfo,
+}
+
+impl P2PClientInfo {
+    fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        // device address, interface address, device capability
+        if bytes.len() < 13 {
+            return None;
+        }
+
+        // the device capability sits between the addresses and the rest of
+        // the device info
+        let mut device_info_bytes = bytes[0..6].to_vec();
+        device_info_bytes.extend_from_slice(&bytes[13..]);
+        let device_info = P2PDeviceInfo::from_bytes(&device_info_bytes)?;
+
+        Some(Self {
+            interface_address: read_mac_address(&bytes[6..12]),
+            device_capability: P2PDeviceCapability::from_u8(bytes[12]),
+            device_info,
+        })
+    }
+}
@@ -0,0 +1,127 @@
+use super::*;
+
+impl TaggedParameters<'_> {
+    /// Supported Rates followed by Extended Supported Rates
+    pub fn rates(&self) -> impl Iterator<Item = Rate> + '_ {
+        self.get_bytes(TagName::SupportedRates)
+            .into_iter()
+            .chain(self.get_bytes(TagName::ExtendedSupportedRates))
+            .flatten()
+            .map(|&b| Rate::from_u8(b))
+    }
+
+    /// Rates every station has to support to join, without membership
+    /// selectors
+    #[must_use]
+    pub fn basic_rates(&self) -> Vec<Rate> {
+        self.rates()
+            .filter(|rate| matches!(rate, Rate::Rate { is_basic: true, .. }))
+            .collect()
+    }
+
+    /// BSS membership selectors, features every station has to support to join
+    #[must_use]
+    pub fn bss_membership_selectors(&self) -> Vec<Rate> {
+        self.rates().filter(Rate::is_selector).collect()
+    }
+}
+
+/// Entry of a Supported Rates or Extended Supported Rates element
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Rate {
+    Rate {
+        kbps: u32,
+        /// part of the BSS basic rate set
+        is_basic: bool,
+    },
+    /// HT PHY membership selector
+    HTPhy, // 127
+    /// VHT PHY membership selector
+    VHTPhy, // 126
+    /// GLK membership selector
+    GLK, // 125
+    /// EPD membership selector
+    EPD, // 124
+    /// SAE Hash to Element Only membership selector
+    SAEHashToElementOnly, // 123
+    /// HE PHY membership selector
+    HEPhy, // 122
+}
+
+impl Rate {
+    #[must_use]
+    pub fn from_u8(b: u8) -> Self {
+        let is_basic = (b & 0b1000_0000) != 0;
+        let value = b & 0b0111_1111;
+
+        // selectors are only valid with the basic bit set
+        match (is_basic, value) {
+            (true, 127) => Self::HTPhy,
+            (true, 126) => Self::VHTPhy,
+            (true, 125) => Self::GLK,
+            (true, 124) => Self::EPD,
+            (true, 123) => Self::SAEHashToElementOnly,
+            (true, 122) => Self::HEPhy,
+            _ => Self::Rate {
+                kbps: u32::from(value) * 500,
+                is_basic,
+            },
+        }
+    }
+
+    /// None if `kbps` is not a multiple of 500 kbps up to 63.5 Mbps, or is a
+    /// basic rate that would read back as a membership selector
+    #[must_use]
+    pub fn into_u8(self) -> Option<u8> {
+        match self {
+            Self::Rate { kbps, is_basic } => {
+                let value = u8::try_from(kbps / 500).ok()?;
+                if kbps % 500 != 0 || value > 0b0111_1111 {
+                    return None;
+                }
+                let b = value | if is_basic { 0b1000_0000 } else { 0 };
+                (Self::from_u8(b) == self).then_some(b)
+            }
+            Self::HTPhy => Some(0b1000_0000 | 127),
+            Self::VHTPhy => Some(0b1000_0000 | 126),
+            Self::GLK => Some(0b1000_0000 | 125),
+            Self::EPD => Some(0b1000_0000 | 124),
+            Self::SAEHashToElementOnly => Some(0b1000_0000 | 123),
+            Self::HEPhy => Some(0b1000_0000 | 122),
+        }
+    }
+
+    #[must_use]
+    pub fn new(kbps: u32, is_basic: bool) -> Self {
+        Self::Rate { kbps, is_basic }
+    }
+
+    /// None for membership selectors
+    #[must_use]
+    pub fn kbps(&self) -> Option<u32> {
+        match self {
+            Self::Rate { kbps, .. } => Some(*kbps),
+            _ => None,
+        }
+    }
+
+    /// in Mbit/sec, None for membership selectors
+    #[must_use]
+    pub fn mbps(&self) -> Option<f64> {
+        self.kbps().map(|kbps| f64::from(kbps) / 1000.0)
+    }
+
+    /// Membership selectors are always basic
+    #[must_use]
+    pub fn is_basic(&self) -> bool {
+        match self {
+            Self::Rate { is_basic, .. } => *is_basic,
+            _ => true,
+        }
+    }
+
+    #[must_use]
+    pub fn is_selector(&self) -> bool {
+        !matches!(self, Self::Rate { .. })
+    }
+}
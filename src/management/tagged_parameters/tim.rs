@@ -0,0 +1,141 @@
+use super::*;
+
+/// Highest Association ID
+const MAX_AID: u16 = 2007;
+
+/// Length of the full virtual bitmap, one bit per AID
+const VIRTUAL_BITMAP_LENGTH: usize = 251;
+
+impl TaggedParameters<'_> {
+    #[must_use]
+    pub fn traffic_indication_map(&self) -> Option<TrafficIndicationMap> {
+        self.get_bytes(TagName::TrafficIndicationMap)
+            .and_then(TrafficIndicationMap::from_bytes)
+    }
+}
+
+/// Traffic Indication Map element (5)
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TrafficIndicationMap {
+    /// beacons until the next DTIM, 0 means this beacon is a DTIM
+    pub dtim_count: u8,
+    /// beacon intervals between DTIMs
+    pub dtim_period: u8,
+    /// group addressed frames are buffered at the AP, only set in DTIMs
+    pub multicast: bool,
+    /// index of the first octet of the virtual bitmap in
+    /// `partial_virtual_bitmap`, always even
+    pub bitmap_offset: u8,
+    pub partial_virtual_bitmap: Vec<u8>,
+}
+
+impl TrafficIndicationMap {
+    /// An empty TIM, with no buffered traffic
+    #[must_use]
+    pub fn new(dtim_count: u8, dtim_period: u8) -> Self {
+        Self {
+            dtim_count,
+            dtim_period,
+            multicast: false,
+            bitmap_offset: 0,
+            partial_virtual_bitmap: vec![0],
+        }
+    }
+
+    #[must_use]
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        // DTIM count, DTIM period, bitmap control, at least one bitmap octet
+        if bytes.len() < 4 {
+            return None;
+        }
+
+        Some(Self {
+            dtim_count: bytes[0],
+            dtim_period: bytes[1],
+            multicast: (bytes[2] & 0b0000_0001) != 0,
+            bitmap_offset: bytes[2] & 0b1111_1110,
+            partial_virtual_bitmap: bytes[3..].to_vec(),
+        })
+    }
+
+    /// Element body
+    #[must_use]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![
+            self.dtim_count,
+            self.dtim_period,
+            (self.bitmap_offset & 0b1111_1110) | u8::from(self.multicast),
+        ];
+        bytes.extend_from_slice(&self.partial_virtual_bitmap);
+        bytes
+    }
+
+    #[must_use]
+    pub fn is_dtim(&self) -> bool {
+        self.dtim_count == 0
+    }
+
+    /// Whether the AP has frames buffered for the station with `aid`
+    #[must_use]
+    pub fn has_buffered_traffic(&self, aid: u16) -> bool {
+        if aid == 0 || aid > MAX_AID {
+            return false;
+        }
+
+        let octet = (aid / 8) as usize;
+        let offset = self.bitmap_offset as usize;
+        if octet < offset {
+            return false;
+        }
+
+        self.partial_virtual_bitmap
+            .get(octet - offset)
+            .is_some_and(|b| (b & (1 << (aid % 8))) != 0)
+    }
+
+    /// Every AID with buffered frames, in order
+    #[must_use]
+    pub fn buffered_aids(&self) -> Vec<u16> {
+        (1..=MAX_AID)
+            .filter(|&aid| self.has_buffered_traffic(aid))
+            .collect()
+    }
+
+    /// Sets or clears the buffered traffic bit for `aid`, keeping the partial
+    /// virtual bitmap as short as possible
+    ///
+    /// Panics if `aid` is not between 1 and 2007.
+    pub fn set_buffered_traffic(&mut self, aid: u16, buffered: bool) {
+        assert!((1..=MAX_AID).contains(&aid), "invalid aid");
+
+        let mut virtual_bitmap = [0u8; VIRTUAL_BITMAP_LENGTH];
+        let offset = self.bitmap_offset as usize;
+        for (i, b) in self.partial_virtual_bitmap.iter().enumerate() {
+            if let Some(octet) = virtual_bitmap.get_mut(offset + i) {
+                *octet = *b;
+            }
+        }
+
+        let octet = (aid / 8) as usize;
+        if buffered {
+            virtual_bitmap[octet] |= 1 << (aid % 8);
+        } else {
+            virtual_bitmap[octet] &= !(1 << (aid % 8));
+        }
+
+        let first = virtual_bitmap.iter().position(|&b| b != 0);
+        let last = virtual_bitmap.iter().rposition(|&b| b != 0);
+        match (first, last) {
+            (Some(first), Some(last)) => {
+                // N1 is the largest even number with no bits set before it
+                let first = first & !1;
+                self.bitmap_offset = first as u8;
+                self.partial_virtual_bitmap = virtual_bitmap[first..=last].to_vec();
+            }
+            _ => {
+                self.bitmap_offset = 0;
+                self.partial_virtual_bitmap = vec![0];
+            }
+        }
+    }
+}
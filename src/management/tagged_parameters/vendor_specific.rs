@@ -0,0 +1,266 @@
+use super::*;
+
+/// Wi-Fi Alliance OUI, used by P2P, Hotspot 2.0, OWE Transition Mode and MBO-OCE
+pub const WIFI_ALLIANCE_OUI: [u8; 3] = [0x50, 0x6f, 0x9a];
+
+/// Vendor Specific element (221)
+///
+/// Starts with the OUI of the vendor, most vendors follow it with an OUI type
+/// and sometimes an OUI subtype.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct VendorSpecific<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> VendorSpecific<'a> {
+    /// `bytes` is the element body, returns None if it is too short to contain an OUI
+    #[must_use]
+    pub fn new(bytes: &'a [u8]) -> Option<Self> {
+        if bytes.len() < 3 {
+            return None;
+        }
+
+        Some(Self { bytes })
+    }
+
+    #[must_use]
+    pub fn bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    #[must_use]
+    pub fn oui(&self) -> [u8; 3] {
+        let mut oui = [0; 3];
+        oui.copy_from_slice(&self.bytes[0..3]);
+        oui
+    }
+
+    #[must_use]
+    pub fn vendor(&self) -> Vendor {
+        Vendor::from_oui(self.oui())
+    }
+
+    /// Byte following the OUI, only meaningful for vendors that use one
+    #[must_use]
+    pub fn oui_type(&self) -> Option<u8> {
+        self.bytes.get(3).copied()
+    }
+
+    /// Byte following the OUI type, only meaningful for formats that use one
+    #[must_use]
+    pub fn oui_subtype(&self) -> Option<u8> {
+        self.bytes.get(4).copied()
+    }
+
+    /// Everything after the OUI
+    #[must_use]
+    pub fn payload(&self) -> &'a [u8] {
+        &self.bytes[3..]
+    }
+
+    #[must_use]
+    pub fn vendor_specific_type(&self) -> VendorSpecificType {
+        VendorSpecificType::from(self.oui(), self.oui_type(), self.oui_subtype())
+    }
+
+    /// Whether this element has the header of the `T` format
+    #[must_use]
+    pub fn is<T: VendorSpecificElement<'a>>(&self) -> bool {
+        self.oui() == T::OUI
+            && (T::OUI_TYPE.is_none() || self.oui_type() == T::OUI_TYPE)
+            && (T::OUI_SUBTYPE.is_none() || self.oui_subtype() == T::OUI_SUBTYPE)
+    }
+
+    /// Decodes the element as `T` if its header matches
+    #[must_use]
+    pub fn decode<T: VendorSpecificElement<'a>>(&self) -> Option<T> {
+        if !self.is::<T>() {
+            return None;
+        }
+
+        let header_len =
+            3 + usize::from(T::OUI_TYPE.is_some()) + usize::from(T::OUI_SUBTYPE.is_some());
+
+        T::decode(&self.bytes[header_len..])
+    }
+}
+
+/// A vendor format that can be decoded from a Vendor Specific element
+///
+/// Implement this for your own formats to use them with
+/// `VendorSpecific::decode` and `TaggedParameters::vendor_specific_element`.
+pub trait VendorSpecificElement<'a>: Sized {
+    const OUI: [u8; 3];
+    /// None if the format doesn't use an OUI type
+    const OUI_TYPE: Option<u8>;
+    /// None if the format doesn't use an OUI subtype
+    const OUI_SUBTYPE: Option<u8> = None;
+
+    /// `payload` is the element body following the OUI, OUI type and OUI subtype
+    fn decode(payload: &'a [u8]) -> Option<Self>;
+}
+
+impl<'a> VendorSpecificElement<'a> for WPAVersion {
+    const OUI: [u8; 3] = MICROSOFT_OUI;
+    const OUI_TYPE: Option<u8> = Some(WPA_OUI_TYPE);
+
+    fn decode(payload: &'a [u8]) -> Option<Self> {
+        Self::from_bytes(payload)
+    }
+}
+
+impl<'a> VendorSpecificElement<'a> for WMMInformation {
+    const OUI: [u8; 3] = MICROSOFT_OUI;
+    const OUI_TYPE: Option<u8> = Some(WMM_OUI_TYPE);
+    const OUI_SUBTYPE: Option<u8> = Some(WMM_INFORMATION_OUI_SUBTYPE);
+
+    fn decode(payload: &'a [u8]) -> Option<Self> {
+        Self::from_bytes(payload)
+    }
+}
+
+impl<'a> VendorSpecificElement<'a> for WMMParameter {
+    const OUI: [u8; 3] = MICROSOFT_OUI;
+    const OUI_TYPE: Option<u8> = Some(WMM_OUI_TYPE);
+    const OUI_SUBTYPE: Option<u8> = Some(WMM_PARAMETER_OUI_SUBTYPE);
+
+    fn decode(payload: &'a [u8]) -> Option<Self> {
+        Self::from_bytes(payload)
+    }
+}
+
+impl<'a> TaggedParameters<'a> {
+    /// Every Vendor Specific element, in order
+    pub fn vendor_specific(&self) -> impl Iterator<Item = VendorSpecific<'_>> + '_ {
+        self.get_all_bytes(TagName::VendorSpecific)
+            .filter_map(VendorSpecific::new)
+    }
+
+    /// First Vendor Specific element that decodes as `T`
+    #[must_use]
+    pub fn vendor_specific_element<'b, T: VendorSpecificElement<'b>>(&'b self) -> Option<T> {
+        self.vendor_specific()
+            .filter(VendorSpecific::is::<T>)
+            .find_map(|vendor_specific| vendor_specific.decode::<T>())
+    }
+}
+
+/// Iterates over the Vendor Specific elements of a frame without building a
+/// `TaggedParameters`
+pub struct VendorSpecificIterator<'a> {
+    tagged_parameters: TaggedParameterIterator<'a>,
+}
+
+impl<'a> VendorSpecificIterator<'a> {
+    #[must_use]
+    pub fn new(tagged_parameters: TaggedParameterIterator<'a>) -> Self {
+        Self { tagged_parameters }
+    }
+}
+
+impl<'a> Iterator for VendorSpecificIterator<'a> {
+    type Item = VendorSpecific<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.tagged_parameters.next()? {
+                Ok((TagName::VendorSpecific, bytes)) => {
+                    if let Some(vendor_specific) = VendorSpecific::new(bytes) {
+                        return Some(vendor_specific);
+                    }
+                }
+                Ok(_) => {}
+                Err(_) => return None,
+            }
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Vendor {
+    /// 00:50:F2
+    Microsoft,
+    /// 50:6F:9A
+    WiFiAlliance,
+    /// 00:40:96
+    Cisco,
+    /// 00:0B:86
+    Aruba,
+    /// 00:17:F2
+    Apple,
+    /// 00:10:18
+    Broadcom,
+    /// 00:03:7F
+    Qualcomm,
+    Other([u8; 3]),
+}
+
+impl Vendor {
+    #[must_use]
+    pub fn from_oui(oui: [u8; 3]) -> Self {
+        match oui {
+            [0x00, 0x50, 0xf2] => Self::Microsoft,
+            [0x50, 0x6f, 0x9a] => Self::WiFiAlliance,
+            [0x00, 0x40, 0x96] => Self::Cisco,
+            [0x00, 0x0b, 0x86] => Self::Aruba,
+            [0x00, 0x17, 0xf2] => Self::Apple,
+            [0x00, 0x10, 0x18] => Self::Broadcom,
+            [0x00, 0x03, 0x7f] => Self::Qualcomm,
+            other => Self::Other(other),
+        }
+    }
+
+    #[must_use]
+    pub fn into_oui(self) -> [u8; 3] {
+        match self {
+            Self::Microsoft => [0x00, 0x50, 0xf2],
+            Self::WiFiAlliance => [0x50, 0x6f, 0x9a],
+            Self::Cisco => [0x00, 0x40, 0x96],
+            Self::Aruba => [0x00, 0x0b, 0x86],
+            Self::Apple => [0x00, 0x17, 0xf2],
+            Self::Broadcom => [0x00, 0x10, 0x18],
+            Self::Qualcomm => [0x00, 0x03, 0x7f],
+            Self::Other(other) => other,
+        }
+    }
+}
+
+/// Known Vendor Specific element formats
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum VendorSpecificType {
+    /// Microsoft WPA (WPA1), type 1
+    WPA,
+    /// Microsoft WMM/WME Information Element, type 2 subtype 0
+    WMMInformation,
+    /// Microsoft WMM/WME Parameter Element, type 2 subtype 1
+    WMMParameter,
+    /// Microsoft Wi-Fi Protected Setup, type 4
+    WPS,
+    /// Wi-Fi Alliance Wi-Fi Direct, type 9
+    P2P,
+    /// Wi-Fi Alliance Hotspot 2.0 Indication, type 16
+    HotSpot20,
+    /// Wi-Fi Alliance Multi Band Operation / Optimized Connectivity Experience, type 22
+    MBOOCE,
+    /// Wi-Fi Alliance OWE Transition Mode, type 28
+    OWETransitionMode,
+    /// any other element from a known vendor, with its OUI type
+    Vendor(Vendor, Option<u8>),
+}
+
+impl VendorSpecificType {
+    #[must_use]
+    pub fn from(oui: [u8; 3], oui_type: Option<u8>, oui_subtype: Option<u8>) -> Self {
+        match (Vendor::from_oui(oui), oui_type, oui_subtype) {
+            (Vendor::Microsoft, Some(1), _) => Self::WPA,
+            (Vendor::Microsoft, Some(2), Some(0)) => Self::WMMInformation,
+            (Vendor::Microsoft, Some(2), Some(1)) => Self::WMMParameter,
+            (Vendor::Microsoft, Some(4), _) => Self::WPS,
+            (Vendor::WiFiAlliance, Some(0x09), _) => Self::P2P,
+            (Vendor::WiFiAlliance, Some(0x10), _) => Self::HotSpot20,
+            (Vendor::WiFiAlliance, Some(0x16), _) => Self::MBOOCE,
+            (Vendor::WiFiAlliance, Some(0x1c), _) => Self::OWETransitionMode,
+            (vendor, oui_type, _) => Self::Vendor(vendor, oui_type),
+        }
+    }
+}
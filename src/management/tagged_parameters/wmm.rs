@@ -0,0 +1,281 @@
+use super::*;
+use byteorder::{ByteOrder, LittleEndian};
+
+/// OUI type of the WMM/WME vendor specific elements
+pub const WMM_OUI_TYPE: u8 = 2;
+
+/// OUI subtype of the WMM Information Element
+pub const WMM_INFORMATION_OUI_SUBTYPE: u8 = 0;
+
+/// OUI subtype of the WMM Parameter Element
+pub const WMM_PARAMETER_OUI_SUBTYPE: u8 = 1;
+
+impl TaggedParameters<'_> {
+    /// WMM Information Element
+    #[must_use]
+    pub fn wmm_information(&self) -> Option<WMMInformation> {
+        self.vendor_specific_element()
+    }
+
+    /// WMM Parameter Element
+    #[must_use]
+    pub fn wmm_parameter(&self) -> Option<WMMParameter> {
+        self.vendor_specific_element()
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct WMMInformation {
+    pub version: u8,
+    /// use `ap_qos_info` or `sta_qos_info` depending on who sent the element
+    pub qos_info: u8,
+}
+
+impl WMMInformation {
+    /// Parses the element body following the OUI, OUI type and OUI subtype
+    #[must_use]
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() < 2 {
+            return None;
+        }
+
+        Some(Self {
+            version: bytes[0],
+            qos_info: bytes[1],
+        })
+    }
+
+    /// Element body, including the OUI, OUI type and OUI subtype
+    #[must_use]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = MICROSOFT_OUI.to_vec();
+        bytes.push(WMM_OUI_TYPE);
+        bytes.push(WMM_INFORMATION_OUI_SUBTYPE);
+        bytes.push(self.version);
+        bytes.push(self.qos_info);
+        bytes
+    }
+
+    #[must_use]
+    pub fn ap_qos_info(&self) -> WMMAPQoSInfo {
+        WMMAPQoSInfo::from_u8(self.qos_info)
+    }
+
+    #[must_use]
+    pub fn sta_qos_info(&self) -> WMMSTAQoSInfo {
+        WMMSTAQoSInfo::from_u8(self.qos_info)
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct WMMParameter {
+    pub version: u8,
+    /// use `ap_qos_info`, the Parameter Element is only sent by APs
+    pub qos_info: u8,
+    /// in the order they appear, normally Best Effort, Background, Video, Voice
+    pub ac_parameters: [WMMACParameter; 4],
+}
+
+impl WMMParameter {
+    /// Parses the element body following the OUI, OUI type and OUI subtype
+    #[must_use]
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        // version, qos info, reserved, 4 AC parameter records
+        if bytes.len() < 3 + 4 * 4 {
+            return None;
+        }
+
+        let mut ac_parameters = [WMMACParameter::default(); 4];
+        for (i, ac_parameter) in ac_parameters.iter_mut().enumerate() {
+            let start = 3 + i * 4;
+            *ac_parameter = WMMACParameter::from_bytes(&bytes[start..(start + 4)]);
+        }
+
+        Some(Self {
+            version: bytes[0],
+            qos_info: bytes[1],
+            ac_parameters,
+        })
+    }
+
+    /// Element body, including the OUI, OUI type and OUI subtype
+    #[must_use]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = MICROSOFT_OUI.to_vec();
+        bytes.push(WMM_OUI_TYPE);
+        bytes.push(WMM_PARAMETER_OUI_SUBTYPE);
+        bytes.push(self.version);
+        bytes.push(self.qos_info);
+        // reserved
+        bytes.push(0);
+        for ac_parameter in &self.ac_parameters {
+            bytes.extend_from_slice(&ac_parameter.to_bytes());
+        }
+        bytes
+    }
+
+    #[must_use]
+    pub fn ap_qos_info(&self) -> WMMAPQoSInfo {
+        WMMAPQoSInfo::from_u8(self.qos_info)
+    }
+
+    #[must_use]
+    pub fn ac_parameter(&self, access_category: AccessCategory) -> Option<&WMMACParameter> {
+        self.ac_parameters
+            .iter()
+            .find(|ac_parameter| ac_parameter.aci == access_category)
+    }
+}
+
+/// QoS Info field as sent by an AP
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct WMMAPQoSInfo {
+    /// Incremented every time the AP changes its EDCA parameters
+    pub parameter_set_count: u8,
+    /// U-APSD supported
+    pub uapsd: bool,
+}
+
+impl WMMAPQoSInfo {
+    #[must_use]
+    pub fn from_u8(b: u8) -> Self {
+        Self {
+            parameter_set_count: b & 0b0000_1111,
+            uapsd: (b & 0b1000_0000) != 0,
+        }
+    }
+
+    #[must_use]
+    pub fn into_u8(self) -> u8 {
+        (self.parameter_set_count & 0b0000_1111) | (if self.uapsd { 0b1000_0000 } else { 0 })
+    }
+}
+
+/// QoS Info field as sent by a STA
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct WMMSTAQoSInfo {
+    /// U-APSD enabled for AC_VO
+    pub ac_vo_uapsd: bool,
+    /// U-APSD enabled for AC_VI
+    pub ac_vi_uapsd: bool,
+    /// U-APSD enabled for AC_BK
+    pub ac_bk_uapsd: bool,
+    /// U-APSD enabled for AC_BE
+    pub ac_be_uapsd: bool,
+    // 0: All buffered frames
+    // 1: 2 buffered frames
+    // 2: 4 buffered frames
+    // 3: 6 buffered frames
+    pub max_sp_length: u8,
+}
+
+impl WMMSTAQoSInfo {
+    #[must_use]
+    pub fn from_u8(b: u8) -> Self {
+        Self {
+            ac_vo_uapsd: (b & 0b0000_0001) != 0,
+            ac_vi_uapsd: (b & 0b0000_0010) != 0,
+            ac_bk_uapsd: (b & 0b0000_0100) != 0,
+            ac_be_uapsd: (b & 0b0000_1000) != 0,
+            max_sp_length: (b & 0b0110_0000) >> 5,
+        }
+    }
+
+    #[must_use]
+    pub fn into_u8(self) -> u8 {
+        let mut b = (self.max_sp_length << 5) & 0b0110_0000;
+        if self.ac_vo_uapsd {
+            b |= 0b0000_0001;
+        }
+        if self.ac_vi_uapsd {
+            b |= 0b0000_0010;
+        }
+        if self.ac_bk_uapsd {
+            b |= 0b0000_0100;
+        }
+        if self.ac_be_uapsd {
+            b |= 0b0000_1000;
+        }
+        b
+    }
+}
+
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct WMMACParameter {
+    /// Access Category Index
+    pub aci: AccessCategory,
+    /// Arbitration Inter-Frame Space Number
+    pub aifsn: u8,
+    /// Admission Control Mandatory
+    pub acm: bool,
+    /// CWmin = 2^ECWmin - 1
+    pub ecw_min: u8,
+    /// CWmax = 2^ECWmax - 1
+    pub ecw_max: u8,
+    /// in units of 32 microseconds, 0 allows a single MSDU
+    pub txop_limit: u16,
+}
+
+impl WMMACParameter {
+    fn from_bytes(bytes: &[u8]) -> Self {
+        Self {
+            aci: AccessCategory::from_u8((bytes[0] & 0b0110_0000) >> 5),
+            aifsn: bytes[0] & 0b0000_1111,
+            acm: (bytes[0] & 0b0001_0000) != 0,
+            ecw_min: bytes[1] & 0b0000_1111,
+            ecw_max: (bytes[1] & 0b1111_0000) >> 4,
+            txop_limit: LittleEndian::read_u16(&bytes[2..4]),
+        }
+    }
+
+    fn to_bytes(self) -> [u8; 4] {
+        let mut bytes = [0; 4];
+        bytes[0] = ((self.aci.into_u8() << 5) & 0b0110_0000)
+            | (if self.acm { 0b0001_0000 } else { 0 })
+            | (self.aifsn & 0b0000_1111);
+        bytes[1] = ((self.ecw_max << 4) & 0b1111_0000) | (self.ecw_min & 0b0000_1111);
+        LittleEndian::write_u16(&mut bytes[2..4], self.txop_limit);
+        bytes
+    }
+
+    /// in microseconds
+    #[must_use]
+    pub fn txop_limit_us(&self) -> u32 {
+        u32::from(self.txop_limit) * 32
+    }
+}
+
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum AccessCategory {
+    /// AC_BE
+    #[default]
+    BestEffort, // 0
+    /// AC_BK
+    Background, // 1
+    /// AC_VI
+    Video, // 2
+    /// AC_VO
+    Voice, // 3
+}
+
+impl AccessCategory {
+    #[must_use]
+    pub fn from_u8(n: u8) -> Self {
+        match n & 0b11 {
+            0 => Self::BestEffort,
+            1 => Self::Background,
+            2 => Self::Video,
+            _ => Self::Voice,
+        }
+    }
+
+    #[must_use]
+    pub fn into_u8(self) -> u8 {
+        match self {
+            Self::BestEffort => 0,
+            Self::Background => 1,
+            Self::Video => 2,
+            Self::Voice => 3,
+        }
+    }
+}
@@ -0,0 +1,108 @@
+use super::*;
+use byteorder::{ByteOrder, LittleEndian};
+
+/// Microsoft OUI, used by the WPA and WMM vendor specific elements
+pub const MICROSOFT_OUI: [u8; 3] = [0x00, 0x50, 0xf2];
+
+/// OUI type of the WPA (WPA1) vendor specific element
+pub const WPA_OUI_TYPE: u8 = 1;
+
+impl TaggedParameters<'_> {
+    /// Legacy WPA (WPA1) vendor specific element
+    #[must_use]
+    pub fn wpa(&self) -> Option<WPAVersion> {
+        self.vendor_specific_element()
+    }
+}
+
+fn make_std_wpa(bytes: &[u8]) -> WPA {
+    let mut i = 0;
+    let len = bytes.len();
+
+    let mut wpa = WPA::default();
+
+    if (i + 4) > len {
+        return wpa;
+    }
+    let multicast_cipher_suite = WPA::read_cipher_suite(&bytes[i..(i + 4)]);
+    wpa.multicast_cipher_suite = Some(multicast_cipher_suite);
+    i += 4;
+
+    if (i + 2) > len {
+        return wpa;
+    }
+    let unicast_cipher_suite_count = LittleEndian::read_u16(&bytes[i..(i + 2)]);
+    i += 2;
+
+    for _ in 0..unicast_cipher_suite_count {
+        if (i + 4) > len {
+            return wpa;
+        }
+        let unicast_cipher_suite = WPA::read_cipher_suite(&bytes[i..(i + 4)]);
+        wpa.unicast_cipher_suites.push(unicast_cipher_suite);
+        i += 4;
+    }
+
+    if (i + 2) > len {
+        return wpa;
+    }
+    let akm_suite_count = LittleEndian::read_u16(&bytes[i..(i + 2)]);
+    i += 2;
+
+    for _ in 0..akm_suite_count {
+        if (i + 4) > len {
+            return wpa;
+        }
+        let akm_suite = WPA::read_akm_suite(&bytes[i..(i + 4)]);
+        wpa.akm_suites.push(akm_suite);
+        i += 4;
+    }
+
+    wpa
+}
+
+#[derive(Debug, PartialEq)]
+pub enum WPAVersion {
+    Standard(WPA), // 1
+    Reserved(u16),
+}
+
+impl WPAVersion {
+    /// Parses the element body following the OUI and OUI type
+    #[must_use]
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() < 2 {
+            return None;
+        }
+        let version = LittleEndian::read_u16(&bytes[0..2]);
+        Some(match version {
+            1 => Self::Standard(make_std_wpa(&bytes[2..])),
+            other => Self::Reserved(other),
+        })
+    }
+}
+
+/// WPA suites use the Microsoft OUI but share their type numbers with RSN,
+/// so they are decoded as `Standard` suites.
+#[derive(Debug, Default, PartialEq)]
+pub struct WPA {
+    pub multicast_cipher_suite: Option<CipherSuite>,
+    pub unicast_cipher_suites: Vec<CipherSuite>,
+    pub akm_suites: Vec<AKMSuite>,
+}
+
+impl WPA {
+    fn read_cipher_suite(bytes: &[u8]) -> CipherSuite {
+        match RSN::read_suite_oui_and_type(bytes) {
+            (MICROSOFT_OUI, type_) => CipherSuite::Standard(CipherSuiteType::from(type_)),
+            (oui, type_) => CipherSuite::from(oui, type_),
+        }
+    }
+
+    fn read_akm_suite(bytes: &[u8]) -> AKMSuite {
+        match RSN::read_suite_oui_and_type(bytes) {
+            (MICROSOFT_OUI, type_) => AKMSuite::Standard(AKMSuiteType::from(type_)),
+            (oui, type_) => AKMSuite::from(oui, type_),
+        }
+    }
+}
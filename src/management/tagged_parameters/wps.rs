@@ -0,0 +1,399 @@
+use super::*;
+use byteorder::{BigEndian, ByteOrder};
+
+/// OUI type of the Wi-Fi Protected Setup vendor specific element
+pub const WPS_OUI_TYPE: u8 = 4;
+
+impl TaggedParameters<'_> {
+    /// Wi-Fi Protected Setup attributes
+    ///
+    /// WPS data longer than one element is split across consecutive WPS
+    /// elements, their payloads are joined before decoding.
+    #[must_use]
+    pub fn wps(&self) -> Option<WPS> {
+        let mut bytes = Vec::new();
+        let mut found = false;
+
+        for vendor_specific in self.vendor_specific().filter(VendorSpecific::is::<WPS>) {
+            bytes.extend_from_slice(&vendor_specific.payload()[1..]);
+            found = true;
+        }
+
+        if !found {
+            return None;
+        }
+
+        Some(WPS::from_bytes(&bytes))
+    }
+}
+
+impl<'a> VendorSpecificElement<'a> for WPS {
+    const OUI: [u8; 3] = MICROSOFT_OUI;
+    const OUI_TYPE: Option<u8> = Some(WPS_OUI_TYPE);
+
+    fn decode(payload: &'a [u8]) -> Option<Self> {
+        Some(Self::from_bytes(payload))
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct WPS {
+    /// in the order they appear, a truncated last attribute is dropped
+    pub attributes: Vec<WPSAttribute>,
+}
+
+impl WPS {
+    /// Parses big-endian 16-bit type, 16-bit length attributes
+    #[must_use]
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        let mut attributes = Vec::new();
+
+        let mut i = 0;
+        let len = bytes.len();
+        while (i + 4) <= len {
+            let attribute_type = BigEndian::read_u16(&bytes[i..(i + 2)]);
+            let attribute_length = BigEndian::read_u16(&bytes[(i + 2)..(i + 4)]) as usize;
+            i += 4;
+
+            if (i + attribute_length) > len {
+                break;
+            }
+            attributes.push(WPSAttribute::from(
+                attribute_type,
+                &bytes[i..(i + attribute_length)],
+            ));
+            i += attribute_length;
+        }
+
+        Self { attributes }
+    }
+
+    #[must_use]
+    pub fn version(&self) -> Option<u8> {
+        self.attributes
+            .iter()
+            .find_map(|attribute| match attribute {
+                WPSAttribute::Version(version) => Some(*version),
+                _ => None,
+            })
+    }
+
+    /// Version 2 from the Wi-Fi Alliance vendor extension
+    #[must_use]
+    pub fn version2(&self) -> Option<u8> {
+        self.attributes
+            .iter()
+            .find_map(|attribute| match attribute {
+                WPSAttribute::VendorExtension(WPS_WIFI_ALLIANCE_VENDOR_ID, data) => {
+                    WPSWiFiAllianceSubelementIterator { bytes: data }
+                        .find(|(id, data)| *id == 0x00 && data.len() == 1)
+                        .map(|(_, data)| data[0])
+                }
+                _ => None,
+            })
+    }
+
+    #[must_use]
+    pub fn wps_state(&self) -> Option<WPSState> {
+        self.attributes
+            .iter()
+            .find_map(|attribute| match attribute {
+                WPSAttribute::WPSState(wps_state) => Some(*wps_state),
+                _ => None,
+            })
+    }
+
+    /// UUID-E from enrollees, or UUID-R from registrars
+    #[must_use]
+    pub fn uuid(&self) -> Option<[u8; 16]> {
+        self.attributes
+            .iter()
+            .find_map(|attribute| match attribute {
+                WPSAttribute::UUIDE(uuid) | WPSAttribute::UUIDR(uuid) => Some(*uuid),
+                _ => None,
+            })
+    }
+
+    #[must_use]
+    pub fn manufacturer(&self) -> Option<&[u8]> {
+        self.attributes
+            .iter()
+            .find_map(|attribute| match attribute {
+                WPSAttribute::Manufacturer(manufacturer) => Some(manufacturer.as_ref()),
+                _ => None,
+            })
+    }
+
+    #[must_use]
+    pub fn model_name(&self) -> Option<&[u8]> {
+        self.attributes
+            .iter()
+            .find_map(|attribute| match attribute {
+                WPSAttribute::ModelName(model_name) => Some(model_name.as_ref()),
+                _ => None,
+            })
+    }
+
+    #[must_use]
+    pub fn model_number(&self) -> Option<&[u8]> {
+        self.attributes
+            .iter()
+            .find_map(|attribute| match attribute {
+                WPSAttribute::ModelNumber(model_number) => Some(model_number.as_ref()),
+                _ => None,
+            })
+    }
+
+    #[must_use]
+    pub fn serial_number(&self) -> Option<&[u8]> {
+        self.attributes
+            .iter()
+            .find_map(|attribute| match attribute {
+                WPSAttribute::SerialNumber(serial_number) => Some(serial_number.as_ref()),
+                _ => None,
+            })
+    }
+
+    #[must_use]
+    pub fn device_name(&self) -> Option<&[u8]> {
+        self.attributes
+            .iter()
+            .find_map(|attribute| match attribute {
+                WPSAttribute::DeviceName(device_name) => Some(device_name.as_ref()),
+                _ => None,
+            })
+    }
+
+    #[must_use]
+    pub fn primary_device_type(&self) -> Option<WPSPrimaryDeviceType> {
+        self.attributes
+            .iter()
+            .find_map(|attribute| match attribute {
+                WPSAttribute::PrimaryDeviceType(primary_device_type) => Some(*primary_device_type),
+                _ => None,
+            })
+    }
+
+    #[must_use]
+    pub fn config_methods(&self) -> Option<WPSConfigMethods> {
+        self.attributes
+            .iter()
+            .find_map(|attribute| match attribute {
+                WPSAttribute::ConfigMethods(config_methods) => Some(*config_methods),
+                _ => None,
+            })
+    }
+}
+
+/// Wi-Fi Alliance vendor id used in the WPS Vendor Extension attribute
+pub const WPS_WIFI_ALLIANCE_VENDOR_ID: [u8; 3] = [0x00, 0x37, 0x2a];
+
+/// 8-bit id, 8-bit length subelements of the Wi-Fi Alliance vendor extension
+struct WPSWiFiAllianceSubelementIterator<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Iterator for WPSWiFiAllianceSubelementIterator<'a> {
+    type Item = (u8, &'a [u8]);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.bytes.len() < 2 {
+            return None;
+        }
+
+        let id = self.bytes[0];
+        let length = self.bytes[1] as usize;
+        if self.bytes.len() < 2 + length {
+            return None;
+        }
+
+        let data = &self.bytes[2..(2 + length)];
+        self.bytes = &self.bytes[(2 + length)..];
+
+        Some((id, data))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum WPSAttribute {
+    /// 0x104A, deprecated in favor of Version2 in the vendor extension
+    Version(u8),
+    /// 0x1044
+    WPSState(WPSState),
+    /// 0x1057
+    APSetupLocked(bool),
+    /// 0x1041
+    SelectedRegistrar(bool),
+    /// 0x1012
+    DevicePasswordID(u16),
+    /// 0x1053
+    SelectedRegistrarConfigMethods(WPSConfigMethods),
+    /// 0x103A
+    RequestType(u8),
+    /// 0x103B
+    ResponseType(u8),
+    /// 0x1047
+    UUIDE([u8; 16]),
+    /// 0x1048
+    UUIDR([u8; 16]),
+    /// 0x1021
+    Manufacturer(Vec<u8>),
+    /// 0x1023
+    ModelName(Vec<u8>),
+    /// 0x1024
+    ModelNumber(Vec<u8>),
+    /// 0x1042
+    SerialNumber(Vec<u8>),
+    /// 0x1054
+    PrimaryDeviceType(WPSPrimaryDeviceType),
+    /// 0x1011
+    DeviceName(Vec<u8>),
+    /// 0x1008
+    ConfigMethods(WPSConfigMethods),
+    /// 0x103C
+    RFBands(u8),
+    /// 0x1002
+    AssociationState(u16),
+    /// 0x1009
+    ConfigurationError(u16),
+    /// 0x1049, vendor id and vendor data
+    VendorExtension([u8; 3], Vec<u8>),
+    /// unknown attributes, or known attributes with an unexpected length
+    Other(u16, Vec<u8>),
+}
+
+impl WPSAttribute {
+    #[must_use]
+    pub fn from(attribute_type: u16, data: &[u8]) -> Self {
+        match (attribute_type, data.len()) {
+            (0x104a, 1) => Self::Version(data[0]),
+            (0x1044, 1) => Self::WPSState(WPSState::from_u8(data[0])),
+            (0x1057, 1) => Self::APSetupLocked(data[0] != 0),
+            (0x1041, 1) => Self::SelectedRegistrar(data[0] != 0),
+            (0x1012, 2) => Self::DevicePasswordID(BigEndian::read_u16(data)),
+            (0x1053, 2) => Self::SelectedRegistrarConfigMethods(WPSConfigMethods::from_u16(
+                BigEndian::read_u16(data),
+            )),
+            (0x103a, 1) => Self::RequestType(data[0]),
+            (0x103b, 1) => Self::ResponseType(data[0]),
+            (0x1047, 16) => {
+                let mut uuid = [0; 16];
+                uuid.copy_from_slice(data);
+                Self::UUIDE(uuid)
+            }
+            (0x1048, 16) => {
+                let mut uuid = [0; 16];
+                uuid.copy_from_slice(data);
+                Self::UUIDR(uuid)
+            }
+            (0x1021, _) => Self::Manufacturer(data.to_vec()),
+            (0x1023, _) => Self::ModelName(data.to_vec()),
+            (0x1024, _) => Self::ModelNumber(data.to_vec()),
+            (0x1042, _) => Self::SerialNumber(data.to_vec()),
+            (0x1054, 8) => Self::PrimaryDeviceType(WPSPrimaryDeviceType::from_bytes(data)),
+            (0x1011, _) => Self::DeviceName(data.to_vec()),
+            (0x1008, 2) => {
+                Self::ConfigMethods(WPSConfigMethods::from_u16(BigEndian::read_u16(data)))
+            }
+            (0x103c, 1) => Self::RFBands(data[0]),
+            (0x1002, 2) => Self::AssociationState(BigEndian::read_u16(data)),
+            (0x1009, 2) => Self::ConfigurationError(BigEndian::read_u16(data)),
+            (0x1049, n) if n >= 3 => {
+                let mut vendor_id = [0; 3];
+                vendor_id.copy_from_slice(&data[0..3]);
+                Self::VendorExtension(vendor_id, data[3..].to_vec())
+            }
+            (other, _) => Self::Other(other, data.to_vec()),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum WPSState {
+    NotConfigured, // 1
+    Configured,    // 2
+    Reserved(u8),
+}
+
+impl WPSState {
+    #[must_use]
+    pub fn from_u8(n: u8) -> Self {
+        match n {
+            1 => Self::NotConfigured,
+            2 => Self::Configured,
+            other => Self::Reserved(other),
+        }
+    }
+
+    #[must_use]
+    pub fn into_u8(self) -> u8 {
+        match self {
+            Self::NotConfigured => 1,
+            Self::Configured => 2,
+            Self::Reserved(other) => other,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct WPSPrimaryDeviceType {
+    /// 1: Computer, 2: Input Device, 3: Printer, 4: Camera, 5: Storage,
+    /// 6: Network Infrastructure, 7: Display, 8: Multimedia, 9: Gaming,
+    /// 10: Telephone, 11: Audio
+    pub category: u16,
+    /// normally the Wi-Fi Alliance OUI 00:50:F2:04
+    pub oui: [u8; 4],
+    pub subcategory: u16,
+}
+
+impl WPSPrimaryDeviceType {
+    #[must_use]
+    pub fn from_bytes(bytes: &[u8]) -> Self {
+        let mut oui = [0; 4];
+        oui.copy_from_slice(&bytes[2..6]);
+
+        Self {
+            category: BigEndian::read_u16(&bytes[0..2]),
+            oui,
+            subcategory: BigEndian::read_u16(&bytes[6..8]),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct WPSConfigMethods {
+    pub usba: bool,
+    pub ethernet: bool,
+    pub label: bool,
+    pub display: bool,
+    pub external_nfc_token: bool,
+    pub integrated_nfc_token: bool,
+    pub nfc_interface: bool,
+    pub push_button: bool,
+    pub keypad: bool,
+    pub virtual_push_button: bool,
+    pub physical_push_button: bool,
+    pub virtual_display: bool,
+    pub physical_display: bool,
+}
+
+impl WPSConfigMethods {
+    #[must_use]
+    pub fn from_u16(b: u16) -> Self {
+        Self {
+            usba: (b & 0b0000_0000_0000_0001) != 0,
+            ethernet: (b & 0b0000_0000_0000_0010) != 0,
+            label: (b & 0b0000_0000_0000_0100) != 0,
+            display: (b & 0b0000_0000_0000_1000) != 0,
+            external_nfc_token: (b & 0b0000_0000_0001_0000) != 0,
+            integrated_nfc_token: (b & 0b0000_0000_0010_0000) != 0,
+            nfc_interface: (b & 0b0000_0000_0100_0000) != 0,
+            push_button: (b & 0b0000_0000_1000_0000) != 0,
+            keypad: (b & 0b0000_0001_0000_0000) != 0,
+            virtual_push_button: (b & 0b0000_0010_0000_0000) != 0,
+            physical_push_button: (b & 0b0000_0100_0000_0000) != 0,
+            virtual_display: (b & 0b0010_0000_0000_0000) != 0,
+            physical_display: (b & 0b0100_0000_0000_0000) != 0,
+        }
+    }
+}
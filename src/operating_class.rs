@@ -0,0 +1,1153 @@
+use super::*;
+
+/// Operating class table, the third octet of a country string 0x01-0x04
+/// selects one
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Region {
+    UnitedStates, // 1
+    Europe,       // 2
+    Japan,        // 3
+    Global,       // 4
+}
+
+impl Region {
+    /// Operating class table number, as found in the Country element
+    #[must_use]
+    pub fn from_table_number(n: u8) -> Option<Self> {
+        match n {
+            1 => Some(Self::UnitedStates),
+            2 => Some(Self::Europe),
+            3 => Some(Self::Japan),
+            4 => Some(Self::Global),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub fn table_number(self) -> u8 {
+        match self {
+            Self::UnitedStates => 1,
+            Self::Europe => 2,
+            Self::Japan => 3,
+            Self::Global => 4,
+        }
+    }
+
+    /// Regional table used in an ISO 3166-1 alpha-2 country
+    #[must_use]
+    pub fn from_country_code(country_code: [u8; 2]) -> Option<Self> {
+        match &country_code {
+            b"US" | b"PR" | b"GU" | b"AS" | b"VI" | b"MP" | b"UM" => Some(Self::UnitedStates),
+            b"JP" => Some(Self::Japan),
+            // EU, EFTA and the UK, which follow the ETSI rules
+            b"AT" | b"BE" | b"BG" | b"HR" | b"CY" | b"CZ" | b"DK" | b"EE" | b"FI" | b"FR"
+            | b"DE" | b"GR" | b"HU" | b"IE" | b"IT" | b"LV" | b"LT" | b"LU" | b"MT" | b"NL"
+            | b"PL" | b"PT" | b"RO" | b"SK" | b"SI" | b"ES" | b"SE" | b"GB" | b"CH" | b"NO"
+            | b"IS" | b"LI" => Some(Self::Europe),
+            _ => None,
+        }
+    }
+
+    #[must_use]
+    pub fn operating_classes(self) -> &'static [OperatingClass] {
+        match self {
+            Self::UnitedStates => US_OPERATING_CLASSES,
+            Self::Europe => EU_OPERATING_CLASSES,
+            Self::Japan => JP_OPERATING_CLASSES,
+            Self::Global => GLOBAL_OPERATING_CLASSES,
+        }
+    }
+
+    #[must_use]
+    pub fn operating_class(self, number: u8) -> Option<&'static OperatingClass> {
+        self.operating_classes()
+            .iter()
+            .find(|operating_class| operating_class.number == number)
+    }
+
+    /// Global operating class of the regional class `number`
+    #[must_use]
+    pub fn to_global(self, number: u8) -> Option<u8> {
+        self.operating_class(number)
+            .map(|operating_class| operating_class.global)
+    }
+
+    /// First regional operating class of the global class `global`
+    #[must_use]
+    pub fn from_global(self, global: u8) -> Option<u8> {
+        self.operating_classes()
+            .iter()
+            .find(|operating_class| operating_class.global == global)
+            .map(|operating_class| operating_class.number)
+    }
+
+    /// First operating class that `channel` belongs to
+    #[must_use]
+    pub fn find_operating_class(self, channel: &Channel) -> Option<&'static OperatingClass> {
+        self.operating_classes()
+            .iter()
+            .find(|operating_class| operating_class.contains(channel))
+    }
+}
+
+/// An operating class, 802.11 Annex E
+///
+/// Classes of bands without a `Band`, like 3.65 GHz, 4.9 GHz public safety and
+/// 5.9 GHz ITS, are left out.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct OperatingClass {
+    pub number: u8,
+    /// matching global operating class, `number` for global classes
+    pub global: u8,
+    pub band: Band,
+    pub width: ChannelWidth,
+    /// side of the secondary channel of the 2.4 and 5 GHz 40 MHz classes
+    pub secondary_channel_offset: SecondaryChannelOffset,
+    /// channel set as listed in Annex E: the primary channels of the 2.4 and
+    /// 5 GHz 20 and 40 MHz classes, the center channels otherwise
+    pub channel_set: &'static [u8],
+}
+
+impl OperatingClass {
+    /// Global operating class `number`
+    #[must_use]
+    pub fn global(number: u8) -> Option<&'static Self> {
+        Region::Global.operating_class(number)
+    }
+
+    /// Channel `number` of the class, `number` is the primary channel or for
+    /// the 5 and 6 GHz 40 MHz and wider classes the center channel
+    #[must_use]
+    pub fn channel(&self, number: u8) -> Option<Channel> {
+        let secondary_above = match self.secondary_channel_offset {
+            SecondaryChannelOffset::Above => Some(true),
+            SecondaryChannelOffset::Below => Some(false),
+            _ => None,
+        };
+        let channel = Channel::from_band_and_width(self.band, number, self.width, secondary_above)?;
+
+        let listed = self.channel_set.contains(&channel.number)
+            || channel
+                .center_number()
+                .is_some_and(|center| self.channel_set.contains(&center));
+        if !listed {
+            return None;
+        }
+
+        Some(channel)
+    }
+
+    /// Every channel of the class, with the lowest primary channel for wide
+    /// channels
+    pub fn channels(&self) -> impl Iterator<Item = Channel> + '_ {
+        self.channel_set
+            .iter()
+            .filter_map(move |number| self.channel(*number))
+    }
+
+    /// Whether `channel` is one of the class's channels, the second segment of
+    /// an 80+80 MHz channel is not checked
+    #[must_use]
+    pub fn contains(&self, channel: &Channel) -> bool {
+        if channel.band != self.band || channel.width != self.width {
+            return false;
+        }
+
+        // by center too, a primary channel can be in two 320 MHz channels
+        [Some(channel.number), channel.center_number()]
+            .into_iter()
+            .flatten()
+            .filter_map(|number| self.channel(number))
+            .any(|class_channel| class_channel.center_frequency == channel.center_frequency)
+    }
+}
+
+/// Global operating classes (802.11 Annex E Table E-4)
+pub const GLOBAL_OPERATING_CLASSES: &[OperatingClass] = &[
+    OperatingClass {
+        number: 81,
+        global: 81,
+        band: Band::TwoPointFourGHz,
+        width: ChannelWidth::MHz20,
+        secondary_channel_offset: SecondaryChannelOffset::None,
+        channel_set: &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13],
+    },
+    OperatingClass {
+        number: 82,
+        global: 82,
+        band: Band::TwoPointFourGHz,
+        width: ChannelWidth::MHz20,
+        secondary_channel_offset: SecondaryChannelOffset::None,
+        channel_set: &[14],
+    },
+    OperatingClass {
+        number: 83,
+        global: 83,
+        band: Band::TwoPointFourGHz,
+        width: ChannelWidth::MHz40,
+        secondary_channel_offset: SecondaryChannelOffset::Above,
+        channel_set: &[1, 2, 3, 4, 5, 6, 7, 8, 9],
+    },
+    OperatingClass {
+        number: 84,
+        global: 84,
+        band: Band::TwoPointFourGHz,
+        width: ChannelWidth::MHz40,
+        secondary_channel_offset: SecondaryChannelOffset::Below,
+        channel_set: &[5, 6, 7, 8, 9, 10, 11, 12, 13],
+    },
+    OperatingClass {
+        number: 115,
+        global: 115,
+        band: Band::FiveGHz,
+        width: ChannelWidth::MHz20,
+        secondary_channel_offset: SecondaryChannelOffset::None,
+        channel_set: &[36, 40, 44, 48],
+    },
+    OperatingClass {
+        number: 116,
+        global: 116,
+        band: Band::FiveGHz,
+        width: ChannelWidth::MHz40,
+        secondary_channel_offset: SecondaryChannelOffset::Above,
+        channel_set: &[36, 44],
+    },
+    OperatingClass {
+        number: 117,
+        global: 117,
+        band: Band::FiveGHz,
+        width: ChannelWidth::MHz40,
+        secondary_channel_offset: SecondaryChannelOffset::Below,
+        channel_set: &[40, 48],
+    },
+    OperatingClass {
+        number: 118,
+        global: 118,
+        band: Band::FiveGHz,
+        width: ChannelWidth::MHz20,
+        secondary_channel_offset: SecondaryChannelOffset::None,
+        channel_set: &[52, 56, 60, 64],
+    },
+    OperatingClass {
+        number: 119,
+        global: 119,
+        band: Band::FiveGHz,
+        width: ChannelWidth::MHz40,
+        secondary_channel_offset: SecondaryChannelOffset::Above,
+        channel_set: &[52, 60],
+    },
+    OperatingClass {
+        number: 120,
+        global: 120,
+        band: Band::FiveGHz,
+        width: ChannelWidth::MHz40,
+        secondary_channel_offset: SecondaryChannelOffset::Below,
+        channel_set: &[56, 64],
+    },
+    OperatingClass {
+        number: 121,
+        global: 121,
+        band: Band::FiveGHz,
+        width: ChannelWidth::MHz20,
+        secondary_channel_offset: SecondaryChannelOffset::None,
+        channel_set: &[100, 104, 108, 112, 116, 120, 124, 128, 132, 136, 140, 144],
+    },
+    OperatingClass {
+        number: 122,
+        global: 122,
+        band: Band::FiveGHz,
+        width: ChannelWidth::MHz40,
+        secondary_channel_offset: SecondaryChannelOffset::Above,
+        channel_set: &[100, 108, 116, 124, 132, 140],
+    },
+    OperatingClass {
+        number: 123,
+        global: 123,
+        band: Band::FiveGHz,
+        width: ChannelWidth::MHz40,
+        secondary_channel_offset: SecondaryChannelOffset::Below,
+        channel_set: &[104, 112, 120, 128, 136, 144],
+    },
+    OperatingClass {
+        number: 124,
+        global: 124,
+        band: Band::FiveGHz,
+        width: ChannelWidth::MHz20,
+        secondary_channel_offset: SecondaryChannelOffset::None,
+        channel_set: &[149, 153, 157, 161],
+    },
+    OperatingClass {
+        number: 125,
+        global: 125,
+        band: Band::FiveGHz,
+        width: ChannelWidth::MHz20,
+        secondary_channel_offset: SecondaryChannelOffset::None,
+        channel_set: &[149, 153, 157, 161, 165, 169, 173, 177],
+    },
+    OperatingClass {
+        number: 126,
+        global: 126,
+        band: Band::FiveGHz,
+        width: ChannelWidth::MHz40,
+        secondary_channel_offset: SecondaryChannelOffset::Above,
+        channel_set: &[149, 157, 165, 173],
+    },
+    OperatingClass {
+        number: 127,
+        global: 127,
+        band: Band::FiveGHz,
+        width: ChannelWidth::MHz40,
+        secondary_channel_offset: SecondaryChannelOffset::Below,
+        channel_set: &[153, 161, 169, 177],
+    },
+    OperatingClass {
+        number: 128,
+        global: 128,
+        band: Band::FiveGHz,
+        width: ChannelWidth::MHz80,
+        secondary_channel_offset: SecondaryChannelOffset::None,
+        channel_set: &[42, 58, 106, 122, 138, 155, 171],
+    },
+    OperatingClass {
+        number: 129,
+        global: 129,
+        band: Band::FiveGHz,
+        width: ChannelWidth::MHz160,
+        secondary_channel_offset: SecondaryChannelOffset::None,
+        channel_set: &[50, 114, 163],
+    },
+    OperatingClass {
+        number: 130,
+        global: 130,
+        band: Band::FiveGHz,
+        width: ChannelWidth::MHz80Plus80,
+        secondary_channel_offset: SecondaryChannelOffset::None,
+        channel_set: &[42, 58, 106, 122, 138, 155, 171],
+    },
+    OperatingClass {
+        number: 131,
+        global: 131,
+        band: Band::SixGHz,
+        width: ChannelWidth::MHz20,
+        secondary_channel_offset: SecondaryChannelOffset::None,
+        channel_set: &[
+            1, 5, 9, 13, 17, 21, 25, 29, 33, 37, 41, 45, 49, 53, 57, 61, 65, 69, 73, 77, 81, 85,
+            89, 93, 97, 101, 105, 109, 113, 117, 121, 125, 129, 133, 137, 141, 145, 149, 153, 157,
+            161, 165, 169, 173, 177, 181, 185, 189, 193, 197, 201, 205, 209, 213, 217, 221, 225,
+            229, 233,
+        ],
+    },
+    OperatingClass {
+        number: 132,
+        global: 132,
+        band: Band::SixGHz,
+        width: ChannelWidth::MHz40,
+        secondary_channel_offset: SecondaryChannelOffset::None,
+        channel_set: &[
+            3, 11, 19, 27, 35, 43, 51, 59, 67, 75, 83, 91, 99, 107, 115, 123, 131, 139, 147, 155,
+            163, 171, 179, 187, 195, 203, 211, 219, 227,
+        ],
+    },
+    OperatingClass {
+        number: 133,
+        global: 133,
+        band: Band::SixGHz,
+        width: ChannelWidth::MHz80,
+        secondary_channel_offset: SecondaryChannelOffset::None,
+        channel_set: &[
+            7, 23, 39, 55, 71, 87, 103, 119, 135, 151, 167, 183, 199, 215,
+        ],
+    },
+    OperatingClass {
+        number: 134,
+        global: 134,
+        band: Band::SixGHz,
+        width: ChannelWidth::MHz160,
+        secondary_channel_offset: SecondaryChannelOffset::None,
+        channel_set: &[15, 47, 79, 111, 143, 175, 207],
+    },
+    OperatingClass {
+        number: 135,
+        global: 135,
+        band: Band::SixGHz,
+        width: ChannelWidth::MHz80Plus80,
+        secondary_channel_offset: SecondaryChannelOffset::None,
+        channel_set: &[
+            7, 23, 39, 55, 71, 87, 103, 119, 135, 151, 167, 183, 199, 215,
+        ],
+    },
+    OperatingClass {
+        number: 136,
+        global: 136,
+        band: Band::SixGHz,
+        width: ChannelWidth::MHz20,
+        secondary_channel_offset: SecondaryChannelOffset::None,
+        channel_set: &[2],
+    },
+    OperatingClass {
+        number: 137,
+        global: 137,
+        band: Band::SixGHz,
+        width: ChannelWidth::MHz320,
+        secondary_channel_offset: SecondaryChannelOffset::None,
+        channel_set: &[31, 63, 95, 127, 159, 191],
+    },
+    OperatingClass {
+        number: 180,
+        global: 180,
+        band: Band::SixtyGHz,
+        width: ChannelWidth::MHz2160,
+        secondary_channel_offset: SecondaryChannelOffset::None,
+        channel_set: &[1, 2, 3, 4, 5, 6],
+    },
+];
+
+/// Operating classes in the United States (802.11 Annex E Table E-1)
+pub const US_OPERATING_CLASSES: &[OperatingClass] = &[
+    OperatingClass {
+        number: 1,
+        global: 115,
+        band: Band::FiveGHz,
+        width: ChannelWidth::MHz20,
+        secondary_channel_offset: SecondaryChannelOffset::None,
+        channel_set: &[36, 40, 44, 48],
+    },
+    OperatingClass {
+        number: 2,
+        global: 118,
+        band: Band::FiveGHz,
+        width: ChannelWidth::MHz20,
+        secondary_channel_offset: SecondaryChannelOffset::None,
+        channel_set: &[52, 56, 60, 64],
+    },
+    OperatingClass {
+        number: 3,
+        global: 124,
+        band: Band::FiveGHz,
+        width: ChannelWidth::MHz20,
+        secondary_channel_offset: SecondaryChannelOffset::None,
+        channel_set: &[149, 153, 157, 161],
+    },
+    OperatingClass {
+        number: 4,
+        global: 121,
+        band: Band::FiveGHz,
+        width: ChannelWidth::MHz20,
+        secondary_channel_offset: SecondaryChannelOffset::None,
+        channel_set: &[100, 104, 108, 112, 116, 120, 124, 128, 132, 136, 140, 144],
+    },
+    OperatingClass {
+        number: 5,
+        global: 125,
+        band: Band::FiveGHz,
+        width: ChannelWidth::MHz20,
+        secondary_channel_offset: SecondaryChannelOffset::None,
+        channel_set: &[149, 153, 157, 161, 165],
+    },
+    OperatingClass {
+        number: 12,
+        global: 81,
+        band: Band::TwoPointFourGHz,
+        width: ChannelWidth::MHz20,
+        secondary_channel_offset: SecondaryChannelOffset::None,
+        channel_set: &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11],
+    },
+    OperatingClass {
+        number: 22,
+        global: 116,
+        band: Band::FiveGHz,
+        width: ChannelWidth::MHz40,
+        secondary_channel_offset: SecondaryChannelOffset::Above,
+        channel_set: &[36, 44],
+    },
+    OperatingClass {
+        number: 23,
+        global: 119,
+        band: Band::FiveGHz,
+        width: ChannelWidth::MHz40,
+        secondary_channel_offset: SecondaryChannelOffset::Above,
+        channel_set: &[52, 60],
+    },
+    OperatingClass {
+        number: 24,
+        global: 122,
+        band: Band::FiveGHz,
+        width: ChannelWidth::MHz40,
+        secondary_channel_offset: SecondaryChannelOffset::Above,
+        channel_set: &[100, 108, 116, 124, 132, 140],
+    },
+    OperatingClass {
+        number: 25,
+        global: 126,
+        band: Band::FiveGHz,
+        width: ChannelWidth::MHz40,
+        secondary_channel_offset: SecondaryChannelOffset::Above,
+        channel_set: &[149, 157],
+    },
+    OperatingClass {
+        number: 26,
+        global: 126,
+        band: Band::FiveGHz,
+        width: ChannelWidth::MHz40,
+        secondary_channel_offset: SecondaryChannelOffset::Above,
+        channel_set: &[149, 157],
+    },
+    OperatingClass {
+        number: 27,
+        global: 117,
+        band: Band::FiveGHz,
+        width: ChannelWidth::MHz40,
+        secondary_channel_offset: SecondaryChannelOffset::Below,
+        channel_set: &[40, 48],
+    },
+    OperatingClass {
+        number: 28,
+        global: 120,
+        band: Band::FiveGHz,
+        width: ChannelWidth::MHz40,
+        secondary_channel_offset: SecondaryChannelOffset::Below,
+        channel_set: &[56, 64],
+    },
+    OperatingClass {
+        number: 29,
+        global: 123,
+        band: Band::FiveGHz,
+        width: ChannelWidth::MHz40,
+        secondary_channel_offset: SecondaryChannelOffset::Below,
+        channel_set: &[104, 112, 120, 128, 136, 144],
+    },
+    OperatingClass {
+        number: 30,
+        global: 127,
+        band: Band::FiveGHz,
+        width: ChannelWidth::MHz40,
+        secondary_channel_offset: SecondaryChannelOffset::Below,
+        channel_set: &[153, 161],
+    },
+    OperatingClass {
+        number: 31,
+        global: 127,
+        band: Band::FiveGHz,
+        width: ChannelWidth::MHz40,
+        secondary_channel_offset: SecondaryChannelOffset::Below,
+        channel_set: &[153, 161],
+    },
+    OperatingClass {
+        number: 32,
+        global: 83,
+        band: Band::TwoPointFourGHz,
+        width: ChannelWidth::MHz40,
+        secondary_channel_offset: SecondaryChannelOffset::Above,
+        channel_set: &[1, 2, 3, 4, 5, 6, 7],
+    },
+    OperatingClass {
+        number: 33,
+        global: 84,
+        band: Band::TwoPointFourGHz,
+        width: ChannelWidth::MHz40,
+        secondary_channel_offset: SecondaryChannelOffset::Below,
+        channel_set: &[5, 6, 7, 8, 9, 10, 11],
+    },
+    OperatingClass {
+        number: 128,
+        global: 128,
+        band: Band::FiveGHz,
+        width: ChannelWidth::MHz80,
+        secondary_channel_offset: SecondaryChannelOffset::None,
+        channel_set: &[42, 58, 106, 122, 138, 155, 171],
+    },
+    OperatingClass {
+        number: 129,
+        global: 129,
+        band: Band::FiveGHz,
+        width: ChannelWidth::MHz160,
+        secondary_channel_offset: SecondaryChannelOffset::None,
+        channel_set: &[50, 114, 163],
+    },
+    OperatingClass {
+        number: 130,
+        global: 130,
+        band: Band::FiveGHz,
+        width: ChannelWidth::MHz80Plus80,
+        secondary_channel_offset: SecondaryChannelOffset::None,
+        channel_set: &[42, 58, 106, 122, 138, 155, 171],
+    },
+    OperatingClass {
+        number: 131,
+        global: 131,
+        band: Band::SixGHz,
+        width: ChannelWidth::MHz20,
+        secondary_channel_offset: SecondaryChannelOffset::None,
+        channel_set: &[
+            1, 5, 9, 13, 17, 21, 25, 29, 33, 37, 41, 45, 49, 53, 57, 61, 65, 69, 73, 77, 81, 85,
+            89, 93, 97, 101, 105, 109, 113, 117, 121, 125, 129, 133, 137, 141, 145, 149, 153, 157,
+            161, 165, 169, 173, 177, 181, 185, 189, 193, 197, 201, 205, 209, 213, 217, 221, 225,
+            229, 233,
+        ],
+    },
+    OperatingClass {
+        number: 132,
+        global: 132,
+        band: Band::SixGHz,
+        width: ChannelWidth::MHz40,
+        secondary_channel_offset: SecondaryChannelOffset::None,
+        channel_set: &[
+            3, 11, 19, 27, 35, 43, 51, 59, 67, 75, 83, 91, 99, 107, 115, 123, 131, 139, 147, 155,
+            163, 171, 179, 187, 195, 203, 211, 219, 227,
+        ],
+    },
+    OperatingClass {
+        number: 133,
+        global: 133,
+        band: Band::SixGHz,
+        width: ChannelWidth::MHz80,
+        secondary_channel_offset: SecondaryChannelOffset::None,
+        channel_set: &[
+            7, 23, 39, 55, 71, 87, 103, 119, 135, 151, 167, 183, 199, 215,
+        ],
+    },
+    OperatingClass {
+        number: 134,
+        global: 134,
+        band: Band::SixGHz,
+        width: ChannelWidth::MHz160,
+        secondary_channel_offset: SecondaryChannelOffset::None,
+        channel_set: &[15, 47, 79, 111, 143, 175, 207],
+    },
+    OperatingClass {
+        number: 135,
+        global: 135,
+        band: Band::SixGHz,
+        width: ChannelWidth::MHz80Plus80,
+        secondary_channel_offset: SecondaryChannelOffset::None,
+        channel_set: &[
+            7, 23, 39, 55, 71, 87, 103, 119, 135, 151, 167, 183, 199, 215,
+        ],
+    },
+    OperatingClass {
+        number: 136,
+        global: 136,
+        band: Band::SixGHz,
+        width: ChannelWidth::MHz20,
+        secondary_channel_offset: SecondaryChannelOffset::None,
+        channel_set: &[2],
+    },
+    OperatingClass {
+        number: 137,
+        global: 137,
+        band: Band::SixGHz,
+        width: ChannelWidth::MHz320,
+        secondary_channel_offset: SecondaryChannelOffset::None,
+        channel_set: &[31, 63, 95, 127, 159, 191],
+    },
+];
+
+/// Operating classes in Europe (802.11 Annex E Table E-2)
+pub const EU_OPERATING_CLASSES: &[OperatingClass] = &[
+    OperatingClass {
+        number: 1,
+        global: 115,
+        band: Band::FiveGHz,
+        width: ChannelWidth::MHz20,
+        secondary_channel_offset: SecondaryChannelOffset::None,
+        channel_set: &[36, 40, 44, 48],
+    },
+    OperatingClass {
+        number: 2,
+        global: 118,
+        band: Band::FiveGHz,
+        width: ChannelWidth::MHz20,
+        secondary_channel_offset: SecondaryChannelOffset::None,
+        channel_set: &[52, 56, 60, 64],
+    },
+    OperatingClass {
+        number: 3,
+        global: 121,
+        band: Band::FiveGHz,
+        width: ChannelWidth::MHz20,
+        secondary_channel_offset: SecondaryChannelOffset::None,
+        channel_set: &[100, 104, 108, 112, 116, 120, 124, 128, 132, 136, 140],
+    },
+    OperatingClass {
+        number: 4,
+        global: 81,
+        band: Band::TwoPointFourGHz,
+        width: ChannelWidth::MHz20,
+        secondary_channel_offset: SecondaryChannelOffset::None,
+        channel_set: &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13],
+    },
+    OperatingClass {
+        number: 5,
+        global: 116,
+        band: Band::FiveGHz,
+        width: ChannelWidth::MHz40,
+        secondary_channel_offset: SecondaryChannelOffset::Above,
+        channel_set: &[36, 44],
+    },
+    OperatingClass {
+        number: 6,
+        global: 119,
+        band: Band::FiveGHz,
+        width: ChannelWidth::MHz40,
+        secondary_channel_offset: SecondaryChannelOffset::Above,
+        channel_set: &[52, 60],
+    },
+    OperatingClass {
+        number: 7,
+        global: 122,
+        band: Band::FiveGHz,
+        width: ChannelWidth::MHz40,
+        secondary_channel_offset: SecondaryChannelOffset::Above,
+        channel_set: &[100, 108, 116, 124, 132],
+    },
+    OperatingClass {
+        number: 8,
+        global: 117,
+        band: Band::FiveGHz,
+        width: ChannelWidth::MHz40,
+        secondary_channel_offset: SecondaryChannelOffset::Below,
+        channel_set: &[40, 48],
+    },
+    OperatingClass {
+        number: 9,
+        global: 120,
+        band: Band::FiveGHz,
+        width: ChannelWidth::MHz40,
+        secondary_channel_offset: SecondaryChannelOffset::Below,
+        channel_set: &[56, 64],
+    },
+    OperatingClass {
+        number: 10,
+        global: 123,
+        band: Band::FiveGHz,
+        width: ChannelWidth::MHz40,
+        secondary_channel_offset: SecondaryChannelOffset::Below,
+        channel_set: &[104, 112, 120, 128, 136],
+    },
+    OperatingClass {
+        number: 11,
+        global: 83,
+        band: Band::TwoPointFourGHz,
+        width: ChannelWidth::MHz40,
+        secondary_channel_offset: SecondaryChannelOffset::Above,
+        channel_set: &[1, 2, 3, 4, 5, 6, 7, 8, 9],
+    },
+    OperatingClass {
+        number: 12,
+        global: 84,
+        band: Band::TwoPointFourGHz,
+        width: ChannelWidth::MHz40,
+        secondary_channel_offset: SecondaryChannelOffset::Below,
+        channel_set: &[5, 6, 7, 8, 9, 10, 11, 12, 13],
+    },
+    OperatingClass {
+        number: 17,
+        global: 125,
+        band: Band::FiveGHz,
+        width: ChannelWidth::MHz20,
+        secondary_channel_offset: SecondaryChannelOffset::None,
+        channel_set: &[149, 153, 157, 161, 165, 169],
+    },
+    OperatingClass {
+        number: 18,
+        global: 180,
+        band: Band::SixtyGHz,
+        width: ChannelWidth::MHz2160,
+        secondary_channel_offset: SecondaryChannelOffset::None,
+        channel_set: &[1, 2, 3, 4, 5, 6],
+    },
+    OperatingClass {
+        number: 128,
+        global: 128,
+        band: Band::FiveGHz,
+        width: ChannelWidth::MHz80,
+        secondary_channel_offset: SecondaryChannelOffset::None,
+        channel_set: &[42, 58, 106, 122, 138, 155, 171],
+    },
+    OperatingClass {
+        number: 129,
+        global: 129,
+        band: Band::FiveGHz,
+        width: ChannelWidth::MHz160,
+        secondary_channel_offset: SecondaryChannelOffset::None,
+        channel_set: &[50, 114, 163],
+    },
+    OperatingClass {
+        number: 130,
+        global: 130,
+        band: Band::FiveGHz,
+        width: ChannelWidth::MHz80Plus80,
+        secondary_channel_offset: SecondaryChannelOffset::None,
+        channel_set: &[42, 58, 106, 122, 138, 155, 171],
+    },
+    OperatingClass {
+        number: 131,
+        global: 131,
+        band: Band::SixGHz,
+        width: ChannelWidth::MHz20,
+        secondary_channel_offset: SecondaryChannelOffset::None,
+        channel_set: &[
+            1, 5, 9, 13, 17, 21, 25, 29, 33, 37, 41, 45, 49, 53, 57, 61, 65, 69, 73, 77, 81, 85,
+            89, 93, 97, 101, 105, 109, 113, 117, 121, 125, 129, 133, 137, 141, 145, 149, 153, 157,
+            161, 165, 169, 173, 177, 181, 185, 189, 193, 197, 201, 205, 209, 213, 217, 221, 225,
+            229, 233,
+        ],
+    },
+    OperatingClass {
+        number: 132,
+        global: 132,
+        band: Band::SixGHz,
+        width: ChannelWidth::MHz40,
+        secondary_channel_offset: SecondaryChannelOffset::None,
+        channel_set: &[
+            3, 11, 19, 27, 35, 43, 51, 59, 67, 75, 83, 91, 99, 107, 115, 123, 131, 139, 147, 155,
+            163, 171, 179, 187, 195, 203, 211, 219, 227,
+        ],
+    },
+    OperatingClass {
+        number: 133,
+        global: 133,
+        band: Band::SixGHz,
+        width: ChannelWidth::MHz80,
+        secondary_channel_offset: SecondaryChannelOffset::None,
+        channel_set: &[
+            7, 23, 39, 55, 71, 87, 103, 119, 135, 151, 167, 183, 199, 215,
+        ],
+    },
+    OperatingClass {
+        number: 134,
+        global: 134,
+        band: Band::SixGHz,
+        width: ChannelWidth::MHz160,
+        secondary_channel_offset: SecondaryChannelOffset::None,
+        channel_set: &[15, 47, 79, 111, 143, 175, 207],
+    },
+    OperatingClass {
+        number: 135,
+        global: 135,
+        band: Band::SixGHz,
+        width: ChannelWidth::MHz80Plus80,
+        secondary_channel_offset: SecondaryChannelOffset::None,
+        channel_set: &[
+            7, 23, 39, 55, 71, 87, 103, 119, 135, 151, 167, 183, 199, 215,
+        ],
+    },
+    OperatingClass {
+        number: 136,
+        global: 136,
+        band: Band::SixGHz,
+        width: ChannelWidth::MHz20,
+        secondary_channel_offset: SecondaryChannelOffset::None,
+        channel_set: &[2],
+    },
+    OperatingClass {
+        number: 137,
+        global: 137,
+        band: Band::SixGHz,
+        width: ChannelWidth::MHz320,
+        secondary_channel_offset: SecondaryChannelOffset::None,
+        channel_set: &[31, 63, 95, 127, 159, 191],
+    },
+];
+
+/// Operating classes in Japan (802.11 Annex E Table E-3)
+pub const JP_OPERATING_CLASSES: &[OperatingClass] = &[
+    OperatingClass {
+        number: 1,
+        global: 115,
+        band: Band::FiveGHz,
+        width: ChannelWidth::MHz20,
+        secondary_channel_offset: SecondaryChannelOffset::None,
+        channel_set: &[36, 40, 44, 48],
+    },
+    OperatingClass {
+        number: 30,
+        global: 81,
+        band: Band::TwoPointFourGHz,
+        width: ChannelWidth::MHz20,
+        secondary_channel_offset: SecondaryChannelOffset::None,
+        channel_set: &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13],
+    },
+    OperatingClass {
+        number: 31,
+        global: 82,
+        band: Band::TwoPointFourGHz,
+        width: ChannelWidth::MHz20,
+        secondary_channel_offset: SecondaryChannelOffset::None,
+        channel_set: &[14],
+    },
+    OperatingClass {
+        number: 32,
+        global: 118,
+        band: Band::FiveGHz,
+        width: ChannelWidth::MHz20,
+        secondary_channel_offset: SecondaryChannelOffset::None,
+        channel_set: &[52, 56, 60, 64],
+    },
+    OperatingClass {
+        number: 33,
+        global: 118,
+        band: Band::FiveGHz,
+        width: ChannelWidth::MHz20,
+        secondary_channel_offset: SecondaryChannelOffset::None,
+        channel_set: &[52, 56, 60, 64],
+    },
+    OperatingClass {
+        number: 34,
+        global: 121,
+        band: Band::FiveGHz,
+        width: ChannelWidth::MHz20,
+        secondary_channel_offset: SecondaryChannelOffset::None,
+        channel_set: &[100, 104, 108, 112, 116, 120, 124, 128, 132, 136, 140],
+    },
+    OperatingClass {
+        number: 35,
+        global: 121,
+        band: Band::FiveGHz,
+        width: ChannelWidth::MHz20,
+        secondary_channel_offset: SecondaryChannelOffset::None,
+        channel_set: &[100, 104, 108, 112, 116, 120, 124, 128, 132, 136, 140],
+    },
+    OperatingClass {
+        number: 36,
+        global: 116,
+        band: Band::FiveGHz,
+        width: ChannelWidth::MHz40,
+        secondary_channel_offset: SecondaryChannelOffset::Above,
+        channel_set: &[36, 44],
+    },
+    OperatingClass {
+        number: 37,
+        global: 119,
+        band: Band::FiveGHz,
+        width: ChannelWidth::MHz40,
+        secondary_channel_offset: SecondaryChannelOffset::Above,
+        channel_set: &[52, 60],
+    },
+    OperatingClass {
+        number: 38,
+        global: 119,
+        band: Band::FiveGHz,
+        width: ChannelWidth::MHz40,
+        secondary_channel_offset: SecondaryChannelOffset::Above,
+        channel_set: &[52, 60],
+    },
+    OperatingClass {
+        number: 39,
+        global: 122,
+        band: Band::FiveGHz,
+        width: ChannelWidth::MHz40,
+        secondary_channel_offset: SecondaryChannelOffset::Above,
+        channel_set: &[100, 108, 116, 124, 132],
+    },
+    OperatingClass {
+        number: 40,
+        global: 122,
+        band: Band::FiveGHz,
+        width: ChannelWidth::MHz40,
+        secondary_channel_offset: SecondaryChannelOffset::Above,
+        channel_set: &[100, 108, 116, 124, 132],
+    },
+    OperatingClass {
+        number: 41,
+        global: 117,
+        band: Band::FiveGHz,
+        width: ChannelWidth::MHz40,
+        secondary_channel_offset: SecondaryChannelOffset::Below,
+        channel_set: &[40, 48],
+    },
+    OperatingClass {
+        number: 42,
+        global: 120,
+        band: Band::FiveGHz,
+        width: ChannelWidth::MHz40,
+        secondary_channel_offset: SecondaryChannelOffset::Below,
+        channel_set: &[56, 64],
+    },
+    OperatingClass {
+        number: 43,
+        global: 120,
+        band: Band::FiveGHz,
+        width: ChannelWidth::MHz40,
+        secondary_channel_offset: SecondaryChannelOffset::Below,
+        channel_set: &[56, 64],
+    },
+    OperatingClass {
+        number: 44,
+        global: 123,
+        band: Band::FiveGHz,
+        width: ChannelWidth::MHz40,
+        secondary_channel_offset: SecondaryChannelOffset::Below,
+        channel_set: &[104, 112, 120, 128, 136],
+    },
+    OperatingClass {
+        number: 45,
+        global: 123,
+        band: Band::FiveGHz,
+        width: ChannelWidth::MHz40,
+        secondary_channel_offset: SecondaryChannelOffset::Below,
+        channel_set: &[104, 112, 120, 128, 136],
+    },
+    OperatingClass {
+        number: 56,
+        global: 83,
+        band: Band::TwoPointFourGHz,
+        width: ChannelWidth::MHz40,
+        secondary_channel_offset: SecondaryChannelOffset::Above,
+        channel_set: &[1, 2, 3, 4, 5, 6, 7, 8, 9],
+    },
+    OperatingClass {
+        number: 57,
+        global: 84,
+        band: Band::TwoPointFourGHz,
+        width: ChannelWidth::MHz40,
+        secondary_channel_offset: SecondaryChannelOffset::Below,
+        channel_set: &[5, 6, 7, 8, 9, 10, 11, 12, 13],
+    },
+    OperatingClass {
+        number: 59,
+        global: 180,
+        band: Band::SixtyGHz,
+        width: ChannelWidth::MHz2160,
+        secondary_channel_offset: SecondaryChannelOffset::None,
+        channel_set: &[1, 2, 3, 4, 5, 6],
+    },
+    OperatingClass {
+        number: 128,
+        global: 128,
+        band: Band::FiveGHz,
+        width: ChannelWidth::MHz80,
+        secondary_channel_offset: SecondaryChannelOffset::None,
+        channel_set: &[42, 58, 106, 122, 138, 155, 171],
+    },
+    OperatingClass {
+        number: 129,
+        global: 129,
+        band: Band::FiveGHz,
+        width: ChannelWidth::MHz160,
+        secondary_channel_offset: SecondaryChannelOffset::None,
+        channel_set: &[50, 114, 163],
+    },
+    OperatingClass {
+        number: 130,
+        global: 130,
+        band: Band::FiveGHz,
+        width: ChannelWidth::MHz80Plus80,
+        secondary_channel_offset: SecondaryChannelOffset::None,
+        channel_set: &[42, 58, 106, 122, 138, 155, 171],
+    },
+];
+
+#[test]
+fn test_operating_class() {
+    let operating_class = OperatingClass::global(116).unwrap();
+    assert_eq!(operating_class.band, Band::FiveGHz, "116 band");
+    assert_eq!(operating_class.width, ChannelWidth::MHz40, "116 width");
+    assert_eq!(
+        operating_class
+            .channels()
+            .map(|channel| channel.center_number())
+            .collect::<Vec<_>>(),
+        [Some(38), Some(46)],
+        "116 channels"
+    );
+    assert_eq!(operating_class.channel(40), None, "116 channel 40");
+
+    assert_eq!(Region::UnitedStates.to_global(12), Some(81), "US 12");
+    assert_eq!(Region::Europe.from_global(83), Some(11), "EU 83");
+    assert_eq!(Region::Japan.to_global(31), Some(82), "JP 31");
+    assert_eq!(
+        Region::from_country_code(*b"DE"),
+        Some(Region::Europe),
+        "DE"
+    );
+
+    // channel 13 is not used in the United States
+    let channel = Channel::new(Band::TwoPointFourGHz, 13).unwrap();
+    assert_eq!(
+        Region::Global
+            .find_operating_class(&channel)
+            .map(|operating_class| operating_class.number),
+        Some(81),
+        "global 2.4 GHz"
+    );
+    assert_eq!(
+        Region::UnitedStates.find_operating_class(&channel),
+        None,
+        "US channel 13"
+    );
+
+    let channel = Channel::with_width(Band::FiveGHz, 100, ChannelWidth::MHz80).unwrap();
+    assert_eq!(
+        Region::Global
+            .find_operating_class(&channel)
+            .map(|operating_class| operating_class.number),
+        Some(128),
+        "global 80 MHz"
+    );
+}
+
+#[test]
+fn test_operating_class_wide_channels() {
+    // the channel sets of the wide classes list center channels
+    for number in [128, 129, 130, 132, 133, 134, 135, 137] {
+        let operating_class = OperatingClass::global(number).unwrap();
+        assert_eq!(
+            operating_class
+                .channels()
+                .map(|channel| channel.center_number().unwrap())
+                .collect::<Vec<_>>(),
+            operating_class.channel_set,
+            "{number} channels"
+        );
+    }
+
+    let operating_class = OperatingClass::global(129).unwrap();
+    let channel = operating_class.channel(114).unwrap();
+    assert_eq!(channel.number, 100, "129 primary");
+    assert_eq!(channel.center_frequency, 5570, "129 center");
+    let channel = operating_class.channel(128).unwrap();
+    assert_eq!(channel.center_number(), Some(114), "129 channel 128");
+    assert_eq!(operating_class.channel(106), None, "129 channel 106");
+
+    let operating_class = OperatingClass::global(133).unwrap();
+    let channel = operating_class.channel(39).unwrap();
+    assert_eq!(channel.number, 33, "133 primary");
+    assert_eq!(channel.center_frequency, 6145, "133 center");
+
+    // both 320 MHz channelizations, 320-2 is offset by 160 MHz
+    let operating_class = OperatingClass::global(137).unwrap();
+    let channel = operating_class.channel(31).unwrap();
+    assert_eq!(channel.number, 1, "137 channel 31 primary");
+    assert_eq!(channel.center_frequency, 6105, "137 channel 31 center");
+    let channel = operating_class.channel(63).unwrap();
+    assert_eq!(channel.number, 33, "137 channel 63 primary");
+    assert_eq!(channel.center_frequency, 6265, "137 channel 63 center");
+    assert_eq!(
+        operating_class
+            .channel(127)
+            .map(|channel| channel.center_frequency),
+        Some(6585),
+        "137 channel 127"
+    );
+    assert_eq!(
+        operating_class
+            .channel(191)
+            .map(|channel| channel.center_frequency),
+        Some(6905),
+        "137 channel 191"
+    );
+    assert_eq!(operating_class.channel(47), None, "137 channel 47");
+
+    // primary channels default to 320-1, above it to 320-2
+    let channel = operating_class.channel(33).unwrap();
+    assert_eq!(channel.center_number(), Some(31), "137 channel 33");
+    let channel = operating_class.channel(197).unwrap();
+    assert_eq!(channel.center_number(), Some(191), "137 channel 197");
+
+    let channel = Channel::new(Band::SixGHz, 41)
+        .unwrap()
+        .with_center(ChannelWidth::MHz320, 63, None)
+        .unwrap();
+    assert!(operating_class.contains(&channel), "137 contains 320-2");
+}
@@ -41,6 +41,8 @@ struct TestItem<'a> {
 
     rsn: Option<RSNVersion>,
 
+    wpa: Option<WPAVersion>,
+
     channel: Option<u8>,
 }
 
@@ -162,6 +164,12 @@ fn test_test_item(test_item: TestItem) {
                                 "rsn",
                             );
 
+                            check(
+                                beacon_frame.tagged_parameters().unwrap().wpa(),
+                                test_item.wpa,
+                                "wpa",
+                            );
+
                             check(
                                 beacon_frame.tagged_parameters().unwrap().channel(),
                                 test_item.channel,
@@ -275,9 +283,16 @@ include!("./packets/deauthentication.rs");
 include!("./packets/disassociate.rs");
 include!("./packets/association_request.rs");
 include!("./packets/association_response.rs");
+include!("./packets/reassociation_request.rs");
 include!("./packets/probe_request.rs");
+include!("./packets/probe_request_wps.rs");
 include!("./packets/probe_response.rs");
 include!("./packets/beacon_ciphers.rs");
+include!("./packets/beacon_wmm.rs");
+include!("./packets/beacon_country.rs");
+include!("./packets/action_p2p.rs");
+include!("./packets/action_gas.rs");
+include!("./packets/action_bss_transition.rs");
 
 // Control
 include!("./packets/power_save_poll.rs");
@@ -293,5 +308,9 @@ include!("./packets/data.rs");
 include!("./packets/data_layer.rs");
 include!("./packets/qos_data.rs");
 include!("./packets/null_data.rs");
+include!("./packets/data_wep.rs");
+include!("./packets/data_ccmp.rs");
+include!("./packets/data_tkip.rs");
+include!("./packets/eapol.rs");
 
 include!("./packets/very_bad.rs");
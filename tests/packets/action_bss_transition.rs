@@ -0,0 +1,125 @@
+const ACTION_BSS_TRANSITION_MANAGEMENT_REQUEST: [u8; 72] = [
+  0xd0, 0x00, 0x3a, 0x01, 0x02, 0x11, 0x22, 0x33, 0x44, 0x55, 0x00, 0x11, 0x22, 0xaa, 0xbb, 0xcc,
+  0x00, 0x11, 0x22, 0xaa, 0xbb, 0xcc, 0x90, 0x01, 0x0a, 0x07, 0x03, 0x05, 0xc8, 0x00, 0xff, 0x34,
+  0x15, 0x00, 0x11, 0x22, 0xaa, 0xbb, 0xdd, 0xaf, 0x18, 0x00, 0x00, 0x73, 0x24, 0x09, 0x03, 0x01,
+  0xff, 0x06, 0x03, 0x01, 0x2a, 0x00, 0x34, 0x10, 0x00, 0x11, 0x22, 0xaa, 0xbb, 0xee, 0x07, 0x40,
+  0x00, 0x00, 0x83, 0x05, 0x0e, 0x03, 0x01, 0x64,
+];
+
+#[test]
+fn test_action_bss_transition_management_request() {
+  // Transmitter address: 00:11:22:aa:bb:cc
+  // Disassociation imminent, candidates on 5 GHz 80 MHz and 6 GHz
+
+  test_test_item(TestItem {
+    bytes: &ACTION_BSS_TRANSITION_MANAGEMENT_REQUEST,
+    subtype: Some(FrameSubtype::Management(ManagementSubtype::Action)),
+
+    ds_status: Some(DSStatus::NotLeavingDSOrADHOC),
+    duration_id: Some(DurationID::Duration(314)),
+
+    receiver_address: "02:11:22:33:44:55".parse().unwrap(),
+    destination_address: Some("02:11:22:33:44:55".parse().unwrap()),
+
+    transmitter_address: Some("00:11:22:aa:bb:cc".parse().unwrap()),
+    source_address: Some("00:11:22:aa:bb:cc".parse().unwrap()),
+
+    bssid_address: Some("00:11:22:aa:bb:cc".parse().unwrap()),
+
+    fragment_number: Some(0),
+    sequence_number: Some(25),
+
+    ..Default::default()
+  });
+
+  let action_frame = ActionFrame::new(&ACTION_BSS_TRANSITION_MANAGEMENT_REQUEST[..]);
+  let wnm_action_frame = match action_frame.next_layer() {
+    Some(ActionFrameLayer::WNM(wnm_action_frame)) => wnm_action_frame,
+    _ => panic!("not a wnm action frame"),
+  };
+  let btm_frame = match wnm_action_frame.next_layer() {
+    Some(WNMActionFrameLayer::BSSTransitionManagementRequest(btm_frame)) => btm_frame,
+    _ => panic!("not a bss transition management request frame"),
+  };
+
+  assert_eq!(btm_frame.dialog_token(), 3, "dialog_token");
+  let request_mode = btm_frame.request_mode();
+  assert!(
+    request_mode.preferred_candidate_list_included,
+    "preferred_candidate_list_included"
+  );
+  assert!(
+    request_mode.disassociation_imminent,
+    "disassociation_imminent"
+  );
+  assert_eq!(btm_frame.disassociation_timer(), 200, "disassociation_timer");
+  assert_eq!(btm_frame.validity_interval(), 255, "validity_interval");
+  assert_eq!(
+    btm_frame.bss_termination_duration(),
+    None,
+    "bss_termination_duration"
+  );
+
+  let candidate_list = btm_frame.candidate_list();
+  assert_eq!(candidate_list.len(), 2, "candidate_list");
+
+  let candidate = &candidate_list[0];
+  assert_eq!(
+    candidate.bssid,
+    "00:11:22:aa:bb:dd".parse().unwrap(),
+    "bssid"
+  );
+  assert_eq!(
+    candidate.bssid_information.ap_reachability,
+    APReachability::Reachable,
+    "ap_reachability"
+  );
+  assert!(candidate.bssid_information.security, "security");
+  assert!(
+    candidate.bssid_information.very_high_throughput,
+    "very_high_throughput"
+  );
+  assert!(
+    !candidate.bssid_information.high_efficiency,
+    "high_efficiency"
+  );
+  assert_eq!(
+    candidate.bssid_information.into_u32(),
+    0x18af,
+    "bssid_information"
+  );
+  assert_eq!(candidate.phy_type, PhyType::VHT, "phy_type");
+  assert_eq!(candidate.preference(), Some(255), "preference");
+  let channel = candidate.channel(Region::Global).unwrap();
+  assert_eq!(channel.number, 36, "channel");
+  assert_eq!(channel.width, ChannelWidth::MHz80, "width");
+  assert_eq!(channel.center_frequency, 5210, "center_frequency");
+
+  // the operating class is looked up in the table of the region
+  let regional = NeighborReport {
+    operating_class: 1,
+    subelements: Vec::new(),
+    ..candidate.clone()
+  };
+  assert_eq!(
+    regional
+      .channel(Region::UnitedStates)
+      .map(|channel| channel.frequency),
+    Some(5180),
+    "US class 1"
+  );
+  assert_eq!(regional.channel(Region::Global), None, "global class 1");
+
+  let candidate = &candidate_list[1];
+  assert_eq!(candidate.phy_type, PhyType::HE, "phy_type");
+  assert_eq!(candidate.preference(), Some(100), "preference");
+  let channel = candidate.channel(Region::Global).unwrap();
+  assert_eq!(channel.band, Band::SixGHz, "band");
+  assert_eq!(channel.frequency, 5975, "frequency");
+  assert_eq!(candidate.channel(Region::Japan), None, "JP 6 GHz");
+  assert_eq!(
+    NeighborReport::from_bytes(&candidate.to_bytes()).as_ref(),
+    Some(candidate),
+    "to_bytes"
+  );
+}
@@ -0,0 +1,121 @@
+const ACTION_GAS_INITIAL_RESPONSE: [u8; 135] = [
+  0xd0, 0x00, 0x3a, 0x01, 0x02, 0x11, 0x22, 0x33, 0x44, 0x55, 0x00, 0x11, 0x22, 0xaa, 0xbb, 0xcc,
+  0x00, 0x11, 0x22, 0xaa, 0xbb, 0xcc, 0x80, 0x0c, 0x04, 0x0b, 0x07, 0x00, 0x00, 0x00, 0x00, 0x6c,
+  0x02, 0x7f, 0x00, 0x62, 0x00, 0x02, 0x01, 0x0f, 0x00, 0x02, 0x08, 0x0c, 0x65, 0x6e, 0x67, 0x54,
+  0x65, 0x73, 0x74, 0x20, 0x43, 0x61, 0x66, 0x65, 0x07, 0x01, 0x18, 0x00, 0x01, 0x00, 0x14, 0x00,
+  0x00, 0x0b, 0x65, 0x78, 0x61, 0x6d, 0x70, 0x6c, 0x65, 0x2e, 0x63, 0x6f, 0x6d, 0x01, 0x05, 0x15,
+  0x01, 0x02, 0x01, 0x04, 0x08, 0x01, 0x08, 0x00, 0x00, 0x06, 0x00, 0x04, 0x01, 0x13, 0x00, 0x14,
+  0x0c, 0x01, 0x0c, 0x00, 0x0b, 0x65, 0x78, 0x61, 0x6d, 0x70, 0x6c, 0x65, 0x2e, 0x63, 0x6f, 0x6d,
+  0xdd, 0xdd, 0x13, 0x00, 0x50, 0x6f, 0x9a, 0x11, 0x04, 0x00, 0x01, 0x10, 0x27, 0x00, 0x00, 0xe8,
+  0x03, 0x00, 0x00, 0x20, 0x10, 0x0a, 0x00,
+];
+
+#[test]
+fn test_action_gas_initial_response() {
+  // Transmitter address: 00:11:22:aa:bb:cc
+  // ANQP venue name, NAI realm, 3GPP, domain name and HS2.0 WAN metrics
+
+  test_test_item(TestItem {
+    bytes: &ACTION_GAS_INITIAL_RESPONSE,
+    subtype: Some(FrameSubtype::Management(ManagementSubtype::Action)),
+
+    ds_status: Some(DSStatus::NotLeavingDSOrADHOC),
+    duration_id: Some(DurationID::Duration(314)),
+
+    receiver_address: "02:11:22:33:44:55".parse().unwrap(),
+    destination_address: Some("02:11:22:33:44:55".parse().unwrap()),
+
+    transmitter_address: Some("00:11:22:aa:bb:cc".parse().unwrap()),
+    source_address: Some("00:11:22:aa:bb:cc".parse().unwrap()),
+
+    bssid_address: Some("00:11:22:aa:bb:cc".parse().unwrap()),
+
+    fragment_number: Some(0),
+    sequence_number: Some(200),
+
+    ..Default::default()
+  });
+
+  let action_frame = ActionFrame::new(&ACTION_GAS_INITIAL_RESPONSE[..]);
+  let public_action_frame = match action_frame.next_layer() {
+    Some(ActionFrameLayer::Public(public_action_frame)) => public_action_frame,
+    _ => panic!("not a public action frame"),
+  };
+  let gas_frame = match public_action_frame.next_layer() {
+    Some(PublicActionFrameLayer::GASInitialResponse(gas_frame)) => gas_frame,
+    _ => panic!("not a gas initial response frame"),
+  };
+
+  assert_eq!(gas_frame.dialog_token(), 7, "dialog_token");
+  assert_eq!(gas_frame.status_code(), StatusCode::Successful, "status_code");
+  assert_eq!(gas_frame.comeback_delay(), 0, "comeback_delay");
+  assert!(
+    gas_frame.advertisement_protocol().unwrap().has_anqp(),
+    "advertisement_protocol"
+  );
+
+  let anqp = gas_frame.anqp().unwrap();
+  assert_eq!(anqp.elements.len(), 5, "elements");
+
+  let venue_name = anqp.venue_name().unwrap();
+  assert_eq!(
+    venue_name.venue_info,
+    VenueInfo { group: 2, type_: 8 },
+    "venue_info"
+  );
+  assert_eq!(
+    venue_name.names,
+    vec![ANQPName {
+      language: *b"eng",
+      name: b"Test Cafe".to_vec(),
+    }],
+    "venue_name"
+  );
+
+  assert_eq!(
+    anqp.nai_realms(),
+    Some(
+      &[NAIRealm {
+        utf8: false,
+        realm: b"example.com".to_vec(),
+        eap_methods: vec![NAIRealmEAPMethod {
+          eap_method: 21,
+          authentication_parameters: vec![(2, vec![4])],
+        }],
+      }][..]
+    ),
+    "nai_realms"
+  );
+
+  assert_eq!(
+    anqp.cellular_networks(),
+    Some(
+      &[PLMN {
+        mcc: "310".to_string(),
+        mnc: "410".to_string(),
+      }][..]
+    ),
+    "cellular_networks"
+  );
+
+  assert_eq!(
+    anqp.domain_names(),
+    Some(&[b"example.com".to_vec()][..]),
+    "domain_names"
+  );
+
+  assert_eq!(
+    anqp.hotspot20().collect::<Vec<_>>(),
+    vec![&HotSpot20ANQPElement::WANMetrics(HotSpot20WANMetrics {
+      link_status: 1,
+      symmetric_link: false,
+      at_capacity: false,
+      downlink_speed: 10000,
+      uplink_speed: 1000,
+      downlink_load: 0x20,
+      uplink_load: 0x10,
+      load_measurement_duration: 10,
+    })],
+    "hotspot20"
+  );
+}
@@ -0,0 +1,134 @@
+const ACTION_P2P_GO_NEGOTIATION_REQUEST: [u8; 123] = [
+  0xd0, 0x00, 0x00, 0x00, 0x02, 0x11, 0x22, 0x33, 0x44, 0x55, 0x02, 0xaa, 0xbb, 0xcc, 0xdd, 0xee,
+  0x02, 0xaa, 0xbb, 0xcc, 0xdd, 0xee, 0x40, 0x06, 0x04, 0x09, 0x50, 0x6f, 0x9a, 0x09, 0x00, 0x05,
+  0xdd, 0x15, 0x50, 0x6f, 0x9a, 0x09, 0x02, 0x02, 0x00, 0x25, 0x00, 0x04, 0x01, 0x00, 0x0f, 0x05,
+  0x02, 0x00, 0x64, 0x64, 0x06, 0x05, 0x00, 0xdd, 0x42, 0x50, 0x6f, 0x9a, 0x09, 0x58, 0x58, 0x04,
+  0x51, 0x06, 0x09, 0x06, 0x00, 0x02, 0xaa, 0xbb, 0xcc, 0xdd, 0xef, 0x0b, 0x08, 0x00, 0x58, 0x58,
+  0x04, 0x51, 0x03, 0x01, 0x06, 0x0b, 0x0d, 0x1a, 0x00, 0x02, 0xaa, 0xbb, 0xcc, 0xdd, 0xee, 0x01,
+  0x88, 0x00, 0x0a, 0x00, 0x50, 0xf2, 0x04, 0x00, 0x05, 0x00, 0x10, 0x11, 0x00, 0x05, 0x50, 0x68,
+  0x6f, 0x6e, 0x65, 0x11, 0x05, 0x00, 0x58, 0x58, 0x04, 0x51, 0x06,
+];
+
+#[test]
+fn test_action_p2p_go_negotiation_request() {
+  // Transmitter address: 02:aa:bb:cc:dd:ee
+  // P2P attributes split across two vendor specific elements
+
+  test_test_item(TestItem {
+    bytes: &ACTION_P2P_GO_NEGOTIATION_REQUEST,
+    subtype: Some(FrameSubtype::Management(ManagementSubtype::Action)),
+
+    ds_status: Some(DSStatus::NotLeavingDSOrADHOC),
+    duration_id: Some(DurationID::Duration(0)),
+
+    receiver_address: "02:11:22:33:44:55".parse().unwrap(),
+    destination_address: Some("02:11:22:33:44:55".parse().unwrap()),
+
+    transmitter_address: Some("02:aa:bb:cc:dd:ee".parse().unwrap()),
+    source_address: Some("02:aa:bb:cc:dd:ee".parse().unwrap()),
+
+    bssid_address: Some("02:aa:bb:cc:dd:ee".parse().unwrap()),
+
+    fragment_number: Some(0),
+    sequence_number: Some(100),
+
+    ..Default::default()
+  });
+
+  let action_frame = ActionFrame::new(&ACTION_P2P_GO_NEGOTIATION_REQUEST[..]);
+  assert_eq!(action_frame.category(), ActionCategory::Public, "category");
+
+  let public_action_frame = match action_frame.next_layer() {
+    Some(ActionFrameLayer::Public(public_action_frame)) => public_action_frame,
+    _ => panic!("not a public action frame"),
+  };
+  assert_eq!(
+    public_action_frame.public_action(),
+    PublicAction::VendorSpecific,
+    "public_action"
+  );
+
+  let p2p_frame = match public_action_frame.next_layer() {
+    Some(PublicActionFrameLayer::P2P(p2p_frame)) => p2p_frame,
+    _ => panic!("not a p2p public action frame"),
+  };
+  assert_eq!(
+    p2p_frame.p2p_subtype(),
+    P2PPublicActionSubtype::GONegotiationRequest,
+    "p2p_subtype"
+  );
+  assert_eq!(p2p_frame.dialog_token(), 5, "dialog_token");
+
+  let p2p = p2p_frame.p2p().unwrap();
+
+  let capability = p2p.capability().unwrap();
+  assert!(
+    capability.device_capability.service_discovery,
+    "service_discovery"
+  );
+  assert!(
+    capability.device_capability.concurrent_operation,
+    "concurrent_operation"
+  );
+  assert!(
+    capability.device_capability.invitation_procedure,
+    "invitation_procedure"
+  );
+  assert!(!capability.group_capability.group_owner, "group_owner");
+
+  assert_eq!(
+    p2p.group_owner_intent(),
+    Some(P2PGroupOwnerIntent {
+      intent: 7,
+      tie_breaker: true,
+    }),
+    "group_owner_intent"
+  );
+
+  let channel = P2PChannel {
+    country_string: *b"XX\x04",
+    operating_class: 81,
+    channel: 6,
+  };
+  assert_eq!(p2p.listen_channel(), Some(channel), "listen_channel");
+  assert_eq!(p2p.operating_channel(), Some(channel), "operating_channel");
+  assert_eq!(
+    channel.to_channel().map(|channel| channel.frequency),
+    Some(2437),
+    "operating_channel frequency"
+  );
+
+  assert!(
+    p2p.attributes.contains(&P2PAttribute::IntendedInterfaceAddress(
+      "02:aa:bb:cc:dd:ef".parse().unwrap()
+    )),
+    "intended_interface_address"
+  );
+  assert!(
+    p2p.attributes.contains(&P2PAttribute::ChannelList(P2PChannelList {
+      country_string: *b"XX\x04",
+      entries: vec![(81, vec![1, 6, 11])],
+    })),
+    "channel_list"
+  );
+
+  let device_info = p2p.device_info().unwrap();
+  assert_eq!(
+    device_info.device_address,
+    "02:aa:bb:cc:dd:ee".parse().unwrap(),
+    "device_address"
+  );
+  assert!(device_info.config_methods.display, "display");
+  assert!(device_info.config_methods.push_button, "push_button");
+  assert!(device_info.config_methods.keypad, "keypad");
+  assert_eq!(
+    device_info.primary_device_type,
+    WPSPrimaryDeviceType {
+      category: 10,
+      oui: [0x00, 0x50, 0xf2, 0x04],
+      subcategory: 5,
+    },
+    "primary_device_type"
+  );
+  assert_eq!(device_info.device_name, b"Phone".to_vec(), "device_name");
+}
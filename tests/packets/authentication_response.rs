@@ -60,11 +60,19 @@ fn test_authentication_response_packet() {
         authentication_frame
           .tagged_parameters()
           .unwrap()
-          .get_bytes(TagName::Other(221))
+          .get_bytes(TagName::VendorSpecific)
           .unwrap(),
         [0x00, 0x10, 0x18, 0x01, 0x01, 0x00],
         "tagged_paramters"
       );
+
+      let vendor_specific = authentication_frame
+        .iter_vendor_specific()
+        .collect::<Vec<_>>();
+      assert_eq!(vendor_specific.len(), 1, "vendor_specific");
+      assert_eq!(vendor_specific[0].vendor(), Vendor::Broadcom, "vendor");
+      assert_eq!(vendor_specific[0].oui_type(), Some(0x01), "oui_type");
+      assert_eq!(vendor_specific[0].payload(), [0x01, 0x01, 0x00], "payload");
     }
     _ => unreachable!("not authentication"),
   }
@@ -60,8 +60,73 @@ fn test_beacon_packet() {
 
     supported_rates: Some(vec![1.0, 2.0, 5.5, 11.0, 18.0, 24.0, 36.0, 54.0]),
 
+    wpa: Some(WPAVersion::Standard(WPA {
+      multicast_cipher_suite: Some(CipherSuite::Standard(CipherSuiteType::TKIP)),
+      unicast_cipher_suites: vec![CipherSuite::Standard(CipherSuiteType::TKIP)],
+      akm_suites: vec![AKMSuite::Standard(AKMSuiteType::PSK)],
+    })),
+
     channel: Some(11),
 
     ..Default::default()
   });
+
+  let beacon_frame = BeaconFrame::new(&BEACON_PACKET[..]);
+  let traffic_indication_map = beacon_frame
+    .tagged_parameters()
+    .unwrap()
+    .traffic_indication_map()
+    .unwrap();
+  assert_eq!(
+    traffic_indication_map,
+    TrafficIndicationMap::new(0, 1),
+    "traffic_indication_map"
+  );
+  assert!(traffic_indication_map.is_dtim(), "is_dtim");
+  assert!(
+    !traffic_indication_map.has_buffered_traffic(1),
+    "has_buffered_traffic"
+  );
+
+  let tagged_parameters = beacon_frame.tagged_parameters().unwrap();
+  assert!(
+    beacon_frame.nontransmitted_bsss().is_empty(),
+    "nontransmitted_bsss"
+  );
+  assert_eq!(
+    tagged_parameters.erp_information(),
+    Some(ERPInformation {
+      non_erp_present: false,
+      use_protection: false,
+      barker_preamble_mode: true,
+    }),
+    "erp_information"
+  );
+  assert_eq!(tagged_parameters.bss_load(), None, "bss_load");
+  assert_eq!(tagged_parameters.power_capability(), None, "power_capability");
+
+  assert_eq!(
+    tagged_parameters
+      .rates()
+      .filter_map(|rate| rate.kbps())
+      .collect::<Vec<_>>(),
+    [1000, 2000, 5500, 11000, 18000, 24000, 36000, 54000, 6000, 9000, 12000, 48000],
+    "rates"
+  );
+  assert_eq!(
+    tagged_parameters.basic_rates(),
+    [
+      Rate::new(1000, true),
+      Rate::new(2000, true),
+      Rate::new(5500, true),
+      Rate::new(11000, true),
+    ],
+    "basic_rates"
+  );
+
+  let operating_channel = tagged_parameters.operating_channel().unwrap();
+  assert_eq!(operating_channel.band, Band::TwoPointFourGHz, "band");
+  assert_eq!(operating_channel.number, 11, "operating_channel");
+  assert_eq!(operating_channel.frequency, 2462, "frequency");
+  assert_eq!(operating_channel.width, ChannelWidth::MHz20, "width");
 }
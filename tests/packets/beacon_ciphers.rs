@@ -78,10 +78,35 @@ fn test_beacon_ciphers_packet() {
         joint_multi_band_rsna: false,
         peerkey: false,
       }),
+      pmkids: vec![],
+      group_management_cipher_suite: None,
+    })),
+
+    wpa: Some(WPAVersion::Standard(WPA {
+      multicast_cipher_suite: Some(CipherSuite::Standard(CipherSuiteType::TKIP)),
+      unicast_cipher_suites: vec![
+        CipherSuite::Standard(CipherSuiteType::CCMP),
+        CipherSuite::Standard(CipherSuiteType::TKIP),
+      ],
+      akm_suites: vec![AKMSuite::Standard(AKMSuiteType::PSK)],
     })),
 
     channel: Some(1),
 
     ..Default::default()
   });
+
+  let beacon_frame = BeaconFrame::new(&BEACON_CIPHERS_PACKET[..]);
+  let vendor_specific_types = beacon_frame
+    .iter_vendor_specific()
+    .map(|vendor_specific| vendor_specific.vendor_specific_type())
+    .collect::<Vec<_>>();
+  assert_eq!(
+    vendor_specific_types,
+    vec![
+      VendorSpecificType::Vendor(Vendor::Broadcom, Some(0x02)),
+      VendorSpecificType::WPA,
+    ],
+    "vendor_specific_types"
+  );
 }
@@ -0,0 +1,105 @@
+const BEACON_COUNTRY_PACKET: [u8; 78] = [
+  0x80, 0x00, 0x00, 0x00, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x00, 0x1b, 0x2c, 0x3d, 0x4e, 0x60,
+  0x00, 0x1b, 0x2c, 0x3d, 0x4e, 0x60, 0x40, 0x06, 0x89, 0x67, 0x45, 0x23, 0x01, 0x00, 0x00, 0x00,
+  0x64, 0x00, 0x11, 0x01, 0x00, 0x09, 0x43, 0x6f, 0x75, 0x6e, 0x74, 0x72, 0x79, 0x41, 0x50, 0x01,
+  0x08, 0x8c, 0x12, 0x98, 0x24, 0xb0, 0x48, 0x60, 0x6c, 0x03, 0x01, 0x24, 0x07, 0x10, 0x55, 0x53,
+  0x20, 0x24, 0x04, 0x17, 0x34, 0x04, 0x18, 0x64, 0x0b, 0x18, 0x95, 0x05, 0x1e, 0x00,
+];
+
+#[test]
+fn test_beacon_country_packet() {
+  // Transmitter address: 00:1b:2c:3d:4e:60
+  // SSID: CountryAP, channel 36
+  // Country: US, 4 subbands, padded to an even length
+
+  test_test_item(TestItem {
+    bytes: &BEACON_COUNTRY_PACKET,
+    subtype: Some(FrameSubtype::Management(ManagementSubtype::Beacon)),
+
+    ds_status: Some(DSStatus::NotLeavingDSOrADHOC),
+    duration_id: Some(DurationID::Duration(0)),
+
+    receiver_address: "ff:ff:ff:ff:ff:ff".parse().unwrap(),
+    destination_address: Some("ff:ff:ff:ff:ff:ff".parse().unwrap()),
+
+    transmitter_address: Some("00:1b:2c:3d:4e:60".parse().unwrap()),
+    source_address: Some("00:1b:2c:3d:4e:60".parse().unwrap()),
+
+    bssid_address: Some("00:1b:2c:3d:4e:60".parse().unwrap()),
+
+    fragment_number: Some(0),
+    sequence_number: Some(100),
+
+    ssid: Some(b"CountryAP".to_vec()),
+
+    timestamp: Some(0x0000_0001_2345_6789u64),
+
+    beacon_interval: Some(0.102_400f64),
+
+    capabilities_info: Some(CapabilitiesInfo {
+      ess_capabilities: true,
+      ibss_status: false,
+      cfp_partitipation_capabilities: 0,
+      privacy: true,
+      short_preamble: false,
+      pbcc: false,
+      channel_agility: false,
+      spectrum_management: true,
+      short_slot_time: false,
+      automatic_power_save_delivery: false,
+      radio_measurement: false,
+      dsss_ofdm: false,
+      delayed_block_ack: false,
+      immediate_block_ack: false,
+    }),
+
+    supported_rates: Some(vec![6.0, 9.0, 12.0, 18.0, 24.0, 36.0, 48.0, 54.0]),
+
+    channel: Some(36),
+
+    ..Default::default()
+  });
+
+  let beacon_frame = BeaconFrame::new(&BEACON_COUNTRY_PACKET[..]);
+  let tagged_parameters = beacon_frame.tagged_parameters().unwrap();
+
+  let country = tagged_parameters.country().unwrap();
+  assert_eq!(country.country_string, *b"US", "country_string");
+  assert_eq!(country.environment, CountryEnvironment::Any, "environment");
+  assert_eq!(
+    country.triplets,
+    vec![
+      CountryTriplet::Subband {
+        first_channel: 36,
+        number_of_channels: 4,
+        max_transmit_power: 23,
+      },
+      CountryTriplet::Subband {
+        first_channel: 52,
+        number_of_channels: 4,
+        max_transmit_power: 24,
+      },
+      CountryTriplet::Subband {
+        first_channel: 100,
+        number_of_channels: 11,
+        max_transmit_power: 24,
+      },
+      CountryTriplet::Subband {
+        first_channel: 149,
+        number_of_channels: 5,
+        max_transmit_power: 30,
+      },
+    ],
+    "triplets"
+  );
+  assert_eq!(country.subbands().count(), 4, "subbands");
+  assert_eq!(country.region(), Some(Region::UnitedStates), "region");
+  assert!(country.operating_classes().is_empty(), "operating_classes");
+
+  // the pad byte is written back
+  assert_eq!(
+    country.to_bytes(),
+    BEACON_COUNTRY_PACKET[(BEACON_COUNTRY_PACKET.len() - 16)..],
+    "to_bytes"
+  );
+}
@@ -0,0 +1,98 @@
+const BEACON_WMM_PACKET: [u8; 83] = [
+  0x80, 0x00, 0x00, 0x00, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x00, 0x1b, 0x2c, 0x3d, 0x4e, 0x5f,
+  0x00, 0x1b, 0x2c, 0x3d, 0x4e, 0x5f, 0x40, 0x2b, 0x2e, 0x41, 0x7c, 0x05, 0x00, 0x00, 0x00, 0x00,
+  0x64, 0x00, 0x11, 0x04, 0x00, 0x06, 0x54, 0x65, 0x73, 0x74, 0x41, 0x50, 0x01, 0x08, 0x82, 0x84,
+  0x8b, 0x96, 0x0c, 0x12, 0x18, 0x24, 0x03, 0x01, 0x06, 0xdd, 0x18, 0x00, 0x50, 0xf2, 0x02, 0x01,
+  0x01, 0x83, 0x00, 0x03, 0xa4, 0x00, 0x00, 0x27, 0xa4, 0x00, 0x00, 0x42, 0x43, 0x5e, 0x00, 0x62,
+  0x32, 0x2f, 0x00,
+];
+
+#[test]
+fn test_beacon_wmm_packet() {
+  // Transmitter address: 00:1b:2c:3d:4e:5f
+  // SSID: TestAP
+  // WMM/WME: Parameter Element
+
+  test_test_item(TestItem {
+    bytes: &BEACON_WMM_PACKET,
+    subtype: Some(FrameSubtype::Management(ManagementSubtype::Beacon)),
+
+    ds_status: Some(DSStatus::NotLeavingDSOrADHOC),
+    duration_id: Some(DurationID::Duration(0)),
+
+    receiver_address: "ff:ff:ff:ff:ff:ff".parse().unwrap(),
+    destination_address: Some("ff:ff:ff:ff:ff:ff".parse().unwrap()),
+
+    transmitter_address: Some("00:1b:2c:3d:4e:5f".parse().unwrap()),
+    source_address: Some("00:1b:2c:3d:4e:5f".parse().unwrap()),
+
+    bssid_address: Some("00:1b:2c:3d:4e:5f".parse().unwrap()),
+
+    fragment_number: Some(0),
+    sequence_number: Some(692),
+
+    ssid: Some(b"TestAP".to_vec()),
+
+    timestamp: Some(0x0000_0000_057c_412eu64),
+
+    beacon_interval: Some(0.102_400f64),
+
+    capabilities_info: Some(CapabilitiesInfo {
+      ess_capabilities: true,
+      ibss_status: false,
+      cfp_partitipation_capabilities: 0,
+      privacy: true,
+      short_preamble: false,
+      pbcc: false,
+      channel_agility: false,
+      spectrum_management: false,
+      short_slot_time: true,
+      automatic_power_save_delivery: false,
+      radio_measurement: false,
+      dsss_ofdm: false,
+      delayed_block_ack: false,
+      immediate_block_ack: false,
+    }),
+
+    supported_rates: Some(vec![1.0, 2.0, 5.5, 11.0, 6.0, 9.0, 12.0, 18.0]),
+
+    channel: Some(6),
+
+    ..Default::default()
+  });
+
+  let beacon_frame = BeaconFrame::new(&BEACON_WMM_PACKET[..]);
+  let tagged_parameters = beacon_frame.tagged_parameters().unwrap();
+
+  assert_eq!(tagged_parameters.wmm_information(), None, "wmm_information");
+
+  let wmm_parameter = tagged_parameters.wmm_parameter().unwrap();
+  assert_eq!(wmm_parameter.version, 1, "version");
+  assert_eq!(
+    wmm_parameter.ap_qos_info(),
+    WMMAPQoSInfo {
+      parameter_set_count: 3,
+      uapsd: true,
+    },
+    "ap_qos_info"
+  );
+
+  let best_effort = wmm_parameter.ac_parameter(AccessCategory::BestEffort).unwrap();
+  assert_eq!(best_effort.aifsn, 3, "best_effort aifsn");
+  assert_eq!(best_effort.ecw_min, 4, "best_effort ecw_min");
+  assert_eq!(best_effort.ecw_max, 10, "best_effort ecw_max");
+
+  let background = wmm_parameter.ac_parameter(AccessCategory::Background).unwrap();
+  assert_eq!(background.aifsn, 7, "background aifsn");
+
+  let video = wmm_parameter.ac_parameter(AccessCategory::Video).unwrap();
+  assert_eq!(video.txop_limit, 94, "video txop_limit");
+  assert_eq!(video.txop_limit_us(), 3008, "video txop_limit_us");
+
+  let voice = wmm_parameter.ac_parameter(AccessCategory::Voice).unwrap();
+  assert!(!voice.acm, "voice acm");
+  assert_eq!(voice.aifsn, 2, "voice aifsn");
+  assert_eq!(voice.ecw_min, 2, "voice ecw_min");
+  assert_eq!(voice.ecw_max, 3, "voice ecw_max");
+  assert_eq!(voice.txop_limit, 47, "voice txop_limit");
+}
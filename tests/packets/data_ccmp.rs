@@ -0,0 +1,205 @@
+const DATA_CCMP_PACKET: [u8; 60] = [
+  0x08, 0x48, 0xc3, 0x2c, 0x0f, 0xd2, 0xe1, 0x28, 0xa5, 0x7c, 0x50, 0x30, 0xf1, 0x84, 0x44, 0x08,
+  0xab, 0xae, 0xa5, 0xb8, 0xfc, 0xba, 0x80, 0x33, 0x0c, 0xe7, 0x00, 0x20, 0x76, 0x97, 0x03, 0xb5,
+  0xf3, 0xd0, 0xa2, 0xfe, 0x9a, 0x3d, 0xbf, 0x23, 0x42, 0xa6, 0x43, 0xe4, 0x32, 0x46, 0xe8, 0x0c,
+  0x3c, 0x04, 0xd0, 0x19, 0x78, 0x45, 0xce, 0x0b, 0x16, 0xf9, 0x76, 0x23,
+];
+
+const DATA_GCMP_256_PACKET: [u8; 86] = [
+  0x88, 0x49, 0x3a, 0x01, 0x02, 0x00, 0x00, 0x00, 0x00, 0x01, 0x02, 0x00, 0x00, 0x00, 0x00, 0x02,
+  0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x70, 0x00, 0x05, 0x00, 0x06, 0x05, 0x00, 0xa0, 0x04, 0x03,
+  0x02, 0x01, 0x9a, 0x4a, 0x6e, 0xf1, 0x29, 0xaa, 0x53, 0x8e, 0xc8, 0xe9, 0x5a, 0x64, 0xc6, 0xcb,
+  0x5b, 0x1a, 0xfe, 0xba, 0x37, 0xe7, 0x00, 0x14, 0x6c, 0xb3, 0x12, 0x84, 0x93, 0x80, 0x7a, 0x19,
+  0x5f, 0x22, 0x12, 0x0f, 0x45, 0xca, 0x3f, 0x91, 0xa1, 0x1f, 0x04, 0xfd, 0xe7, 0x02, 0x51, 0x3e,
+  0x07, 0xe8, 0x4d, 0x30, 0xc6, 0x5d,
+];
+
+
+#[test]
+fn test_data_ccmp_packet() {
+  // CCMP-128 test vector of IEEE 802.11, PN 0xb5039776e70c
+
+  test_test_item(TestItem {
+    bytes: &DATA_CCMP_PACKET,
+    subtype: Some(FrameSubtype::Data(DataSubtype::Data)),
+    ds_status: Some(DSStatus::NotLeavingDSOrADHOC),
+
+    retry: true,
+    protected: true,
+
+    duration_id: Some(DurationID::Duration(11459)),
+
+    receiver_address: "0f:d2:e1:28:a5:7c".parse().unwrap(),
+    transmitter_address: Some("50:30:f1:84:44:08".parse().unwrap()),
+
+    destination_address: Some("0f:d2:e1:28:a5:7c".parse().unwrap()),
+    source_address: Some("50:30:f1:84:44:08".parse().unwrap()),
+
+    bssid_address: Some("ab:ae:a5:b8:fc:ba".parse().unwrap()),
+
+    fragment_number: Some(0),
+    sequence_number: Some(824),
+
+    ..Default::default()
+  });
+
+  let plaintext = [
+    0xf8, 0xba, 0x1a, 0x55, 0xd0, 0x2f, 0x85, 0xae, 0x96, 0x7b, 0xb6, 0x2f, 0xb6, 0xcd, 0xa8, 0xeb,
+    0x7e, 0x78, 0xa0, 0x50,
+  ];
+  let tk = [
+    0xc9, 0x7c, 0x1f, 0x67, 0xce, 0x37, 0x11, 0x85, 0x51, 0x4a, 0x8a, 0x19, 0xf2, 0xbd, 0xd5, 0x2f,
+  ];
+
+  let data_frame = DataFrame::new(&DATA_CCMP_PACKET[..]);
+  assert_eq!(
+    data_frame.security_header(),
+    Some(SecurityHeader::CCMP(CCMPHeader {
+      packet_number: 0xb503_9776_e70c,
+      key_id: 0,
+    })),
+    "security_header"
+  );
+
+  let key = CCMPKey::from_bytes(CipherSuiteType::CCMP, &tk).unwrap();
+  let decrypted = data_frame.decrypt_ccmp(&key).unwrap();
+  assert!(!decrypted.protected(), "protected");
+  assert_eq!(decrypted.bytes()[2..24], DATA_CCMP_PACKET[2..24], "header");
+  assert_eq!(decrypted.next_layer(), Some(&plaintext[..]), "next_layer");
+
+  let mut bytes = DATA_CCMP_PACKET.to_vec();
+  DataFrame::decrypt_ccmp_in_place(&mut bytes, &key).unwrap();
+  assert_eq!(bytes, decrypted.bytes(), "decrypt_ccmp_in_place");
+
+  // same TK as GCMP
+  let wrong_key = CCMPKey::from_bytes(CipherSuiteType::GCMP, &tk).unwrap();
+  assert_eq!(
+    data_frame.decrypt_ccmp(&wrong_key).err(),
+    Some(DecryptionError::MICMismatch),
+    "wrong key"
+  );
+  assert!(
+    CCMPKey::from_bytes(CipherSuiteType::CCMP256, &tk).is_none(),
+    "key length"
+  );
+
+  let wep_data_frame = DataFrame::new(&DATA_WEP_PACKET[..]);
+  assert_eq!(
+    wep_data_frame.decrypt_ccmp(&key).err(),
+    Some(DecryptionError::NotProtected),
+    "not protected"
+  );
+}
+
+#[test]
+fn test_data_gcmp_256_packet() {
+  // GCMP-256 QoS data, TID 5, key ID 2, PN 0x010203040506
+  // TK 00:01:..:1f, ARP request from 02:00:00:00:00:02
+
+  test_test_item(TestItem {
+    bytes: &DATA_GCMP_256_PACKET,
+    subtype: Some(FrameSubtype::Data(DataSubtype::QoSData)),
+    ds_status: Some(DSStatus::FromSTAToDS),
+
+    retry: true,
+    protected: true,
+
+    duration_id: Some(DurationID::Duration(314)),
+
+    receiver_address: "02:00:00:00:00:01".parse().unwrap(),
+    transmitter_address: Some("02:00:00:00:00:02".parse().unwrap()),
+
+    destination_address: Some("ff:ff:ff:ff:ff:ff".parse().unwrap()),
+    source_address: Some("02:00:00:00:00:02".parse().unwrap()),
+
+    bssid_address: Some("02:00:00:00:00:01".parse().unwrap()),
+    station_address: Some("02:00:00:00:00:02".parse().unwrap()),
+
+    fragment_number: Some(0),
+    sequence_number: Some(7),
+
+    ..Default::default()
+  });
+
+  let data_frame = DataFrame::new(&DATA_GCMP_256_PACKET[..]);
+  let protected_payload = data_frame
+    .protected_payload(CipherSuiteType::GCMP256)
+    .unwrap();
+  assert_eq!(
+    protected_payload.security_header,
+    SecurityHeader::CCMP(CCMPHeader {
+      packet_number: 0x0102_0304_0506,
+      key_id: 2,
+    }),
+    "security_header"
+  );
+
+  let tk: Vec<u8> = (0..32).collect();
+  let key = CCMPKey::from_bytes(CipherSuiteType::GCMP256, &tk).unwrap();
+  let decrypted = data_frame.decrypt_ccmp(&key).unwrap();
+  assert!(!decrypted.protected(), "protected");
+  assert_eq!(
+    decrypted.llc_snap().map(|llc_snap| llc_snap.ether_type()),
+    Some(EtherType::ARP),
+    "ether_type"
+  );
+
+  // the TID is part of the AAD
+  let mut bytes = DATA_GCMP_256_PACKET.to_vec();
+  bytes[24] = 0x06;
+  assert_eq!(
+    DataFrame::new(bytes).decrypt_ccmp(&key).err(),
+    Some(DecryptionError::MICMismatch),
+    "tid"
+  );
+
+  // but not the sequence number
+  let mut bytes = DATA_GCMP_256_PACKET.to_vec();
+  bytes[22] = 0x80;
+  assert!(DataFrame::new(bytes).decrypt_ccmp(&key).is_ok(), "sequence number");
+}
+
+#[test]
+fn test_data_gcmp_256_test_case_16() {
+  // the GCMP nonce of addr2 ca:fe:ba:be:fa:ce and PN 0xdbaddecaf888 is the
+  // IV of test case 16 of the GCM specification, the AAD differs so only
+  // the ciphertext is known
+  let tk = [
+    0xfe, 0xff, 0xe9, 0x92, 0x86, 0x65, 0x73, 0x1c, 0x6d, 0x6a, 0x8f, 0x94, 0x67, 0x30, 0x83, 0x08,
+    0xfe, 0xff, 0xe9, 0x92, 0x86, 0x65, 0x73, 0x1c, 0x6d, 0x6a, 0x8f, 0x94, 0x67, 0x30, 0x83, 0x08,
+  ];
+  let plaintext = [
+    0xd9, 0x31, 0x32, 0x25, 0xf8, 0x84, 0x06, 0xe5, 0xa5, 0x59, 0x09, 0xc5, 0xaf, 0xf5, 0x26, 0x9a,
+    0x86, 0xa7, 0xa9, 0x53, 0x15, 0x34, 0xf7, 0xda, 0x2e, 0x4c, 0x30, 0x3d, 0x8a, 0x31, 0x8a, 0x72,
+    0x1c, 0x3c, 0x0c, 0x95, 0x95, 0x68, 0x09, 0x53, 0x2f, 0xcf, 0x0e, 0x24, 0x49, 0xa6, 0xb5, 0x25,
+    0xb1, 0x6a, 0xed, 0xf5, 0xaa, 0x0d, 0xe6, 0x57, 0xba, 0x63, 0x7b, 0x39,
+  ];
+  let ciphertext = [
+    0x52, 0x2d, 0xc1, 0xf0, 0x99, 0x56, 0x7d, 0x07, 0xf4, 0x7f, 0x37, 0xa3, 0x2a, 0x84, 0x42, 0x7d,
+    0x64, 0x3a, 0x8c, 0xdc, 0xbf, 0xe5, 0xc0, 0xc9, 0x75, 0x98, 0xa2, 0xbd, 0x25, 0x55, 0xd1, 0xaa,
+    0x8c, 0xb0, 0x8e, 0x48, 0x59, 0x0d, 0xbb, 0x3d, 0xa7, 0xb0, 0x8b, 0x10, 0x56, 0x82, 0x88, 0x38,
+    0xc5, 0xf6, 0x1e, 0x63, 0x93, 0xba, 0x7a, 0x0a, 0xbc, 0xc9, 0xf6, 0x62,
+  ];
+
+  let mut data_frame_builder = DataFrameBuilder::new();
+  data_frame_builder.subtype(FrameSubtype::Data(DataSubtype::Data));
+  data_frame_builder.transmitter_address("ca:fe:ba:be:fa:ce".parse().unwrap());
+  data_frame_builder.next_layer(&plaintext);
+  let unprotected = data_frame_builder.bytes().to_vec();
+
+  let key = CCMPKey::from_bytes(CipherSuiteType::GCMP256, &tk).unwrap();
+  data_frame_builder
+    .encrypt_ccmp(&key, 0, 0xdbad_deca_f888)
+    .unwrap();
+
+  let data_frame = data_frame_builder.build();
+  let protected_payload = data_frame
+    .protected_payload(CipherSuiteType::GCMP256)
+    .unwrap();
+  assert_eq!(protected_payload.payload, &ciphertext[..], "ciphertext");
+  assert_eq!(
+    data_frame.decrypt_ccmp(&key).unwrap().bytes(),
+    &unprotected[..],
+    "decrypt_ccmp"
+  );
+}
@@ -51,7 +51,62 @@ fn test_data_layer_packet() {
     FrameLayer::Data(ref data_frame) => {
       let data_layer = data_frame.next_layer().unwrap().to_vec();
       assert_eq!(data_layer, bap, "data_layer");
+      assert!(data_frame.llc_snap().is_none(), "llc_snap");
+
+      let security_header = data_frame.security_header().unwrap();
+      assert_eq!(
+        security_header,
+        SecurityHeader::TKIP(TKIPHeader {
+          tsc: 0x5587,
+          wep_seed: 0x75,
+          key_id: 1,
+          ext_iv: true,
+        }),
+        "security_header"
+      );
+      assert_eq!(security_header.packet_number(), Some(0x5587), "packet_number");
+
+      let protected_payload = data_frame
+        .protected_payload(CipherSuiteType::TKIP)
+        .unwrap();
+      assert_eq!(protected_payload.payload, &bap[..44], "payload");
+      assert_eq!(protected_payload.mic, &bap[44..], "mic");
     }
     _ => unreachable!(),
   }
 }
+
+#[test]
+fn test_data_layer_ccmp_security_header() {
+  let mut bytes = DATA_LAYER_PACKET;
+  bytes[24..32].copy_from_slice(&[0x01, 0x02, 0x00, 0x60, 0x03, 0x04, 0x05, 0x06]);
+
+  let data_frame = DataFrame::new(&bytes[..]);
+  let security_header = data_frame.security_header().unwrap();
+  assert_eq!(
+    security_header,
+    SecurityHeader::CCMP(CCMPHeader {
+      packet_number: 0x0605_0403_0201,
+      key_id: 1,
+    }),
+    "security_header"
+  );
+  assert_eq!(security_header.key_id(), 1, "key_id");
+  assert_eq!(security_header.length(), 8, "length");
+
+  let protected_payload = data_frame
+    .protected_payload(CipherSuiteType::CCMP)
+    .unwrap();
+  assert_eq!(protected_payload.payload, &bytes[32..72], "payload");
+  assert_eq!(protected_payload.mic, &bytes[72..], "mic");
+
+  let protected_payload = data_frame
+    .protected_payload(CipherSuiteType::GCMP256)
+    .unwrap();
+  assert_eq!(protected_payload.mic, &bytes[64..], "mic");
+
+  assert!(
+    data_frame.protected_payload(CipherSuiteType::WEP40).is_none(),
+    "wep protected_payload"
+  );
+}
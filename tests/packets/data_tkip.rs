@@ -0,0 +1,102 @@
+const DATA_TKIP_PACKET: [u8; 82] = [
+  0x88, 0x42, 0x3a, 0x01, 0x02, 0x00, 0x00, 0x00, 0x00, 0x02, 0x02, 0x00, 0x00, 0x00, 0x00, 0x01,
+  0x02, 0x00, 0x00, 0x00, 0x00, 0x03, 0x50, 0x00, 0x03, 0x00, 0x02, 0x22, 0x03, 0x60, 0x01, 0x00,
+  0x00, 0x00, 0xe1, 0x0a, 0xe6, 0xf9, 0x4b, 0x54, 0xa1, 0xfd, 0xd8, 0x98, 0x17, 0x52, 0x88, 0x88,
+  0x0d, 0xfe, 0xdc, 0x0a, 0x77, 0x35, 0xcd, 0x28, 0x25, 0x39, 0x27, 0xf0, 0xd3, 0x9f, 0x66, 0xf8,
+  0xe2, 0x45, 0xed, 0xdb, 0x6c, 0x2e, 0x0c, 0x37, 0x81, 0x89, 0xcc, 0x82, 0xbb, 0x5a, 0x98, 0xcc,
+  0xcd, 0x34,
+];
+
+
+#[test]
+fn test_data_tkip_packet() {
+  // TKIP QoS data, TID 3, key ID 1, TSC 0x010203
+  // TK 20:21:..:2f, MIC keys 30:31:..:3f, ARP reply from 02:00:00:00:00:03
+
+  test_test_item(TestItem {
+    bytes: &DATA_TKIP_PACKET,
+    subtype: Some(FrameSubtype::Data(DataSubtype::QoSData)),
+    ds_status: Some(DSStatus::FromDSToSTA),
+
+    protected: true,
+
+    duration_id: Some(DurationID::Duration(314)),
+
+    receiver_address: "02:00:00:00:00:02".parse().unwrap(),
+    transmitter_address: Some("02:00:00:00:00:01".parse().unwrap()),
+
+    destination_address: Some("02:00:00:00:00:02".parse().unwrap()),
+    source_address: Some("02:00:00:00:00:03".parse().unwrap()),
+
+    bssid_address: Some("02:00:00:00:00:01".parse().unwrap()),
+    station_address: Some("02:00:00:00:00:02".parse().unwrap()),
+
+    fragment_number: Some(0),
+    sequence_number: Some(5),
+
+    ..Default::default()
+  });
+
+  let data_frame = DataFrame::new(&DATA_TKIP_PACKET[..]);
+  assert_eq!(
+    data_frame.security_header(),
+    Some(SecurityHeader::TKIP(TKIPHeader {
+      tsc: 0x01_0203,
+      wep_seed: 0x22,
+      key_id: 1,
+      ext_iv: true,
+    })),
+    "security_header"
+  );
+
+  let key_bytes: Vec<u8> = (0x20..0x40).collect();
+  let key = TKIPKey::from_bytes(&key_bytes).unwrap();
+  // the Michael MIC is removed with the ICV
+  let decrypted = data_frame.decrypt_tkip(&key).unwrap();
+  assert!(!decrypted.protected(), "protected");
+  assert_eq!(decrypted.bytes().len(), 26 + 36, "length");
+
+  // right TK, but the Michael key of the other direction
+  let mut wrong_mic_key = key.clone();
+  wrong_mic_key.tx_mic_key = key.rx_mic_key;
+  assert_eq!(
+    data_frame.decrypt_tkip(&wrong_mic_key).err(),
+    Some(DecryptionError::MICMismatch),
+    "wrong mic key"
+  );
+
+  let mut wrong_tk = key.clone();
+  wrong_tk.tk[0] ^= 1;
+  assert_eq!(
+    data_frame.decrypt_tkip(&wrong_tk).err(),
+    Some(DecryptionError::ICVMismatch),
+    "wrong tk"
+  );
+
+  // the Michael MIC of a fragment is only checked after reassembly
+  let mut bytes = DATA_TKIP_PACKET.to_vec();
+  bytes[1] |= 0b0000_0100;
+  let fragment = DataFrame::new(bytes);
+  let decrypted_fragment = fragment.decrypt_tkip(&key).unwrap();
+  let msdu = decrypted_fragment.next_layer().unwrap();
+  assert_eq!(msdu.len(), 36 + MICHAEL_MIC_LENGTH, "fragment length");
+  assert_eq!(
+    fragment.verify_michael_mic(&key, msdu),
+    Ok(decrypted.next_layer().unwrap()),
+    "verify_michael_mic"
+  );
+  assert_eq!(
+    fragment.verify_michael_mic(&wrong_mic_key, msdu),
+    Err(DecryptionError::MICMismatch),
+    "verify_michael_mic wrong mic key"
+  );
+
+  // TKIP sets ExtIV, WEP does not
+  let data_frame = DataFrame::new(&DATA_WEP_PACKET[..]);
+  assert_eq!(
+    data_frame.decrypt_tkip(&key).err(),
+    Some(DecryptionError::NotProtected),
+    "not protected"
+  );
+  assert!(TKIPKey::from_bytes(&key_bytes[..16]).is_none(), "key length");
+}
@@ -0,0 +1,132 @@
+const DATA_WEP_PACKET: [u8; 68] = [
+  0x08, 0x41, 0x3a, 0x01, 0x02, 0x00, 0x00, 0x00, 0x00, 0x01, 0x02, 0x00, 0x00, 0x00, 0x00, 0x02,
+  0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x20, 0x00, 0x0a, 0x0b, 0x0c, 0x40, 0x48, 0x4f, 0xd5, 0x6d,
+  0xbc, 0xb9, 0x5e, 0x5a, 0xaf, 0x52, 0xd6, 0x93, 0xed, 0x03, 0xca, 0xe7, 0xb9, 0x85, 0x6b, 0x00,
+  0x69, 0x45, 0x2d, 0x4e, 0x9a, 0x24, 0x24, 0x36, 0x4b, 0x99, 0x42, 0xd9, 0x1d, 0x83, 0xba, 0x88,
+  0xe5, 0xb2, 0xa3, 0x20,
+];
+
+
+#[test]
+fn test_data_wep_packet() {
+  // WEP-64, key 01:02:03:04:05, key index 1
+  // ARP request from 02:00:00:00:00:02
+
+  test_test_item(TestItem {
+    bytes: &DATA_WEP_PACKET,
+    subtype: Some(FrameSubtype::Data(DataSubtype::Data)),
+    ds_status: Some(DSStatus::FromSTAToDS),
+
+    protected: true,
+
+    duration_id: Some(DurationID::Duration(314)),
+
+    receiver_address: "02:00:00:00:00:01".parse().unwrap(),
+    transmitter_address: Some("02:00:00:00:00:02".parse().unwrap()),
+
+    destination_address: Some("ff:ff:ff:ff:ff:ff".parse().unwrap()),
+    source_address: Some("02:00:00:00:00:02".parse().unwrap()),
+
+    bssid_address: Some("02:00:00:00:00:01".parse().unwrap()),
+    station_address: Some("02:00:00:00:00:02".parse().unwrap()),
+
+    fragment_number: Some(0),
+    sequence_number: Some(2),
+
+    ..Default::default()
+  });
+
+  let data_frame = DataFrame::new(&DATA_WEP_PACKET[..]);
+  assert!(data_frame.is_wep(), "is_wep");
+  assert_eq!(data_frame.wep_iv(), Some([0x0a, 0x0b, 0x0c]), "wep_iv");
+  assert_eq!(data_frame.wep_key_index(), Some(1), "wep_key_index");
+  assert_eq!(
+    data_frame.security_header(),
+    Some(SecurityHeader::WEP(WEPHeader {
+      iv: [0x0a, 0x0b, 0x0c],
+      key_id: 1,
+    })),
+    "security_header"
+  );
+  // only the 4 byte IV and key ID are skipped
+  assert_eq!(
+    data_frame.next_layer(),
+    Some(&DATA_WEP_PACKET[28..]),
+    "next_layer"
+  );
+  assert!(data_frame.llc_snap().is_none(), "llc_snap");
+
+  // ExtIV set, but cut off before the extended IV
+  let mut truncated = DATA_WEP_PACKET[..28].to_vec();
+  truncated[27] |= 0b0010_0000;
+  assert_eq!(DataFrame::new(truncated).next_layer(), None, "truncated");
+
+  let key = WEPKey::from_bytes(&[0x01, 0x02, 0x03, 0x04, 0x05]).unwrap();
+  let decrypted = data_frame.decrypt_wep(&key).unwrap();
+  assert!(!decrypted.protected(), "protected");
+  assert_eq!(decrypted.bytes()[2..24], DATA_WEP_PACKET[2..24], "header");
+  assert_eq!(
+    decrypted.llc_snap().map(|llc_snap| llc_snap.ether_type()),
+    Some(EtherType::ARP),
+    "ether_type"
+  );
+
+  let wrong_key = WEPKey::from_bytes(&[0x01, 0x02, 0x03, 0x04, 0x06]).unwrap();
+  assert_eq!(
+    data_frame.decrypt_wep(&wrong_key).err(),
+    Some(DecryptionError::ICVMismatch),
+    "wrong key"
+  );
+  assert!(WEPKey::from_bytes(&[0; 8]).is_none(), "key length");
+
+  // one byte short of the ICV
+  let mut bytes = DATA_WEP_PACKET[..31].to_vec();
+  assert_eq!(
+    DataFrame::new(&bytes[..]).decrypt_wep(&key).err(),
+    Some(DecryptionError::TooShort),
+    "too short"
+  );
+
+  // the ExtIV bit of TKIP, CCMP and GCMP
+  bytes[27] |= 0b0010_0000;
+  assert_eq!(
+    DataFrame::new(bytes).decrypt_wep(&key).err(),
+    Some(DecryptionError::NotProtected),
+    "ext_iv"
+  );
+}
+
+#[test]
+fn test_data_wep_rfc_6229() {
+  // WEP-128 of the CRC-32 check string "123456789", IV 01:02:03 and key
+  // 04:05:..:10 make the RC4 key of RFC 6229's 128 bit test vector
+  let keystream = [
+    0x9a, 0xc7, 0xcc, 0x9a, 0x60, 0x9d, 0x1e, 0xf7, 0xb2, 0x93, 0x28, 0x99, 0xcd,
+  ];
+  let mut body = b"123456789".to_vec();
+  body.extend_from_slice(&0xcbf4_3926_u32.to_le_bytes());
+  let ciphertext: Vec<u8> = body.iter().zip(keystream).map(|(b, k)| b ^ k).collect();
+
+  let mut data_frame_builder = DataFrameBuilder::new();
+  data_frame_builder.subtype(FrameSubtype::Data(DataSubtype::Data));
+  data_frame_builder.next_layer(b"123456789");
+  let plaintext = data_frame_builder.bytes().to_vec();
+
+  let key_bytes: Vec<u8> = (0x04..=0x10).collect();
+  let key = WEPKey::from_bytes(&key_bytes).unwrap();
+  data_frame_builder
+    .encrypt_wep(&key, 0, [0x01, 0x02, 0x03])
+    .unwrap();
+
+  let data_frame = data_frame_builder.build();
+  assert_eq!(
+    data_frame.next_layer(),
+    Some(&ciphertext[..]),
+    "encrypt_wep"
+  );
+  assert_eq!(
+    data_frame.decrypt_wep(&key).unwrap().bytes(),
+    &plaintext[..],
+    "decrypt_wep"
+  );
+}
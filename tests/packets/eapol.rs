@@ -0,0 +1,516 @@
+const EAPOL_MESSAGE_1: [u8; 153] = [
+  0x08, 0x02, 0x3a, 0x01, 0x02, 0x00, 0x00, 0x00, 0x00, 0x02, 0x02, 0x00, 0x00, 0x00, 0x00, 0x01,
+  0x02, 0x00, 0x00, 0x00, 0x00, 0x01, 0x10, 0x00, 0xaa, 0xaa, 0x03, 0x00, 0x00, 0x00, 0x88, 0x8e,
+  0x02, 0x03, 0x00, 0x75, 0x02, 0x00, 0x8a, 0x00, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+  0x01, 0x3f, 0x1e, 0x8a, 0x0e, 0x1c, 0xc6, 0x3a, 0x67, 0xb1, 0x55, 0xed, 0xd2, 0x1b, 0x3a, 0xa8,
+  0x3f, 0xc0, 0x27, 0x16, 0xf0, 0xdb, 0xf9, 0xbc, 0xb0, 0x13, 0xf3, 0xe6, 0xfc, 0xf7, 0xd3, 0x82,
+  0xe4, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+  0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+  0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+  0x00, 0x00, 0x16, 0xdd, 0x14, 0x00, 0x0f, 0xac, 0x04, 0x48, 0x17, 0x71, 0xde, 0x9d, 0xcf, 0x8f,
+  0x74, 0xe9, 0x90, 0xe1, 0x1f, 0x40, 0xb2, 0x65, 0x2a,
+];
+
+const EAPOL_MESSAGE_2: [u8; 153] = [
+  0x08, 0x01, 0x3a, 0x01, 0x02, 0x00, 0x00, 0x00, 0x00, 0x01, 0x02, 0x00, 0x00, 0x00, 0x00, 0x02,
+  0x02, 0x00, 0x00, 0x00, 0x00, 0x01, 0x20, 0x00, 0xaa, 0xaa, 0x03, 0x00, 0x00, 0x00, 0x88, 0x8e,
+  0x02, 0x03, 0x00, 0x75, 0x02, 0x01, 0x0a, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+  0x01, 0x4a, 0xdc, 0xb7, 0x07, 0xf9, 0xa1, 0x6d, 0x23, 0xde, 0x6a, 0x84, 0x0d, 0x61, 0x4f, 0xe8,
+  0xd9, 0xbd, 0x17, 0x57, 0x14, 0xc7, 0x57, 0x7b, 0xbd, 0xe7, 0x14, 0xd8, 0x21, 0x84, 0xd4, 0x6e,
+  0x11, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+  0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+  0x00, 0x4a, 0x65, 0x99, 0x00, 0x70, 0x72, 0xe2, 0xae, 0xb5, 0xc7, 0x13, 0x5c, 0x91, 0x1d, 0x81,
+  0x0d, 0x00, 0x16, 0x30, 0x14, 0x01, 0x00, 0x00, 0x0f, 0xac, 0x04, 0x01, 0x00, 0x00, 0x0f, 0xac,
+  0x04, 0x01, 0x00, 0x00, 0x0f, 0xac, 0x02, 0x00, 0x00,
+];
+
+const EAPOL_MESSAGE_3: [u8; 187] = [
+  0x08, 0x02, 0x3a, 0x01, 0x02, 0x00, 0x00, 0x00, 0x00, 0x02, 0x02, 0x00, 0x00, 0x00, 0x00, 0x01,
+  0x02, 0x00, 0x00, 0x00, 0x00, 0x01, 0x30, 0x00, 0xaa, 0xaa, 0x03, 0x00, 0x00, 0x00, 0x88, 0x8e,
+  0x02, 0x03, 0x00, 0x97, 0x02, 0x13, 0xca, 0x00, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+  0x02, 0x3f, 0x1e, 0x8a, 0x0e, 0x1c, 0xc6, 0x3a, 0x67, 0xb1, 0x55, 0xed, 0xd2, 0x1b, 0x3a, 0xa8,
+  0x3f, 0xc0, 0x27, 0x16, 0xf0, 0xdb, 0xf9, 0xbc, 0xb0, 0x13, 0xf3, 0xe6, 0xfc, 0xf7, 0xd3, 0x82,
+  0xe4, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+  0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+  0x00, 0xbf, 0xe1, 0x1b, 0xb4, 0x70, 0x08, 0x47, 0x08, 0xc4, 0x12, 0x11, 0x2e, 0x2f, 0x37, 0xec,
+  0xfa, 0x00, 0x38, 0x91, 0xa5, 0x27, 0xbf, 0x81, 0x8c, 0xa9, 0x4a, 0x3a, 0xa1, 0xbe, 0x21, 0xc2,
+  0x61, 0x85, 0x04, 0xe7, 0x33, 0x2e, 0x1b, 0x0b, 0x41, 0x2a, 0xc5, 0xa0, 0xfe, 0xd6, 0xe9, 0xf3,
+  0x0f, 0xab, 0xc3, 0x97, 0x26, 0x51, 0xba, 0x34, 0x1e, 0x2d, 0x29, 0xb6, 0x13, 0x5f, 0x8a, 0x4c,
+  0x12, 0x62, 0xce, 0xd0, 0x8a, 0xc9, 0x59, 0xfe, 0xbf, 0x0f, 0xba,
+];
+
+const EAPOL_MESSAGE_4: [u8; 131] = [
+  0x08, 0x01, 0x3a, 0x01, 0x02, 0x00, 0x00, 0x00, 0x00, 0x01, 0x02, 0x00, 0x00, 0x00, 0x00, 0x02,
+  0x02, 0x00, 0x00, 0x00, 0x00, 0x01, 0x40, 0x00, 0xaa, 0xaa, 0x03, 0x00, 0x00, 0x00, 0x88, 0x8e,
+  0x02, 0x03, 0x00, 0x5f, 0x02, 0x03, 0x0a, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+  0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+  0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+  0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+  0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+  0x00, 0x95, 0x74, 0x16, 0x36, 0x2b, 0xd2, 0x16, 0xd8, 0xe5, 0x0b, 0x5d, 0x39, 0x99, 0x51, 0x59,
+  0x7e, 0x00, 0x00,
+];
+
+const EAPOL_GROUP_MESSAGE_1: [u8; 171] = [
+  0x08, 0x02, 0x3a, 0x01, 0x02, 0x00, 0x00, 0x00, 0x00, 0x02, 0x02, 0x00, 0x00, 0x00, 0x00, 0x01,
+  0x02, 0x00, 0x00, 0x00, 0x00, 0x01, 0x50, 0x00, 0xaa, 0xaa, 0x03, 0x00, 0x00, 0x00, 0x88, 0x8e,
+  0x02, 0x03, 0x00, 0x87, 0x02, 0x13, 0x82, 0x00, 0x10, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+  0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+  0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+  0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+  0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+  0x00, 0xd5, 0x8c, 0x2f, 0xed, 0x09, 0x92, 0x5b, 0xc3, 0x8e, 0xe4, 0xe3, 0x4a, 0x15, 0x0e, 0x3a,
+  0xaf, 0x00, 0x28, 0x32, 0xea, 0x04, 0xcc, 0x26, 0x47, 0xcf, 0x89, 0xe6, 0x1f, 0x3d, 0x7c, 0x2e,
+  0xf3, 0x00, 0xc6, 0x89, 0x8b, 0xc2, 0x99, 0x80, 0x6e, 0x99, 0x2f, 0x31, 0x08, 0xa4, 0x6b, 0x15,
+  0x71, 0x52, 0x04, 0x7f, 0xf4, 0x85, 0x92, 0xbd, 0x9c, 0x50, 0x72,
+];
+
+const EAPOL_GROUP_MESSAGE_2: [u8; 131] = [
+  0x08, 0x01, 0x3a, 0x01, 0x02, 0x00, 0x00, 0x00, 0x00, 0x01, 0x02, 0x00, 0x00, 0x00, 0x00, 0x02,
+  0x02, 0x00, 0x00, 0x00, 0x00, 0x01, 0x60, 0x00, 0xaa, 0xaa, 0x03, 0x00, 0x00, 0x00, 0x88, 0x8e,
+  0x02, 0x03, 0x00, 0x5f, 0x02, 0x03, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+  0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+  0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+  0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+  0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+  0x00, 0xe1, 0xb3, 0x9d, 0x71, 0xd0, 0x01, 0x90, 0x83, 0x2c, 0xc4, 0x97, 0x53, 0x6c, 0x70, 0x14,
+  0xca, 0x00, 0x00,
+];
+
+
+const EAPOL_BEACON: [u8; 50] = [
+  0x80, 0x00, 0x00, 0x00, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x02, 0x00, 0x00, 0x00, 0x00, 0x01,
+  0x02, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+  0x64, 0x00, 0x11, 0x04, 0x00, 0x0c, 0x74, 0x65, 0x73, 0x74, 0x2d, 0x6e, 0x65, 0x74, 0x77, 0x6f,
+  0x72, 0x6b,
+];
+
+const EAPOL_DEAUTHENTICATION: [u8; 26] = [
+  0xc0, 0x00, 0x3a, 0x01, 0x02, 0x00, 0x00, 0x00, 0x00, 0x02, 0x02, 0x00, 0x00, 0x00, 0x00, 0x01,
+  0x02, 0x00, 0x00, 0x00, 0x00, 0x01, 0x70, 0x00, 0x0f, 0x00,
+];
+
+fn eapol_key_of(bytes: &[u8]) -> EAPOLKey<'_> {
+  let data_frame = DataFrame::new(bytes);
+  let eapol = data_frame.eapol().expect("eapol");
+  assert_eq!(eapol.version(), 2, "version");
+  assert_eq!(eapol.packet_type(), EAPOLPacketType::Key, "packet_type");
+  assert_eq!(
+    eapol.body_length() as usize,
+    bytes.len() - 24 - 8 - EAPOL::HEADER_LENGTH,
+    "body_length"
+  );
+
+  // the key frame borrows from the input bytes, not from the data frame
+  let body = &bytes[(24 + 8 + EAPOL::HEADER_LENGTH)..];
+  assert_eq!(eapol.body(), body, "body");
+  EAPOLKey::new(body)
+}
+
+#[test]
+fn test_eapol_four_way_handshake() {
+  // SSID: test-network, passphrase: password123
+  // Authenticator: 02:00:00:00:00:01
+  // Supplicant: 02:00:00:00:00:02
+
+  test_test_item(TestItem {
+    bytes: &EAPOL_MESSAGE_1,
+    subtype: Some(FrameSubtype::Data(DataSubtype::Data)),
+    ds_status: Some(DSStatus::FromDSToSTA),
+
+    duration_id: Some(DurationID::Duration(314)),
+
+    receiver_address: "02:00:00:00:00:02".parse().unwrap(),
+    transmitter_address: Some("02:00:00:00:00:01".parse().unwrap()),
+
+    destination_address: Some("02:00:00:00:00:02".parse().unwrap()),
+    source_address: Some("02:00:00:00:00:01".parse().unwrap()),
+
+    bssid_address: Some("02:00:00:00:00:01".parse().unwrap()),
+    station_address: Some("02:00:00:00:00:02".parse().unwrap()),
+
+    fragment_number: Some(0),
+    sequence_number: Some(1),
+
+    ..Default::default()
+  });
+
+  let anonce = &EAPOL_MESSAGE_1[49..81];
+
+  let message_1 = eapol_key_of(&EAPOL_MESSAGE_1);
+  assert_eq!(
+    message_1.descriptor_type(),
+    EAPOLKeyDescriptorType::RSN,
+    "descriptor_type"
+  );
+  let key_information = message_1.key_information();
+  assert_eq!(key_information.into_u16(), 0x008a, "key_information");
+  assert_eq!(
+    key_information.descriptor_version,
+    KeyDescriptorVersion::HMACSHA1AES,
+    "descriptor_version"
+  );
+  assert!(key_information.pairwise, "pairwise");
+  assert!(key_information.ack, "ack");
+  assert!(!key_information.mic, "mic");
+  assert_eq!(message_1.key_length(), 16, "key_length");
+  assert_eq!(message_1.replay_counter(), 1, "replay_counter");
+  assert_eq!(message_1.nonce()[0..4], [0x3f, 0x1e, 0x8a, 0x0e], "anonce");
+  assert_eq!(message_1.mic(), Some(&[0; 16][..]), "mic");
+  assert_eq!(message_1.key_data_length(), Some(22), "key_data_length");
+  assert_eq!(
+    message_1.pmkid(),
+    Some([
+      0x48, 0x17, 0x71, 0xde, 0x9d, 0xcf, 0x8f, 0x74, 0xe9, 0x90, 0xe1, 0x1f, 0x40, 0xb2, 0x65,
+      0x2a,
+    ]),
+    "pmkid"
+  );
+  assert_eq!(
+    message_1.message(),
+    Some(EAPOLKeyMessage::Message1),
+    "message 1"
+  );
+  assert_eq!(
+    Frame::new(&EAPOL_MESSAGE_1[..]).pmkids(),
+    vec![(
+      "02:00:00:00:00:01".parse().unwrap(),
+      "02:00:00:00:00:02".parse().unwrap(),
+      message_1.pmkid().unwrap(),
+    )],
+    "pmkids"
+  );
+  assert!(
+    Frame::new(&EAPOL_MESSAGE_2[..]).pmkids().is_empty(),
+    "message 2 pmkids"
+  );
+
+  let message_2 = eapol_key_of(&EAPOL_MESSAGE_2);
+  assert_eq!(message_2.replay_counter(), 1, "replay_counter");
+  assert_eq!(message_2.nonce()[0..4], [0x4a, 0xdc, 0xb7, 0x07], "snonce");
+  assert_eq!(
+    message_2.mic(),
+    Some(
+      &[
+        0x4a, 0x65, 0x99, 0x00, 0x70, 0x72, 0xe2, 0xae, 0xb5, 0xc7, 0x13, 0x5c, 0x91, 0x1d, 0x81,
+        0x0d,
+      ][..]
+    ),
+    "mic"
+  );
+  match &message_2.key_data_elements()[..] {
+    [KeyDataElement::RSN(rsn)] => assert_eq!(rsn.len(), 20, "rsn length"),
+    _ => panic!("not a single rsn element"),
+  }
+  assert_eq!(
+    message_2.message(),
+    Some(EAPOLKeyMessage::Message2),
+    "message 2"
+  );
+
+  let message_3 = eapol_key_of(&EAPOL_MESSAGE_3);
+  let key_information = message_3.key_information();
+  assert!(key_information.install, "install");
+  assert!(key_information.secure, "secure");
+  assert!(key_information.encrypted_key_data, "encrypted_key_data");
+  assert_eq!(message_3.replay_counter(), 2, "replay_counter");
+  assert_eq!(message_3.nonce(), anonce, "anonce");
+  assert_eq!(message_3.key_data_length(), Some(56), "key_data_length");
+  assert!(message_3.key_data_elements().is_empty(), "key_data_elements");
+  assert_eq!(
+    message_3.message(),
+    Some(EAPOLKeyMessage::Message3),
+    "message 3"
+  );
+
+  let message_4 = eapol_key_of(&EAPOL_MESSAGE_4);
+  assert_eq!(message_4.replay_counter(), 2, "replay_counter");
+  assert_eq!(message_4.key_data(), Some(&[][..]), "key_data");
+  assert_eq!(
+    message_4.message(),
+    Some(EAPOLKeyMessage::Message4),
+    "message 4"
+  );
+  assert!(
+    EAPOLKeyMessage::Message4.is_four_way(),
+    "message 4 is_four_way"
+  );
+  assert!(
+    !EAPOLKeyMessage::Message4.from_authenticator(),
+    "message 4 from_authenticator"
+  );
+}
+
+#[test]
+fn test_eapol_group_key_handshake() {
+  let group_message_1 = eapol_key_of(&EAPOL_GROUP_MESSAGE_1);
+  assert!(
+    !group_message_1.key_information().pairwise,
+    "group_message_1 pairwise"
+  );
+  assert_eq!(group_message_1.replay_counter(), 3, "replay_counter");
+  assert_eq!(
+    group_message_1.message(),
+    Some(EAPOLKeyMessage::GroupMessage1),
+    "group message 1"
+  );
+
+  let group_message_2 = eapol_key_of(&EAPOL_GROUP_MESSAGE_2);
+  assert_eq!(group_message_2.replay_counter(), 3, "replay_counter");
+  assert_eq!(
+    group_message_2.message(),
+    Some(EAPOLKeyMessage::GroupMessage2),
+    "group message 2"
+  );
+  assert!(
+    !EAPOLKeyMessage::GroupMessage2.is_four_way(),
+    "group message 2 is_four_way"
+  );
+}
+
+#[test]
+fn test_eapol_key_data_elements() {
+  let key_data = [
+    // Error KDE, MUI 1 and ERR_STA_NRSN
+    0xdd, 0x08, 0x00, 0x0f, 0xac, 0x08, 0x00, 0x01, 0x00, 0x02,
+    // Lifetime KDE
+    0xdd, 0x08, 0x00, 0x0f, 0xac, 0x07, 0x00, 0x00, 0x0e, 0x10,
+    // padding
+    0xdd, 0x00,
+  ];
+  assert_eq!(
+    KeyDataElement::parse(&key_data),
+    [
+      KeyDataElement::Error {
+        mui: 1,
+        error_type: 2,
+      },
+      KeyDataElement::Lifetime(3600),
+    ],
+    "key_data_elements"
+  );
+}
+
+#[test]
+fn test_eapol_rekey_message_2() {
+  // a PTK rekey runs the 4-way handshake again with Secure set
+  let mut rekey_message_2 = EAPOL_MESSAGE_2;
+  rekey_message_2[37] |= 0x02;
+
+  let message_2 = eapol_key_of(&rekey_message_2);
+  assert!(message_2.key_information().secure, "secure");
+  assert_eq!(
+    message_2.message(),
+    Some(EAPOLKeyMessage::Message2),
+    "rekey message 2"
+  );
+}
+
+#[test]
+fn test_eapol_handshake_tracker() {
+  let bssid: MacAddress = "02:00:00:00:00:01".parse().unwrap();
+  let station: MacAddress = "02:00:00:00:00:02".parse().unwrap();
+
+  let mut tracker = HandshakeTracker::new();
+  assert_eq!(
+    tracker.add_frame(&Frame::new(&EAPOL_BEACON[..])),
+    [],
+    "beacon"
+  );
+  assert_eq!(tracker.ssid(bssid), Some(&b"test-network"[..]), "ssid");
+
+  // a reassociating station names the SSID too
+  let mut reassociation_tracker = HandshakeTracker::new();
+  reassociation_tracker.add_frame(&Frame::new(&REASSOCIATION_REQUEST_PACKET[..]));
+  assert_eq!(
+    reassociation_tracker.ssid(bssid),
+    Some(&b"test-network"[..]),
+    "reassociation request ssid"
+  );
+
+  assert_eq!(
+    tracker.add_frame(&Frame::new(&EAPOL_MESSAGE_1[..])),
+    [],
+    "message 1"
+  );
+  assert_eq!(
+    tracker.add_frame(&Frame::new(&EAPOL_MESSAGE_1[..])),
+    [HandshakeEvent::Retransmission {
+      bssid,
+      station,
+      message: EAPOLKeyMessage::Message1,
+    }],
+    "message 1 retransmission"
+  );
+
+  let handshake = match &tracker.add_frame(&Frame::new(&EAPOL_MESSAGE_2[..]))[..] {
+    [HandshakeEvent::Complete(handshake)] => handshake.clone(),
+    _ => panic!("message 2 did not complete a handshake"),
+  };
+  assert_eq!(handshake.bssid, bssid, "bssid");
+  assert_eq!(handshake.station, station, "station");
+  assert_eq!(handshake.ssid, Some(b"test-network".to_vec()), "ssid");
+  assert_eq!(
+    handshake.message_pair,
+    HandshakeMessagePair::M1M2,
+    "message_pair"
+  );
+  assert!(!handshake.message_pair.authorized(), "authorized");
+  assert_eq!(handshake.anonce[..], EAPOL_MESSAGE_1[49..81], "anonce");
+  assert_eq!(handshake.snonce[..], EAPOL_MESSAGE_2[49..81], "snonce");
+  assert_eq!(handshake.replay_counter, 1, "replay_counter");
+  assert!(handshake.replay_counter_matched, "replay_counter_matched");
+  assert_eq!(handshake.mic[..], EAPOL_MESSAGE_2[113..129], "mic");
+  assert_eq!(handshake.eapol[..], EAPOL_MESSAGE_2[32..], "eapol");
+  assert_eq!(handshake.messages.len(), 3, "messages");
+
+  let handshake = match &tracker.add_frame(&Frame::new(&EAPOL_MESSAGE_3[..]))[..] {
+    [HandshakeEvent::Complete(handshake)] => handshake.clone(),
+    _ => panic!("message 3 did not complete a handshake"),
+  };
+  assert_eq!(
+    handshake.message_pair,
+    HandshakeMessagePair::M2M3,
+    "message_pair"
+  );
+  assert_eq!(handshake.anonce[..], EAPOL_MESSAGE_3[49..81], "anonce");
+  assert_eq!(handshake.eapol[..], EAPOL_MESSAGE_2[32..], "eapol");
+
+  // message 4 has no SNonce, the group key handshake is not tracked
+  for (bytes, s) in [
+    (&EAPOL_MESSAGE_4[..], "message 4"),
+    (&EAPOL_GROUP_MESSAGE_1[..], "group message 1"),
+    (&EAPOL_GROUP_MESSAGE_2[..], "group message 2"),
+  ] {
+    assert_eq!(tracker.add_frame(&Frame::new(bytes)), [], "{}", s);
+  }
+
+  let attempt = tracker.attempt(bssid, station).unwrap();
+  assert!(attempt.is_complete(), "is_complete");
+  assert_eq!(attempt.retransmissions, 1, "retransmissions");
+  assert_eq!(
+    attempt.message_pairs(),
+    &[HandshakeMessagePair::M1M2, HandshakeMessagePair::M2M3],
+    "message_pairs"
+  );
+
+  // deauthenticated after message 2
+  let mut tracker = HandshakeTracker::new();
+  tracker.add_frame(&Frame::new(&EAPOL_MESSAGE_1[..]));
+  tracker.add_frame(&Frame::new(&EAPOL_MESSAGE_2[..]));
+  match &tracker.add_frame(&Frame::new(&EAPOL_DEAUTHENTICATION[..]))[..] {
+    [HandshakeEvent::Failed(attempt)] => {
+      assert_eq!(attempt.messages.len(), 2, "failed messages");
+    }
+    _ => panic!("deauthentication did not fail the handshake"),
+  }
+  assert!(tracker.attempt(bssid, station).is_none(), "attempt");
+
+  // message 2 after message 3 completes both of its pairs
+  let mut tracker = HandshakeTracker::new();
+  tracker.add_frame(&Frame::new(&EAPOL_MESSAGE_1[..]));
+  tracker.add_frame(&Frame::new(&EAPOL_MESSAGE_3[..]));
+  let message_pairs = tracker
+    .add_frame(&Frame::new(&EAPOL_MESSAGE_2[..]))
+    .into_iter()
+    .map(|event| match event {
+      HandshakeEvent::Complete(handshake) => handshake.message_pair,
+      _ => panic!("message 2 did not complete a handshake"),
+    })
+    .collect::<Vec<_>>();
+  assert_eq!(
+    message_pairs,
+    [HandshakeMessagePair::M1M2, HandshakeMessagePair::M2M3],
+    "late message 2"
+  );
+
+  // a broadcast deauthentication fails the attempts of every station
+  let mut tracker = HandshakeTracker::new();
+  for last_byte in [0x02, 0x03] {
+    let mut message_1 = EAPOL_MESSAGE_1;
+    message_1[9] = last_byte;
+    let mut message_2 = EAPOL_MESSAGE_2;
+    message_2[15] = last_byte;
+    tracker.add_frame(&Frame::new(&message_1[..]));
+    tracker.add_frame(&Frame::new(&message_2[..]));
+  }
+  let mut broadcast_deauthentication = EAPOL_DEAUTHENTICATION;
+  broadcast_deauthentication[4..10].copy_from_slice(&[0xff; 6]);
+  let mut stations = tracker
+    .add_frame(&Frame::new(&broadcast_deauthentication[..]))
+    .into_iter()
+    .map(|event| match event {
+      HandshakeEvent::Failed(attempt) => attempt.station,
+      _ => panic!("broadcast deauthentication did not fail a handshake"),
+    })
+    .collect::<Vec<_>>();
+  stations.sort();
+  assert_eq!(
+    stations,
+    [station, "02:00:00:00:00:03".parse().unwrap()],
+    "broadcast deauthentication"
+  );
+  assert_eq!(tracker.attempts().count(), 0, "attempts");
+}
+
+#[test]
+fn test_eapol_hashcat_22000() {
+  let mut tracker = HandshakeTracker::new();
+  tracker.add_frame(&Frame::new(&EAPOL_BEACON[..]));
+  tracker.add_frame(&Frame::new(&EAPOL_MESSAGE_1[..]));
+  let handshake = match &tracker.add_frame(&Frame::new(&EAPOL_MESSAGE_2[..]))[..] {
+    [HandshakeEvent::Complete(handshake)] => handshake.clone(),
+    _ => panic!("message 2 did not complete a handshake"),
+  };
+  assert_eq!(handshake.hashcat_message_pair(), 0x00, "message_pair");
+
+  let mut writer = Hashcat22000Writer::new(Vec::new());
+  assert!(writer.write_handshake(&handshake).unwrap(), "write_handshake");
+  assert!(
+    !writer.write_handshake(&handshake).unwrap(),
+    "write_handshake duplicate"
+  );
+  assert_eq!(
+    writer
+      .write_pmkids(&Frame::new(&EAPOL_MESSAGE_1[..]), b"test-network")
+      .unwrap(),
+    1,
+    "write_pmkids"
+  );
+
+  assert_eq!(
+    String::from_utf8(writer.into_inner()).unwrap(),
+    concat!(
+      "WPA*02*4a6599007072e2aeb5c7135c911d810d*020000000001*020000000002*",
+      "746573742d6e6574776f726b*",
+      "3f1e8a0e1cc63a67b155edd21b3aa83fc02716f0dbf9bcb013f3e6fcf7d382e4*",
+      "0203007502010a000000000000000000014adcb707f9a16d23de6a840d614fe8d9bd175714c7577bbde714d8",
+      "2184d46e11000000000000000000000000000000000000000000000000000000000000000000000000000000",
+      "000000000000000000001630140100000fac040100000fac040100000fac020000*00\n",
+      "WPA*01*481771de9dcf8f74e990e11f40b2652a*020000000001*020000000002*",
+      "746573742d6e6574776f726b***01\n",
+    ),
+    "hashcat 22000"
+  );
+
+  let mut anonce = [0x3f; 32];
+  let previous = anonce;
+  anonce[31] = 0x40;
+  assert_eq!(
+    NonceCounterEndianness::detect(&previous, &anonce),
+    Some(NonceCounterEndianness::Big),
+    "big endian counter"
+  );
+  anonce[31] = 0x3f;
+  anonce[28] = 0x41;
+  assert_eq!(
+    NonceCounterEndianness::detect(&previous, &anonce),
+    Some(NonceCounterEndianness::Little),
+    "little endian counter"
+  );
+  assert_eq!(
+    NonceCounterEndianness::detect(&previous, &[0; 32]),
+    None,
+    "unrelated anonce"
+  );
+}
@@ -0,0 +1,87 @@
+const PROBE_REQUEST_WPS: [u8; 175] = [
+  0x40, 0x00, 0x00, 0x00, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x00, 0x21, 0x6a, 0x12, 0x34, 0x56,
+  0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x80, 0x5c, 0x00, 0x00, 0x01, 0x04, 0x02, 0x04, 0x0b, 0x16,
+  0xdd, 0x3c, 0x00, 0x50, 0xf2, 0x04, 0x10, 0x4a, 0x00, 0x01, 0x10, 0x10, 0x3a, 0x00, 0x01, 0x00,
+  0x10, 0x08, 0x00, 0x02, 0x26, 0x8c, 0x10, 0x47, 0x00, 0x10, 0xc0, 0xff, 0xee, 0x00, 0x12, 0x34,
+  0x45, 0x67, 0x89, 0x0a, 0xbc, 0xde, 0xf0, 0x12, 0x34, 0x56, 0x10, 0x54, 0x00, 0x08, 0x00, 0x01,
+  0x00, 0x50, 0xf2, 0x04, 0x00, 0x01, 0x10, 0x3c, 0x00, 0x01, 0x03, 0x10, 0x02, 0x00, 0xdd, 0x4f,
+  0x00, 0x50, 0xf2, 0x04, 0x02, 0x00, 0x00, 0x10, 0x09, 0x00, 0x02, 0x00, 0x00, 0x10, 0x12, 0x00,
+  0x02, 0x00, 0x00, 0x10, 0x21, 0x00, 0x04, 0x41, 0x43, 0x4d, 0x45, 0x10, 0x23, 0x00, 0x06, 0x57,
+  0x69, 0x64, 0x67, 0x65, 0x74, 0x10, 0x24, 0x00, 0x03, 0x58, 0x31, 0x30, 0x10, 0x42, 0x00, 0x04,
+  0x31, 0x32, 0x33, 0x34, 0x10, 0x11, 0x00, 0x07, 0x4c, 0x61, 0x70, 0x74, 0x6f, 0x70, 0x31, 0x10,
+  0x49, 0x00, 0x06, 0x00, 0x37, 0x2a, 0x00, 0x01, 0x20, 0x99, 0x99, 0x00, 0x02, 0xab, 0xcd,
+];
+
+#[test]
+fn test_probe_request_wps() {
+  // Transmitter address: 00:21:6a:12:34:56
+  // SSID: Wildcard
+  // WPS attributes split across two vendor specific elements
+
+  test_test_item(TestItem {
+    bytes: &PROBE_REQUEST_WPS,
+    subtype: Some(FrameSubtype::Management(ManagementSubtype::ProbeRequest)),
+
+    ds_status: Some(DSStatus::NotLeavingDSOrADHOC),
+    duration_id: Some(DurationID::Duration(0)),
+
+    receiver_address: "ff:ff:ff:ff:ff:ff".parse().unwrap(),
+    destination_address: Some("ff:ff:ff:ff:ff:ff".parse().unwrap()),
+
+    transmitter_address: Some("00:21:6a:12:34:56".parse().unwrap()),
+    source_address: Some("00:21:6a:12:34:56".parse().unwrap()),
+
+    bssid_address: Some("ff:ff:ff:ff:ff:ff".parse().unwrap()),
+
+    fragment_number: Some(0),
+    sequence_number: Some(1480),
+
+    ssid: Some(b"".to_vec()),
+
+    ..Default::default()
+  });
+
+  let probe_request_frame = ProbeRequestFrame::new(&PROBE_REQUEST_WPS[..]);
+  let wps = probe_request_frame.tagged_parameters().unwrap().wps().unwrap();
+
+  assert_eq!(wps.version(), Some(0x10), "version");
+  assert_eq!(wps.version2(), Some(0x20), "version2");
+  assert_eq!(wps.wps_state(), None, "wps_state");
+  assert_eq!(
+    wps.uuid(),
+    Some([
+      0xc0, 0xff, 0xee, 0x00, 0x12, 0x34, 0x45, 0x67, 0x89, 0x0a, 0xbc, 0xde, 0xf0, 0x12, 0x34,
+      0x56,
+    ]),
+    "uuid"
+  );
+  assert_eq!(wps.manufacturer(), Some(&b"ACME"[..]), "manufacturer");
+  assert_eq!(wps.model_name(), Some(&b"Widget"[..]), "model_name");
+  assert_eq!(wps.model_number(), Some(&b"X10"[..]), "model_number");
+  assert_eq!(wps.serial_number(), Some(&b"1234"[..]), "serial_number");
+  assert_eq!(wps.device_name(), Some(&b"Laptop1"[..]), "device_name");
+  assert_eq!(
+    wps.primary_device_type(),
+    Some(WPSPrimaryDeviceType {
+      category: 1,
+      oui: [0x00, 0x50, 0xf2, 0x04],
+      subcategory: 1,
+    }),
+    "primary_device_type"
+  );
+
+  let config_methods = wps.config_methods().unwrap();
+  assert!(config_methods.label, "label");
+  assert!(config_methods.display, "display");
+  assert!(config_methods.push_button, "push_button");
+  assert!(config_methods.virtual_push_button, "virtual_push_button");
+  assert!(config_methods.physical_push_button, "physical_push_button");
+  assert!(config_methods.virtual_display, "virtual_display");
+  assert!(!config_methods.keypad, "keypad");
+
+  assert_eq!(
+    wps.attributes.last(),
+    Some(&WPSAttribute::Other(0x9999, vec![0xab, 0xcd])),
+    "unknown attribute"
+  );
+}
@@ -1,11 +1,11 @@
-const QOS_DATA_PACKET: [u8; 97] = [
+// without the FCS
+const QOS_DATA_PACKET: [u8; 93] = [
   0x88, 0x01, 0x2c, 0x00, 0x00, 0x14, 0xa5, 0xcd, 0x74, 0x7b, 0x00, 0x14, 0xa5, 0xcb, 0x6e, 0x1a,
   0x00, 0x01, 0x02, 0x27, 0xf9, 0xb2, 0xa0, 0xed, 0x00, 0x00, 0xaa, 0xaa, 0x03, 0x00, 0x00, 0x00,
   0x08, 0x00, 0x45, 0x00, 0x00, 0x3b, 0x8d, 0x06, 0x00, 0x00, 0x80, 0x11, 0x29, 0xd6, 0xc0, 0xa8,
   0x01, 0x84, 0xc0, 0xa8, 0x01, 0x01, 0x04, 0x07, 0x00, 0x35, 0x00, 0x27, 0xab, 0x15, 0x96, 0xc1,
   0x01, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0x77, 0x77, 0x77, 0x06, 0x70,
-  0x6f, 0x6c, 0x69, 0x74, 0x6f, 0x02, 0x69, 0x74, 0x00, 0x00, 0x01, 0x00, 0x01, 0x78, 0x80, 0x59,
-  0x37,
+  0x6f, 0x6c, 0x69, 0x74, 0x6f, 0x02, 0x69, 0x74, 0x00, 0x00, 0x01, 0x00, 0x01,
 ];
 
 #[test]
@@ -39,4 +39,30 @@ fn test_qos_data_packet() {
     // QoS Control 0x0000
     ..Default::default()
   });
+
+  let data_frame = DataFrame::new(&QOS_DATA_PACKET[..]);
+  let llc_snap = data_frame.llc_snap().unwrap();
+  assert_eq!(llc_snap.dsap(), 0xaa, "dsap");
+  assert_eq!(llc_snap.ssap(), 0xaa, "ssap");
+  assert_eq!(llc_snap.control(), 0x03, "control");
+  assert_eq!(llc_snap.oui(), RFC1042_OUI, "oui");
+  assert_eq!(llc_snap.ether_type(), EtherType::IPv4, "ether_type");
+  match llc_snap.next_layer() {
+    LlcSnapLayer::IPv4(payload) => {
+      assert_eq!(payload.len(), 59, "ipv4 length");
+      assert_eq!(payload[0], 0x45, "ipv4 version and header length");
+    }
+    _ => panic!("not ipv4"),
+  }
+
+  let ethernet = data_frame.to_ethernet().unwrap();
+  assert_eq!(
+    ethernet[0..14],
+    [
+      0x00, 0x01, 0x02, 0x27, 0xf9, 0xb2, 0x00, 0x14, 0xa5, 0xcb, 0x6e, 0x1a, 0x08, 0x00,
+    ],
+    "ethernet header"
+  );
+  assert_eq!(ethernet.len(), 14 + 59, "ethernet length");
+  assert_eq!(ethernet[14..], QOS_DATA_PACKET[34..], "ethernet payload");
 }
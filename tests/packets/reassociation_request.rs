@@ -0,0 +1,78 @@
+const REASSOCIATION_REQUEST_PACKET: [u8; 98] = [
+  0x20, 0x00, 0x3a, 0x01, 0x02, 0x00, 0x00, 0x00, 0x00, 0x01, 0x02, 0x00, 0x00, 0x00, 0x00, 0x02,
+  0x02, 0x00, 0x00, 0x00, 0x00, 0x01, 0x10, 0x00, 0x11, 0x04, 0x0a, 0x00, 0x02, 0x00, 0x00, 0x00,
+  0x00, 0x01, 0x00, 0x0c, 0x74, 0x65, 0x73, 0x74, 0x2d, 0x6e, 0x65, 0x74, 0x77, 0x6f, 0x72, 0x6b,
+  0x01, 0x08, 0x82, 0x84, 0x8b, 0x96, 0x0c, 0x12, 0x18, 0x24, 0x30, 0x26, 0x01, 0x00, 0x00, 0x0f,
+  0xac, 0x04, 0x01, 0x00, 0x00, 0x0f, 0xac, 0x04, 0x01, 0x00, 0x00, 0x0f, 0xac, 0x02, 0x00, 0x00,
+  0x01, 0x00, 0x48, 0x17, 0x71, 0xde, 0x9d, 0xcf, 0x8f, 0x74, 0xe9, 0x90, 0xe1, 0x1f, 0x40, 0xb2,
+  0x65, 0x2a,
+];
+
+#[test]
+fn test_reassociation_request_packet() {
+  // Receiver address: 02:00:00:00:00:01
+  // Transmitter address: 02:00:00:00:00:02
+  // RSN element with the PMKID of the cached PMKSA
+
+  test_test_item(TestItem {
+    bytes: &REASSOCIATION_REQUEST_PACKET,
+    subtype: Some(FrameSubtype::Management(
+      ManagementSubtype::ReassociationRequest,
+    )),
+
+    ds_status: Some(DSStatus::NotLeavingDSOrADHOC),
+
+    duration_id: Some(DurationID::Duration(314)),
+
+    receiver_address: "02:00:00:00:00:01".parse().unwrap(),
+    destination_address: Some("02:00:00:00:00:01".parse().unwrap()),
+
+    transmitter_address: Some("02:00:00:00:00:02".parse().unwrap()),
+    source_address: Some("02:00:00:00:00:02".parse().unwrap()),
+
+    bssid_address: Some("02:00:00:00:00:01".parse().unwrap()),
+
+    fragment_number: Some(0),
+    sequence_number: Some(1),
+
+    ..Default::default()
+  });
+
+  let frame = Frame::new(&REASSOCIATION_REQUEST_PACKET[..]);
+  match frame.next_layer() {
+    Some(FrameLayer::Management(management_frame)) => match management_frame.next_layer() {
+      Some(ManagementFrameLayer::ReassociationRequest(reassociation_request_frame)) => {
+        assert_eq!(
+          reassociation_request_frame.listen_interval(),
+          10,
+          "listen_interval"
+        );
+        assert_eq!(
+          reassociation_request_frame.current_ap_address(),
+          "02:00:00:00:00:01".parse().unwrap(),
+          "current_ap_address"
+        );
+        assert_eq!(
+          reassociation_request_frame.ssid(),
+          Some(b"test-network".to_vec()),
+          "ssid"
+        );
+      }
+      _ => panic!("not a reassociation request"),
+    },
+    _ => panic!("not a management frame"),
+  }
+
+  assert_eq!(
+    frame.pmkids(),
+    vec![(
+      "02:00:00:00:00:01".parse().unwrap(),
+      "02:00:00:00:00:02".parse().unwrap(),
+      [
+        0x48, 0x17, 0x71, 0xde, 0x9d, 0xcf, 0x8f, 0x74, 0xe9, 0x90, 0xe1, 0x1f, 0x40, 0xb2, 0x65,
+        0x2a,
+      ],
+    )],
+    "pmkids"
+  );
+}